@@ -0,0 +1,265 @@
+use std::collections::HashMap;
+
+use super::{
+    edge::{Edge, EdgeId},
+    vertice::{Vertice, VerticeKey},
+};
+use crate::{tools::weight::Weight, DiGraph, GraphError, UnGraph};
+
+/// # VerticeMap
+/// Mapa de propriedades tipadas dos vértices de um grafo
+///
+/// Guarda um valor `T` qualquer (nome, coordenada, timestamp, ...) por chave de vértice,
+/// separado do grafo. Remover um vértice do grafo não remove sua entrada aqui.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(
+        serialize = "K: VerticeKey + serde::Serialize, T: serde::Serialize",
+        deserialize = "K: VerticeKey + serde::Deserialize<'de>, T: serde::Deserialize<'de>"
+    ))
+)]
+pub struct VerticeMap<K = i32, T = String> {
+    map: HashMap<K, T>,
+}
+
+impl<K: VerticeKey, T> Default for VerticeMap<K, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[allow(unused)]
+impl<K: VerticeKey, T> VerticeMap<K, T> {
+    pub fn new() -> VerticeMap<K, T> {
+        VerticeMap {
+            map: HashMap::new(),
+        }
+    }
+
+    /// Cria o mapa aplicando `f` a cada vértice do grafo
+    pub fn from_digraph<W: Weight>(
+        graph: &DiGraph<K, W>,
+        f: impl Fn(&Vertice<K, W>) -> T,
+    ) -> VerticeMap<K, T> {
+        Self::from_vertices(graph.iter_vertices(), f)
+    }
+
+    /// Cria o mapa aplicando `f` a cada vértice do grafo não direcionado
+    pub fn from_ungraph<W: Weight>(
+        graph: &UnGraph<K, W>,
+        f: impl Fn(&Vertice<K, W>) -> T,
+    ) -> VerticeMap<K, T> {
+        Self::from_vertices(graph.iter_vertices(), f)
+    }
+
+    fn from_vertices<'a, W: Weight + 'a>(
+        vertices: impl Iterator<Item = &'a Vertice<K, W>>,
+        f: impl Fn(&Vertice<K, W>) -> T,
+    ) -> VerticeMap<K, T>
+    where
+        K: 'a,
+    {
+        VerticeMap {
+            map: vertices.map(|v| (v.key(), f(v))).collect(),
+        }
+    }
+
+    /// Associa `value` ao vértice, retornando o valor anterior se existir
+    pub fn insert(&mut self, vertice_key: K, value: T) -> Option<T> {
+        self.map.insert(vertice_key, value)
+    }
+
+    pub fn get(&self, vertice_key: &K) -> Option<&T> {
+        self.map.get(vertice_key)
+    }
+
+    pub fn get_mut(&mut self, vertice_key: &K) -> Option<&mut T> {
+        self.map.get_mut(vertice_key)
+    }
+
+    pub fn remove(&mut self, vertice_key: &K) -> Option<T> {
+        self.map.remove(vertice_key)
+    }
+
+    pub fn contains(&self, vertice_key: &K) -> bool {
+        self.map.contains_key(vertice_key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &T)> {
+        self.map.iter()
+    }
+}
+
+/// # EdgeMap
+/// Mapa de propriedades tipadas das arestas de um grafo, indexado por `EdgeId`
+///
+/// Como os ids são densos, os valores ficam em um vetor na posição do id.
+/// Os ids só têm significado no grafo que os gerou, então o mapa também.
+///
+/// Com a feature `serde` a desserialização confere que `len` é a quantidade de valores.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "EdgeMapRepr<T>")
+)]
+pub struct EdgeMap<T = i32> {
+    values: Vec<Option<T>>,
+    len: usize,
+}
+
+impl<T> Default for EdgeMap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[allow(unused)]
+impl<T> EdgeMap<T> {
+    pub fn new() -> EdgeMap<T> {
+        EdgeMap {
+            values: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// Cria o mapa aplicando `f` a cada aresta do grafo
+    pub fn from_digraph<K: VerticeKey, W: Weight>(
+        graph: &DiGraph<K, W>,
+        f: impl Fn(&Edge<K, W>) -> T,
+    ) -> EdgeMap<T> {
+        Self::from_edges(&graph.all_edges(), f)
+    }
+
+    /// Cria o mapa aplicando `f` a cada aresta do grafo não direcionado
+    pub fn from_ungraph<K: VerticeKey, W: Weight>(
+        graph: &UnGraph<K, W>,
+        f: impl Fn(&Edge<K, W>) -> T,
+    ) -> EdgeMap<T> {
+        Self::from_edges(&graph.all_edges(), f)
+    }
+
+    fn from_edges<K: VerticeKey, W: Weight>(
+        edges: &[Edge<K, W>],
+        f: impl Fn(&Edge<K, W>) -> T,
+    ) -> EdgeMap<T> {
+        let mut map = EdgeMap::new();
+        for e in edges {
+            // As arestas de um grafo sempre têm id
+            map.insert_at(e.id(), f(e));
+        }
+        map
+    }
+
+    /// Associa `value` à aresta, retornando o valor anterior se existir
+    /// ```
+    /// # use rust_graph::{DiGraph, graph_lib::{attributes::EdgeMap, edge::{Edge, EdgeId}}};
+    /// let mut graph: DiGraph = DiGraph::new();
+    /// let edge = Edge::new(1, 2);
+    /// let mut names = EdgeMap::new();
+    /// assert!(names.insert(edge.id(), "a").is_err());
+    /// let id = graph.add_edge(edge);
+    /// assert_eq!(names.insert(id, "a").unwrap(), None);
+    /// assert!(names.insert(EdgeId::new(usize::MAX - 1), "b").is_err());
+    /// ```
+    ///
+    /// Os ids esperados são os do grafo que gerou o mapa (`0..` quantidade de arestas já
+    /// adicionadas): o mapa ocupa memória proporcional ao maior id inserido.
+    ///
+    /// # Errors
+    /// `GraphError::UnassignedEdge` se `id` for `EdgeId::UNASSIGNED` (a aresta ainda não
+    /// pertence a um grafo) e `GraphError::EdgeIdOutOfRange` se não houver memória para
+    /// chegar até a posição de `id` (um id de outro grafo, por exemplo).
+    pub fn insert(&mut self, id: EdgeId, value: T) -> Result<Option<T>, GraphError> {
+        if id == EdgeId::UNASSIGNED {
+            return Err(GraphError::UnassignedEdge);
+        }
+        if let Some(additional) = (id.index() + 1).checked_sub(self.values.len()) {
+            self.values
+                .try_reserve_exact(additional)
+                .map_err(|_| GraphError::EdgeIdOutOfRange(id))?;
+        }
+        Ok(self.insert_at(id, value))
+    }
+
+    fn insert_at(&mut self, id: EdgeId, value: T) -> Option<T> {
+        let index = id.index();
+        if index >= self.values.len() {
+            self.values.resize_with(index + 1, || None);
+        }
+        let old = self.values[index].replace(value);
+        if old.is_none() {
+            self.len += 1;
+        }
+        old
+    }
+
+    pub fn get(&self, id: EdgeId) -> Option<&T> {
+        self.values.get(id.index())?.as_ref()
+    }
+
+    pub fn get_mut(&mut self, id: EdgeId) -> Option<&mut T> {
+        self.values.get_mut(id.index())?.as_mut()
+    }
+
+    pub fn remove(&mut self, id: EdgeId) -> Option<T> {
+        let old = self.values.get_mut(id.index())?.take();
+        if old.is_some() {
+            self.len -= 1;
+        }
+        old
+    }
+
+    pub fn contains(&self, id: EdgeId) -> bool {
+        self.get(id).is_some()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (EdgeId, &T)> {
+        self.values
+            .iter()
+            .enumerate()
+            .filter_map(|(i, v)| v.as_ref().map(|v| (EdgeId::new(i), v)))
+    }
+}
+
+/// Forma serializada de um `EdgeMap`, validada antes de virar o mapa
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct EdgeMapRepr<T> {
+    values: Vec<Option<T>>,
+    len: usize,
+}
+
+#[cfg(feature = "serde")]
+impl<T> TryFrom<EdgeMapRepr<T>> for EdgeMap<T> {
+    type Error = String;
+
+    fn try_from(repr: EdgeMapRepr<T>) -> Result<Self, Self::Error> {
+        let found = repr.values.iter().filter(|v| v.is_some()).count();
+        if found != repr.len {
+            return Err(format!("edge map declares {} values, found {}", repr.len, found));
+        }
+        Ok(EdgeMap {
+            values: repr.values,
+            len: repr.len,
+        })
+    }
+}
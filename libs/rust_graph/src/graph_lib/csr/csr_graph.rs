@@ -0,0 +1,265 @@
+use std::collections::HashMap;
+
+use super::CsrAdjacency;
+use crate::{
+    graph_lib::{
+        edge::{Edge, EdgeId},
+        vertice::VerticeKey,
+    },
+    tools::weight::Weight,
+    DiGraph,
+};
+
+/// # CsrGraph
+/// Grafo direcionado imutável em formato CSR (Compressed Sparse Row)
+///
+/// Os vértices são remapeados para índices densos `0..n`; as arestas de saída de `v`
+/// ficam em `targets[offsets[v]..offsets[v + 1]]`, com pesos e ids nas mesmas posições.
+/// As arestas de entrada são guardadas da mesma forma (`in_offsets`, `in_sources`),
+/// o que permite percorrer o grafo transposto sem construí-lo.
+///
+/// Criado por `DiGraph::freeze`. Os algoritmos sobre ele ficam em `CsrWorkspace`.
+/// Com a feature `serde` a desserialização confere o `index`, os offsets e os índices.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(
+        serialize = "K: VerticeKey + serde::Serialize, W: Weight + serde::Serialize",
+        deserialize = "K: VerticeKey + serde::Deserialize<'de>, W: Weight + serde::Deserialize<'de>"
+    )),
+    serde(try_from = "CsrGraphRepr<K, W>")
+)]
+pub struct CsrGraph<K = i32, W = i32> {
+    keys: Vec<K>,
+    index: HashMap<K, usize>,
+    offsets: Vec<usize>,
+    targets: Vec<usize>,
+    weights: Vec<W>,
+    edge_ids: Vec<EdgeId>,
+    in_offsets: Vec<usize>,
+    in_sources: Vec<usize>,
+}
+
+#[allow(unused)]
+impl<K: VerticeKey, W: Weight> CsrGraph<K, W> {
+    /// Constrói o CSR a partir de um `DiGraph`
+    ///
+    /// As arestas de cada vértice ficam na ordem de inserção (`EdgeId`).
+    pub fn from_digraph(graph: &DiGraph<K, W>) -> CsrGraph<K, W> {
+        let keys = graph.get_vertice_key_array();
+        let index: HashMap<K, usize> = keys
+            .iter()
+            .enumerate()
+            .map(|(i, k)| (k.clone(), i))
+            .collect();
+
+        let n = keys.len();
+        let m = graph.edges_length();
+        let mut offsets = Vec::with_capacity(n + 1);
+        let mut targets = Vec::with_capacity(m);
+        let mut weights = Vec::with_capacity(m);
+        let mut edge_ids = Vec::with_capacity(m);
+
+        offsets.push(0);
+        for key in keys.iter() {
+            let vertice = graph.get_vertice_arc(key.clone()).unwrap();
+            let mut edges: Vec<_> = vertice.edges_vec_ref().into_iter().collect();
+            edges.sort_by_key(|e| e.id());
+            for e in edges {
+                targets.push(index[e.destiny_key_ref()]);
+                weights.push(e.weight());
+                edge_ids.push(e.id());
+            }
+            offsets.push(targets.len());
+        }
+
+        let (in_offsets, in_sources) = transpose(&offsets, &targets);
+        CsrGraph {
+            keys,
+            index,
+            offsets,
+            targets,
+            weights,
+            edge_ids,
+            in_offsets,
+            in_sources,
+        }
+    }
+
+    /// Retorna a quantidade de vértices no grafo.
+    pub fn vertices_length(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Retorna a quantidade de arestas no grafo.
+    pub fn edges_length(&self) -> usize {
+        self.targets.len()
+    }
+
+    /// Índice denso do vértice de chave `key`, se existir
+    pub fn index_of(&self, key: &K) -> Option<usize> {
+        self.index.get(key).copied()
+    }
+
+    /// Chave do vértice de índice `v`
+    ///
+    /// # Panics
+    /// Se `v >= vertices_length()`
+    pub fn key_of(&self, v: usize) -> &K {
+        &self.keys[v]
+    }
+
+    /// Chaves de todos os vértices, na ordem dos índices
+    pub fn keys(&self) -> &[K] {
+        &self.keys
+    }
+
+    /// Índices dos destinos das arestas que saem de `v`
+    pub fn neighbours(&self, v: usize) -> &[usize] {
+        &self.targets[self.offsets[v]..self.offsets[v + 1]]
+    }
+
+    /// Pesos das arestas que saem de `v`, alinhados com `neighbours(v)`
+    pub fn weights(&self, v: usize) -> &[W] {
+        &self.weights[self.offsets[v]..self.offsets[v + 1]]
+    }
+
+    /// Ids (no `DiGraph` original) das arestas que saem de `v`, alinhados com `neighbours(v)`
+    pub fn edge_ids(&self, v: usize) -> &[EdgeId] {
+        &self.edge_ids[self.offsets[v]..self.offsets[v + 1]]
+    }
+
+    /// Índices das origens das arestas que chegam em `v`
+    pub fn predecessors(&self, v: usize) -> &[usize] {
+        &self.in_sources[self.in_offsets[v]..self.in_offsets[v + 1]]
+    }
+
+    pub fn out_degree(&self, v: usize) -> usize {
+        self.offsets[v + 1] - self.offsets[v]
+    }
+
+    pub fn in_degree(&self, v: usize) -> usize {
+        self.in_offsets[v + 1] - self.in_offsets[v]
+    }
+
+    /// Reconstrói um `DiGraph` com as mesmas arestas (ids novos)
+    pub fn to_digraph(&self) -> DiGraph<K, W> {
+        let mut graph = DiGraph::new_sized(self.keys.len() as u32);
+        for key in self.keys.iter() {
+            graph.add_vertice(key.clone());
+        }
+        for v in 0..self.keys.len() {
+            for (&w, &weight) in self.neighbours(v).iter().zip(self.weights(v)) {
+                graph.add_edge(Edge::new_weighted(
+                    self.keys[v].clone(),
+                    self.keys[w].clone(),
+                    weight,
+                ));
+            }
+        }
+        graph
+    }
+}
+
+/// Arestas de entrada a partir das de saída: contagem por destino e preenchimento estável
+///
+/// Os destinos em `targets` precisam ser menores que `offsets.len() - 1`.
+fn transpose(offsets: &[usize], targets: &[usize]) -> (Vec<usize>, Vec<usize>) {
+    let n = offsets.len() - 1;
+    let mut in_degree = vec![0usize; n];
+    for &w in targets {
+        in_degree[w] += 1;
+    }
+    let mut in_offsets = Vec::with_capacity(n + 1);
+    in_offsets.push(0);
+    for d in in_degree.iter() {
+        in_offsets.push(in_offsets.last().unwrap() + d);
+    }
+    let mut next = in_offsets[..n].to_vec();
+    let mut in_sources = vec![0usize; targets.len()];
+    for v in 0..n {
+        for &w in &targets[offsets[v]..offsets[v + 1]] {
+            in_sources[next[w]] = v;
+            next[w] += 1;
+        }
+    }
+    (in_offsets, in_sources)
+}
+
+impl<K: VerticeKey, W: Weight> CsrAdjacency<W> for CsrGraph<K, W> {
+    fn vertices_length(&self) -> usize {
+        CsrGraph::vertices_length(self)
+    }
+
+    fn neighbours(&self, v: usize) -> &[usize] {
+        CsrGraph::neighbours(self, v)
+    }
+
+    fn weights(&self, v: usize) -> &[W] {
+        CsrGraph::weights(self, v)
+    }
+
+    fn predecessors(&self, v: usize) -> &[usize] {
+        CsrGraph::predecessors(self, v)
+    }
+}
+
+/// Forma serializada de um `CsrGraph`, validada antes de virar o grafo
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct CsrGraphRepr<K: VerticeKey, W> {
+    keys: Vec<K>,
+    index: HashMap<K, usize>,
+    offsets: Vec<usize>,
+    targets: Vec<usize>,
+    weights: Vec<W>,
+    edge_ids: Vec<EdgeId>,
+    in_offsets: Vec<usize>,
+    in_sources: Vec<usize>,
+}
+
+#[cfg(feature = "serde")]
+impl<K: VerticeKey, W: Weight> TryFrom<CsrGraphRepr<K, W>> for CsrGraph<K, W> {
+    type Error = String;
+
+    fn try_from(repr: CsrGraphRepr<K, W>) -> Result<Self, Self::Error> {
+        let n = repr.keys.len();
+        let m = repr.targets.len();
+        let index_ok = repr.index.len() == n
+            && repr.keys.iter().enumerate().all(|(i, k)| repr.index.get(k) == Some(&i));
+        if !index_ok {
+            return Err("index does not match the keys".to_string());
+        }
+        let valid_offsets = repr.offsets.len() == n + 1
+            && repr.offsets.first() == Some(&0)
+            && repr.offsets.last() == Some(&m)
+            && repr.offsets.windows(2).all(|pair| pair[0] <= pair[1]);
+        if !valid_offsets {
+            return Err(format!(
+                "offsets do not describe {n} vertices and {m} edges"
+            ));
+        }
+        if repr.weights.len() != m || repr.edge_ids.len() != m {
+            return Err(format!("weights and edge ids must have {m} entries"));
+        }
+        if repr.targets.iter().any(|&w| w >= n) {
+            return Err("edge target is out of range".to_string());
+        }
+        // As arestas de entrada precisam ser exatamente o transposto das de saída
+        let (in_offsets, in_sources) = transpose(&repr.offsets, &repr.targets);
+        if repr.in_offsets != in_offsets || repr.in_sources != in_sources {
+            return Err("incoming edges do not match the outgoing edges".to_string());
+        }
+        Ok(CsrGraph {
+            keys: repr.keys,
+            index: repr.index,
+            offsets: repr.offsets,
+            targets: repr.targets,
+            weights: repr.weights,
+            edge_ids: repr.edge_ids,
+            in_offsets,
+            in_sources,
+        })
+    }
+}
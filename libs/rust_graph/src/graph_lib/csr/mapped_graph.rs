@@ -0,0 +1,210 @@
+use std::{
+    fs::File,
+    marker::PhantomData,
+    mem::{align_of, size_of},
+};
+
+use memmap2::Mmap;
+
+use super::CsrAdjacency;
+use crate::{
+    graph_lib::{
+        edge::{Edge, EdgeId},
+        formats::binary::{BinaryValue, Layout},
+        vertice::VerticeKey,
+    },
+    tools::weight::Weight,
+    DiGraph, GraphError,
+};
+
+/// # MappedGraph
+/// Grafo somente leitura mapeado em memória a partir de um snapshot binário
+///
+/// Os vetores do CSR (chaves, offsets, destinos, pesos, ids e arestas de entrada) são
+/// lidos direto das páginas do arquivo, sem cópia: abrir o grafo só valida o arquivo,
+/// e várias execuções podem compartilhar o cache de páginas do sistema.
+/// Os índices dos vértices são as posições das chaves em ordem crescente.
+///
+/// Os algoritmos de `CsrWorkspace` aceitam um `MappedGraph` como aceitam um `CsrGraph`.
+///
+/// O arquivo não pode ser alterado enquanto estiver mapeado (ver `# Safety` em
+/// [`MappedGraph::open`]).
+pub struct MappedGraph<K = i32, W = i32> {
+    mmap: Mmap,
+    layout: Layout,
+    types: PhantomData<(K, W)>,
+}
+
+#[allow(unused)]
+impl<K: VerticeKey + BinaryValue + Ord, W: Weight + BinaryValue> MappedGraph<K, W> {
+    /// Mapeia em memória um snapshot salvo com [`DiGraph::save_binary`]
+    /// ```
+    /// # use rust_graph::{DiGraph, graph_lib::{edge::Edge, csr::{mapped_graph::MappedGraph, workspace::CsrWorkspace}}};
+    /// let mut graph: DiGraph = DiGraph::new();
+    /// graph.add_edge(Edge::new_weighted(1, 2, 5));
+    /// graph.add_edge(Edge::new_weighted(2, 3, 1));
+    /// let path = std::env::temp_dir().join("rust_graph_mapped_doc.bin");
+    /// graph.save_binary(path.to_str().unwrap()).unwrap();
+    ///
+    /// // SAFETY: o arquivo acabou de ser escrito e ninguém mais o altera
+    /// let mapped: MappedGraph = unsafe { MappedGraph::open(path.to_str().unwrap()) }.unwrap();
+    /// let source = mapped.index_of(&1).unwrap();
+    /// let mut workspace = CsrWorkspace::new();
+    /// let dist = workspace.dijkstra(&mapped, source);
+    /// assert_eq!(dist[mapped.index_of(&3).unwrap()].unwrap(), 6);
+    /// # std::fs::remove_file(path).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    /// `GraphError::Io` se o arquivo não puder ser aberto e `GraphError::InvalidSnapshot`
+    /// se o snapshot for inválido (ver [`DiGraph::from_binary`]) ou se a plataforma não
+    /// for 64 bits little-endian, que é o layout do arquivo.
+    ///
+    /// # Safety
+    /// Nenhum processo (nem este) pode alterar ou truncar o arquivo enquanto o
+    /// `MappedGraph` existir. O arquivo só é validado aqui: se o conteúdo mudar depois, as
+    /// fatias lidas das páginas mapeadas podem apontar para fora do arquivo, e truncá-lo
+    /// faz os acessos seguintes terminarem o processo com `SIGBUS`.
+    pub unsafe fn open(file_path: &str) -> Result<MappedGraph<K, W>, GraphError> {
+        if cfg!(target_endian = "big") || size_of::<usize>() != 8 {
+            return Err(GraphError::InvalidSnapshot(
+                "memory mapping requires a 64-bit little-endian platform".to_string(),
+            ));
+        }
+        let file = File::open(file_path)?;
+        // SAFETY: garantido por quem chama (ver `# Safety`)
+        let mmap = unsafe { Mmap::map(&file)? };
+        let layout = Layout::validate::<K, W>(&mmap)?;
+        Ok(MappedGraph {
+            mmap,
+            layout,
+            types: PhantomData,
+        })
+    }
+
+    /// `len` valores de `T` a partir do byte `start` do arquivo
+    fn slice<T: Copy>(&self, start: usize, len: usize) -> &[T] {
+        let bytes = &self.mmap[start..start + len * size_of::<T>()];
+        debug_assert_eq!(bytes.as_ptr() as usize % align_of::<T>(), 0, "misaligned section");
+        // SAFETY: `Layout::validate` garantiu o tamanho do arquivo; as seções começam em
+        // múltiplos de 16 bytes de um mapeamento alinhado à página, e `T` é `usize` (64 bits,
+        // verificado em `open`), `EdgeId` (`repr(transparent)` sobre `usize`) ou um
+        // `BinaryValue`, tipos numéricos em que qualquer sequência de bytes é válida.
+        unsafe { std::slice::from_raw_parts(bytes.as_ptr() as *const T, len) }
+    }
+
+    fn offsets(&self) -> &[usize] {
+        self.slice(self.layout.offsets, self.layout.n + 1)
+    }
+
+    fn in_offsets(&self) -> &[usize] {
+        self.slice(self.layout.in_offsets, self.layout.n + 1)
+    }
+
+    /// Retorna a quantidade de vértices no grafo.
+    pub fn vertices_length(&self) -> usize {
+        self.layout.n
+    }
+
+    /// Retorna a quantidade de arestas no grafo.
+    pub fn edges_length(&self) -> usize {
+        self.layout.m
+    }
+
+    /// Chaves de todos os vértices, em ordem crescente (a ordem dos índices)
+    /// ```
+    /// # use rust_graph::{DiGraph, graph_lib::{edge::Edge, csr::mapped_graph::MappedGraph}};
+    /// let mut graph: DiGraph<i128, u128> = DiGraph::new();
+    /// graph.add_edge(Edge::new_weighted(i128::MAX, -7, u128::MAX));
+    /// let path = std::env::temp_dir().join("rust_graph_mapped_i128_doc.bin");
+    /// graph.save_binary(path.to_str().unwrap()).unwrap();
+    ///
+    /// let mapped: MappedGraph<i128, u128> = unsafe { MappedGraph::open(path.to_str().unwrap()) }.unwrap();
+    /// assert_eq!(mapped.keys(), &[-7, i128::MAX]);
+    /// assert_eq!(mapped.weights(mapped.index_of(&i128::MAX).unwrap()), &[u128::MAX]);
+    /// # std::fs::remove_file(path).unwrap();
+    /// ```
+    pub fn keys(&self) -> &[K] {
+        self.slice(self.layout.keys, self.layout.n)
+    }
+
+    /// Índice do vértice de chave `key` (busca binária), se existir
+    pub fn index_of(&self, key: &K) -> Option<usize> {
+        self.keys().binary_search(key).ok()
+    }
+
+    /// Chave do vértice de índice `v`
+    ///
+    /// # Panics
+    /// Se `v >= vertices_length()`
+    pub fn key_of(&self, v: usize) -> &K {
+        &self.keys()[v]
+    }
+
+    /// Índices dos destinos das arestas que saem de `v`
+    pub fn neighbours(&self, v: usize) -> &[usize] {
+        let offsets = self.offsets();
+        &self.slice(self.layout.targets, self.layout.m)[offsets[v]..offsets[v + 1]]
+    }
+
+    /// Pesos das arestas que saem de `v`, alinhados com `neighbours(v)`
+    pub fn weights(&self, v: usize) -> &[W] {
+        let offsets = self.offsets();
+        &self.slice(self.layout.weights, self.layout.m)[offsets[v]..offsets[v + 1]]
+    }
+
+    /// Ids (no `DiGraph` salvo) das arestas que saem de `v`, alinhados com `neighbours(v)`
+    pub fn edge_ids(&self, v: usize) -> &[EdgeId] {
+        let offsets = self.offsets();
+        &self.slice(self.layout.edge_ids, self.layout.m)[offsets[v]..offsets[v + 1]]
+    }
+
+    /// Índices das origens das arestas que chegam em `v`
+    pub fn predecessors(&self, v: usize) -> &[usize] {
+        let in_offsets = self.in_offsets();
+        &self.slice(self.layout.in_sources, self.layout.m)[in_offsets[v]..in_offsets[v + 1]]
+    }
+
+    pub fn out_degree(&self, v: usize) -> usize {
+        let offsets = self.offsets();
+        offsets[v + 1] - offsets[v]
+    }
+
+    pub fn in_degree(&self, v: usize) -> usize {
+        let in_offsets = self.in_offsets();
+        in_offsets[v + 1] - in_offsets[v]
+    }
+
+    /// Copia o grafo para um `DiGraph` (ids novos)
+    pub fn to_digraph(&self) -> DiGraph<K, W> {
+        let keys = self.keys();
+        let mut graph = DiGraph::new_sized(keys.len() as u32);
+        for key in keys.iter() {
+            graph.add_vertice(*key);
+        }
+        for v in 0..keys.len() {
+            for (&w, &weight) in self.neighbours(v).iter().zip(self.weights(v)) {
+                graph.add_edge(Edge::new_weighted(keys[v], keys[w], weight));
+            }
+        }
+        graph
+    }
+}
+
+impl<K: VerticeKey + BinaryValue + Ord, W: Weight + BinaryValue> CsrAdjacency<W> for MappedGraph<K, W> {
+    fn vertices_length(&self) -> usize {
+        MappedGraph::vertices_length(self)
+    }
+
+    fn neighbours(&self, v: usize) -> &[usize] {
+        MappedGraph::neighbours(self, v)
+    }
+
+    fn weights(&self, v: usize) -> &[W] {
+        MappedGraph::weights(self, v)
+    }
+
+    fn predecessors(&self, v: usize) -> &[usize] {
+        MappedGraph::predecessors(self, v)
+    }
+}
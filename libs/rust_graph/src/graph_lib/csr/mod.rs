@@ -0,0 +1,20 @@
+pub mod csr_graph;
+pub mod mapped_graph;
+pub mod workspace;
+
+/// Adjacência somente leitura de um grafo em formato CSR, com vértices `0..n`
+///
+/// Implementado por `CsrGraph` e `MappedGraph`, para que os algoritmos de
+/// `CsrWorkspace` rodem nos dois.
+pub trait CsrAdjacency<W> {
+    fn vertices_length(&self) -> usize;
+
+    /// Índices dos destinos das arestas que saem de `v`
+    fn neighbours(&self, v: usize) -> &[usize];
+
+    /// Pesos das arestas que saem de `v`, alinhados com `neighbours(v)`
+    fn weights(&self, v: usize) -> &[W];
+
+    /// Índices das origens das arestas que chegam em `v`
+    fn predecessors(&self, v: usize) -> &[usize];
+}
@@ -0,0 +1,225 @@
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+use crate::tools::{inifinity::Infinity, weight::Weight};
+
+use super::CsrAdjacency;
+
+/// Entrada da fila de prioridade do Dijkstra, ordenada pela menor distância
+struct HeapEntry<W>(W, usize);
+
+impl<W: PartialOrd> PartialEq for HeapEntry<W> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<W: PartialOrd> Eq for HeapEntry<W> {}
+
+impl<W: PartialOrd> PartialOrd for HeapEntry<W> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<W: PartialOrd> Ord for HeapEntry<W> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // invertido: BinaryHeap é de máximo
+        other.0.partial_cmp(&self.0).unwrap_or(Ordering::Equal)
+    }
+}
+
+/// # CsrWorkspace
+/// Buffers reutilizáveis para os algoritmos sobre `CsrGraph` (ou `MappedGraph`)
+///
+/// Os vetores só crescem quando o grafo é maior que o da chamada anterior, então
+/// chamadas repetidas (por exemplo, um BFS por vértice) não alocam memória.
+/// Os resultados ficam no workspace até a próxima chamada e são indexados
+/// pelos índices densos do grafo.
+pub struct CsrWorkspace<W = i32> {
+    order: Vec<usize>,
+    pred: Vec<Option<usize>>,
+    dist: Vec<Infinity<W>>,
+    visited: Vec<bool>,
+    stack: Vec<(usize, usize)>,
+    heap: BinaryHeap<HeapEntry<W>>,
+    component: Vec<usize>,
+}
+
+impl<W: Weight> Default for CsrWorkspace<W> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[allow(unused)]
+impl<W: Weight> CsrWorkspace<W> {
+    pub fn new() -> CsrWorkspace<W> {
+        CsrWorkspace {
+            order: Vec::new(),
+            pred: Vec::new(),
+            dist: Vec::new(),
+            visited: Vec::new(),
+            stack: Vec::new(),
+            heap: BinaryHeap::new(),
+            component: Vec::new(),
+        }
+    }
+
+    /// Vértices na ordem em que foram descobertos pela última busca
+    /// (ou na ordem de término, após `kosaraju`)
+    pub fn order(&self) -> &[usize] {
+        &self.order
+    }
+
+    /// Predecessor de cada vértice na última busca (`None` para a origem e inalcançáveis)
+    pub fn pred(&self) -> &[Option<usize>] {
+        &self.pred
+    }
+
+    /// Distâncias calculadas pelo último `dijkstra`
+    pub fn dist(&self) -> &[Infinity<W>] {
+        &self.dist
+    }
+
+    /// Componente fortemente conexo de cada vértice, calculado pelo último `kosaraju`
+    pub fn component(&self) -> &[usize] {
+        &self.component
+    }
+
+    fn reset(&mut self, n: usize) {
+        self.order.clear();
+        self.stack.clear();
+        self.heap.clear();
+        self.pred.clear();
+        self.pred.resize(n, None);
+        self.visited.clear();
+        self.visited.resize(n, false);
+    }
+
+    /// Busca em largura a partir do vértice de índice `source`
+    ///
+    /// Retorna os vértices alcançados, na ordem de descoberta.
+    pub fn bfs<G: CsrAdjacency<W>>(&mut self, g: &G, source: usize) -> &[usize] {
+        self.reset(g.vertices_length());
+        self.visited[source] = true;
+        self.order.push(source);
+        // `order` funciona como a própria fila
+        let mut head = 0;
+        while head < self.order.len() {
+            let v = self.order[head];
+            head += 1;
+            for &w in g.neighbours(v) {
+                if !self.visited[w] {
+                    self.visited[w] = true;
+                    self.pred[w] = Some(v);
+                    self.order.push(w);
+                }
+            }
+        }
+        &self.order
+    }
+
+    /// Busca em profundidade a partir do vértice de índice `source`
+    ///
+    /// Retorna os vértices alcançados, na ordem de descoberta.
+    pub fn dfs<G: CsrAdjacency<W>>(&mut self, g: &G, source: usize) -> &[usize] {
+        self.reset(g.vertices_length());
+        self.visit_from(g, source, false);
+        &self.order
+    }
+
+    /// DFS iterativa: a pilha guarda (vértice, próximo vizinho a visitar)
+    ///
+    /// Com `post_order`, `order` recebe os vértices ao terminar em vez de ao descobrir.
+    fn visit_from<G: CsrAdjacency<W>>(&mut self, g: &G, source: usize, post_order: bool) {
+        self.visited[source] = true;
+        if !post_order {
+            self.order.push(source);
+        }
+        self.stack.push((source, 0));
+        while let Some((v, next)) = self.stack.pop() {
+            let neighbours = g.neighbours(v);
+            if next < neighbours.len() {
+                self.stack.push((v, next + 1));
+                let w = neighbours[next];
+                if !self.visited[w] {
+                    self.visited[w] = true;
+                    self.pred[w] = Some(v);
+                    if !post_order {
+                        self.order.push(w);
+                    }
+                    self.stack.push((w, 0));
+                }
+            } else if post_order {
+                self.order.push(v);
+            }
+        }
+    }
+
+    /// Caminho mínimo de Dijkstra a partir do vértice de índice `source`
+    ///
+    /// Os pesos devem ser não negativos. Retorna as distâncias por índice.
+    pub fn dijkstra<G: CsrAdjacency<W>>(&mut self, g: &G, source: usize) -> &[Infinity<W>] {
+        let n = g.vertices_length();
+        self.reset(n);
+        self.dist.clear();
+        self.dist.resize(n, Infinity::Infinite);
+
+        self.dist[source] = Infinity::Number(W::zero());
+        self.heap.push(HeapEntry(W::zero(), source));
+        while let Some(HeapEntry(d, v)) = self.heap.pop() {
+            if self.visited[v] {
+                continue;
+            }
+            self.visited[v] = true;
+            self.order.push(v);
+            for (&w, &weight) in g.neighbours(v).iter().zip(g.weights(v)) {
+                let candidate = d + weight;
+                if self.dist[w] > Infinity::Number(candidate) {
+                    self.dist[w] = Infinity::Number(candidate);
+                    self.pred[w] = Some(v);
+                    self.heap.push(HeapEntry(candidate, w));
+                }
+            }
+        }
+        &self.dist
+    }
+
+    /// Componentes fortemente conexos pelo método de Kosaraju
+    ///
+    /// Retorna a quantidade de componentes; o componente de cada vértice fica em `component()`.
+    pub fn kosaraju<G: CsrAdjacency<W>>(&mut self, g: &G) -> usize {
+        let n = g.vertices_length();
+        self.reset(n);
+
+        // Primeira passada: ordem de término no grafo original
+        for v in 0..n {
+            if !self.visited[v] {
+                self.visit_from(g, v, true);
+            }
+        }
+
+        // Segunda passada: grafo transposto em ordem decrescente de término
+        self.component.clear();
+        self.component.resize(n, usize::MAX);
+        let mut count = 0;
+        for i in (0..n).rev() {
+            let root = self.order[i];
+            if self.component[root] != usize::MAX {
+                continue;
+            }
+            self.component[root] = count;
+            self.stack.push((root, 0));
+            while let Some((v, _)) = self.stack.pop() {
+                for &u in g.predecessors(v) {
+                    if self.component[u] == usize::MAX {
+                        self.component[u] = count;
+                        self.stack.push((u, 0));
+                    }
+                }
+            }
+            count += 1;
+        }
+        count
+    }
+}
@@ -1,84 +1,262 @@
-use std::sync::atomic::AtomicUsize;
+use std::{collections::BTreeMap, fmt::Display};
 
+use crate::tools::weight::Weight;
 
-#[derive(Clone, PartialEq, Eq, Hash)]
-pub struct Edge {
-    destiny_key: i32,
-    origin_key: i32,
-    weight: i32,
-    id: usize,
+use super::vertice::VerticeKey;
+
+/// Identificador de uma aresta dentro de um grafo
+///
+/// Cada grafo distribui seus próprios ids de forma densa (0, 1, 2, ...) na ordem
+/// em que as arestas são adicionadas, então o id só tem significado no grafo que o gerou.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct EdgeId(usize);
+
+impl EdgeId {
+    /// Id de uma aresta que ainda não foi adicionada a nenhum grafo
+    pub const UNASSIGNED: EdgeId = EdgeId(usize::MAX);
+
+    pub fn new(index: usize) -> EdgeId {
+        EdgeId(index)
+    }
+
+    /// Posição da aresta na ordem de inserção do grafo
+    pub fn index(&self) -> usize {
+        self.0
+    }
 }
-#[allow(unused)]
-impl Edge {
-    // Utilizando AtomicUsize para garantir a thread-safety do contador de arestas
-    fn next_id() -> usize {
-        static EDGE_COUNTER: AtomicUsize = AtomicUsize::new(0);
-        EDGE_COUNTER.fetch_add(1, std::sync::atomic::Ordering::SeqCst)
+
+impl Display for EdgeId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Nome de um atributo numérico de uma aresta
+///
+/// `Weight` é sempre o peso da aresta; os demais são opcionais.
+///
+/// Com a feature `serde` o atributo é serializado como texto: o nome da variante
+/// (`"Capacity"`, ...) ou o próprio nome de `Named`. Para desserializar um `Named` o nome
+/// precisa ter sido registrado com [`EdgeAttribute::register_name`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum EdgeAttribute {
+    Weight,
+    Capacity,
+    LowerBound,
+    Cost,
+    Named(&'static str),
+}
+
+/// Nomes de `EdgeAttribute::Named` aceitos na desserialização
+#[cfg(feature = "serde")]
+static NAMES: std::sync::Mutex<std::collections::BTreeSet<&'static str>> =
+    std::sync::Mutex::new(std::collections::BTreeSet::new());
+
+impl EdgeAttribute {
+    /// Registra o nome de um atributo `Named` para que ele possa ser desserializado
+    ///
+    /// Os nomes vêm do programa (são `&'static str`), então a entrada não confiável não
+    /// aloca nada que fique para sempre na memória: nomes não registrados são um erro.
+    /// ```
+    /// # use rust_graph::graph_lib::edge::EdgeAttribute;
+    /// const DELAY: EdgeAttribute = EdgeAttribute::Named("delay");
+    /// EdgeAttribute::register_name("delay");
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn register_name(name: &'static str) {
+        NAMES.lock().unwrap_or_else(|e| e.into_inner()).insert(name);
     }
 
-    pub fn new(origin_vertice: i32, destiny_vertice: i32) -> Edge {
+    /// Retorna um seletor que lê este atributo das arestas, para os algoritmos
+    /// que recebem o custo/capacidade por closure
+    ///
+    /// Arestas sem o atributo valem `default` (por exemplo, capacidade 0 deixa a aresta
+    /// fora do fluxo).
+    /// ```
+    /// # use rust_graph::{DiGraph, graph_lib::{edge::{Edge, EdgeAttribute}, flux::ford_fulkerson::max_flux_by}};
+    /// let mut graph: DiGraph = DiGraph::new();
+    /// graph.add_edge(Edge::new_weighted(0, 1, 7).with_attribute(EdgeAttribute::Capacity, 3));
+    /// graph.add_edge(Edge::new_weighted(1, 2, 7));
+    /// let (flux, _) = max_flux_by(&graph, 0, 2, EdgeAttribute::Capacity.selector_or(0)).unwrap();
+    /// assert_eq!(flux.get_max_flux(), 0);
+    /// ```
+    pub fn selector_or<K: VerticeKey, W: Weight>(self, default: W) -> impl Fn(&Edge<K, W>) -> W {
+        move |e: &Edge<K, W>| e.attribute(self).unwrap_or(default)
+    }
+}
+
+/// Aresta direcionada `origin_key -> destiny_key` com peso genérico `W`
+///
+/// O `id` é atribuído pelo grafo quando a aresta é adicionada; antes disso vale `EdgeId::UNASSIGNED`.
+///
+/// Além do peso, a aresta pode guardar outros atributos numéricos (`EdgeAttribute`),
+/// como capacidade e custo, de forma que o mesmo grafo sirva para vários algoritmos.
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(deserialize = "K: serde::Deserialize<'de>, W: serde::Deserialize<'de>"))
+)]
+pub struct Edge<K = i32, W = i32> {
+    destiny_key: K,
+    origin_key: K,
+    weight: W,
+    id: EdgeId,
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "BTreeMap::is_empty"))]
+    attributes: BTreeMap<EdgeAttribute, W>,
+}
+#[allow(unused)]
+impl<K: VerticeKey, W: Weight> Edge<K, W> {
+    pub fn new(origin_vertice: K, destiny_vertice: K) -> Edge<K, W> {
         Edge {
-            id: Self::next_id(),
+            id: EdgeId::UNASSIGNED,
             destiny_key: destiny_vertice,
             origin_key: origin_vertice,
-            weight: 1,
+            weight: W::one(),
+            attributes: BTreeMap::new(),
         }
     }
 
-    pub fn new_weighted(origin_vertice: i32, destiny_vertice: i32, weight: i32) -> Edge {
+    pub fn new_weighted(origin_vertice: K, destiny_vertice: K, weight: W) -> Edge<K, W> {
         Edge {
-            id: Self::next_id(),
+            id: EdgeId::UNASSIGNED,
             destiny_key: destiny_vertice,
             origin_key: origin_vertice,
             weight,
+            attributes: BTreeMap::new(),
         }
     }
 
-    pub fn destiny_key(&self) -> i32 {
-        self.destiny_key
+    /// Retorna a mesma aresta com o atributo `attribute` definido como `value`
+    pub fn with_attribute(mut self, attribute: EdgeAttribute, value: W) -> Edge<K, W> {
+        self.set_attribute(attribute, value);
+        self
     }
-    pub fn origin_key(&self) -> i32 {
-        self.origin_key
+
+    /// Cria a aresta `destiny_key -> origin_key` com o mesmo peso e atributos, sem id
+    pub fn reversed(&self) -> Edge<K, W> {
+        Edge {
+            id: EdgeId::UNASSIGNED,
+            destiny_key: self.origin_key.clone(),
+            origin_key: self.destiny_key.clone(),
+            weight: self.weight,
+            attributes: self.attributes.clone(),
+        }
+    }
+
+    pub fn destiny_key(&self) -> K {
+        self.destiny_key.clone()
+    }
+    pub fn origin_key(&self) -> K {
+        self.origin_key.clone()
     }
-    pub fn id(&self) -> usize {
+    pub fn id(&self) -> EdgeId {
         self.id
     }
 
-    pub fn origin_key_ref(&self) -> &i32 {
+    pub(crate) fn set_id(&mut self, id: EdgeId) {
+        self.id = id;
+    }
+
+    pub fn origin_key_ref(&self) -> &K {
         &self.origin_key
     }
-    pub fn destiny_key_ref(&self) -> &i32 {
+    pub fn destiny_key_ref(&self) -> &K {
         &self.destiny_key
     }
 
-    pub fn weight(&self) -> i32 {
+    pub fn weight(&self) -> W {
         self.weight
     }
 
-    pub fn set_weight(&mut self, weight: i32) {
+    pub fn set_weight(&mut self, weight: W) {
         self.weight = weight;
     }
 
-    pub fn v_w(&self) -> (i32,i32) {
-        (self.origin_key, self.destiny_key)
+    pub fn v_w(&self) -> (K, K) {
+        (self.origin_key.clone(), self.destiny_key.clone())
+    }
+
+    /// Obtém o valor de um atributo, se a aresta o possuir
+    pub fn attribute(&self, attribute: EdgeAttribute) -> Option<W> {
+        match attribute {
+            EdgeAttribute::Weight => Some(self.weight),
+            _ => self.attributes.get(&attribute).copied(),
+        }
+    }
+
+    /// Define o valor de um atributo, retornando o valor anterior se existir
+    pub fn set_attribute(&mut self, attribute: EdgeAttribute, value: W) -> Option<W> {
+        match attribute {
+            EdgeAttribute::Weight => Some(std::mem::replace(&mut self.weight, value)),
+            _ => self.attributes.insert(attribute, value),
+        }
+    }
+
+    /// Remove um atributo da aresta
+    ///
+    /// O peso (`EdgeAttribute::Weight`) não pode ser removido e retorna `None`.
+    pub fn remove_attribute(&mut self, attribute: EdgeAttribute) -> Option<W> {
+        self.attributes.remove(&attribute)
     }
 
+    /// Itera sobre os atributos da aresta, sem incluir o peso
+    pub fn attributes(&self) -> impl Iterator<Item = (EdgeAttribute, W)> + '_ {
+        self.attributes.iter().map(|(a, v)| (*a, *v))
+    }
 }
 
-impl PartialOrd for Edge {
+impl<K: PartialEq, W: PartialOrd> PartialOrd for Edge<K, W> {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
+        self.weight.partial_cmp(&other.weight)
     }
 }
 
-impl Ord for Edge {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.weight.cmp(&other.weight)
+impl<K: std::fmt::Debug, W: std::fmt::Debug> std::fmt::Debug for Edge<K, W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:?} -> {:?} w {:?} id {}",
+            self.origin_key, self.destiny_key, self.weight, self.id
+        )
     }
 }
 
-impl std::fmt::Debug for Edge {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} -> {} w {} id {}", self.origin_key, self.destiny_key,self.weight, self.id)
+#[cfg(feature = "serde")]
+impl serde::Serialize for EdgeAttribute {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(match self {
+            EdgeAttribute::Weight => "Weight",
+            EdgeAttribute::Capacity => "Capacity",
+            EdgeAttribute::LowerBound => "LowerBound",
+            EdgeAttribute::Cost => "Cost",
+            EdgeAttribute::Named(name) => name,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for EdgeAttribute {
+    /// Nomes que não são de uma variante viram `Named` se tiverem sido registrados com
+    /// [`EdgeAttribute::register_name`]; os demais são um erro.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Ok(match name.as_str() {
+            "Weight" => EdgeAttribute::Weight,
+            "Capacity" => EdgeAttribute::Capacity,
+            "LowerBound" => EdgeAttribute::LowerBound,
+            "Cost" => EdgeAttribute::Cost,
+            _ => {
+                let names = NAMES.lock().unwrap_or_else(|e| e.into_inner());
+                let registered = names.get(name.as_str()).ok_or_else(|| {
+                    serde::de::Error::custom(format!(
+                        "unknown edge attribute {name:?}, register it with EdgeAttribute::register_name"
+                    ))
+                })?;
+                EdgeAttribute::Named(registered)
+            }
+        })
     }
 }
@@ -0,0 +1,95 @@
+use std::{error::Error, fmt::Display, io};
+
+use super::edge::EdgeId;
+
+/// # GraphError
+/// Erros retornados pelas APIs públicas da biblioteca
+///
+/// As funções que podem falhar retornam `Result<_, GraphError>` em vez de imprimir
+/// mensagens ou entrar em pânico.
+#[derive(Debug)]
+pub enum GraphError {
+    /// Falha de leitura/escrita de arquivo ou ao executar um programa externo
+    Io(io::Error),
+    /// Conteúdo inválido na linha `line` (começando em 1) de um arquivo ou texto
+    Parse { line: usize, content: String },
+    /// Um vértice necessário não existe no grafo (chave formatada com `Debug`)
+    MissingVertex(String),
+    /// A capacidade da aresta é negativa
+    NegativeCapacity(EdgeId),
+    /// Tentativa de definir um fluxo acima da capacidade da aresta
+    FluxAboveCapacity { flux: String, capacity: String },
+    /// As quantidades de vértices e arestas declaradas no cabeçalho de um arquivo
+    /// não batem com o conteúdo
+    HeaderMismatch {
+        declared: (usize, usize),
+        found: (usize, usize),
+    },
+    /// O grafo possui um ciclo de custo negativo
+    NegativeCycle,
+    /// Um programa externo (ex.: `dot`) terminou com erro
+    ExternalTool { tool: String, message: String },
+    /// Um snapshot binário é inválido (cabeçalho, tamanho, tipos ou checksum)
+    InvalidSnapshot(String),
+    /// Uma sequência de graus não pode ser realizada por um grafo simples
+    NotGraphical(String),
+    /// A aresta ainda não foi adicionada a um grafo e não tem id
+    UnassignedEdge,
+    /// O id é grande demais para um mapa indexado por `EdgeId` (provavelmente de outro grafo)
+    EdgeIdOutOfRange(EdgeId),
+}
+
+impl GraphError {
+    pub(crate) fn missing_vertex<K: std::fmt::Debug>(key: &K) -> GraphError {
+        GraphError::MissingVertex(format!("{:?}", key))
+    }
+
+    pub(crate) fn parse(line: usize, content: &str) -> GraphError {
+        GraphError::Parse {
+            line,
+            content: content.to_string(),
+        }
+    }
+}
+
+impl Display for GraphError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GraphError::Io(err) => write!(f, "io error: {err}"),
+            GraphError::Parse { line, content } => {
+                write!(f, "parse error at line {line}: {content:?}")
+            }
+            GraphError::MissingVertex(key) => write!(f, "vertex {key} does not exist in graph"),
+            GraphError::NegativeCapacity(id) => write!(f, "edge {id} has negative capacity"),
+            GraphError::FluxAboveCapacity { flux, capacity } => {
+                write!(f, "flux {flux} is above the capacity {capacity}")
+            }
+            GraphError::HeaderMismatch { declared, found } => write!(
+                f,
+                "header declares {} vertices and {} edges, found {} and {}",
+                declared.0, declared.1, found.0, found.1
+            ),
+            GraphError::NegativeCycle => write!(f, "graph has a negative cycle"),
+            GraphError::ExternalTool { tool, message } => write!(f, "{tool} failed: {message}"),
+            GraphError::InvalidSnapshot(reason) => write!(f, "invalid binary snapshot: {reason}"),
+            GraphError::NotGraphical(reason) => write!(f, "degree sequence is not graphical: {reason}"),
+            GraphError::UnassignedEdge => write!(f, "edge has no id, it was never added to a graph"),
+            GraphError::EdgeIdOutOfRange(id) => write!(f, "edge id {id} is too large for an edge map"),
+        }
+    }
+}
+
+impl Error for GraphError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            GraphError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for GraphError {
+    fn from(err: io::Error) -> Self {
+        GraphError::Io(err)
+    }
+}
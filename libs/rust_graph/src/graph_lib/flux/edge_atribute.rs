@@ -1,55 +1,78 @@
-use crate::graph_lib::edge::Edge;
+use crate::{
+    graph_lib::{edge::Edge, vertice::VerticeKey},
+    tools::weight::Weight,
+    GraphError,
+};
 
 /// Define atributos das arestas para a implementação do fluxo máximo
 /// de Ford-Fulkerson
 #[derive(Debug, Clone)]
-pub struct EdgeAtt {
-    flux: u32,
-    capacity: u32,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EdgeAtt<W = i32> {
+    flux: W,
+    capacity: W,
 }
 
-impl EdgeAtt {
+impl<W: Weight> EdgeAtt<W> {
     /// Cria um novo `EdgeAtt` a partir de uma aresta
     ///
     /// `flux` é inicializado com 0
     ///
     /// `capacity` é inicializado com o peso da aresta
     ///
-    /// # Panics
-    ///
-    /// Se ``e.weight() < 0``
-    pub fn from_edge(e: &Edge) -> Self {
-        let flux = 0;
-        let capacity: u32 = if e.weight() >= 0 {
-            e.weight() as u32
-        } else {
-            panic!("Edge weight is negative")
-        };
-        EdgeAtt { flux, capacity }
+    /// # Errors
+    ///
+    /// `GraphError::NegativeCapacity` se ``e.weight() < 0``
+    pub fn from_edge<K: VerticeKey>(e: &Edge<K, W>) -> Result<Self, GraphError> {
+        Self::from_edge_with_capacity(e, e.weight())
+    }
+
+    /// Cria um novo `EdgeAtt` para a aresta `e` com a capacidade dada e fluxo 0
+    ///
+    /// # Errors
+    ///
+    /// `GraphError::NegativeCapacity` se ``capacity < 0``
+    pub fn from_edge_with_capacity<K: VerticeKey>(
+        e: &Edge<K, W>,
+        capacity: W,
+    ) -> Result<Self, GraphError> {
+        if capacity < W::zero() {
+            return Err(GraphError::NegativeCapacity(e.id()));
+        }
+        Ok(EdgeAtt {
+            flux: W::zero(),
+            capacity,
+        })
     }
 
     /// Define o fluxo da aresta
     ///
-    /// Panica se o fluxo exceder a capacidade
-    pub fn set_flux(&mut self, flux: u32) {
+    /// # Errors
+    ///
+    /// `GraphError::FluxAboveCapacity` se o fluxo exceder a capacidade
+    pub fn set_flux(&mut self, flux: W) -> Result<(), GraphError> {
         if self.capacity < flux {
-            panic!("Flux is above the capacity");
+            return Err(GraphError::FluxAboveCapacity {
+                flux: flux.to_string(),
+                capacity: self.capacity.to_string(),
+            });
         }
         self.flux = flux;
+        Ok(())
     }
 
     /// Obtém o fluxo da aresta
-    pub fn get_flux(&self) -> u32 {
+    pub fn get_flux(&self) -> W {
         self.flux
     }
 
     /// Obtém a capacidade da aresta
-    pub fn get_capacity(&self) -> u32 {
+    pub fn get_capacity(&self) -> W {
         self.capacity
     }
 
     /// Retorna uma tupla (fluxo, capacidade)
-    pub fn tuple(&self) -> (u32, u32) {
+    pub fn tuple(&self) -> (W, W) {
         (self.flux, self.capacity)
     }
-}
\ No newline at end of file
+}
@@ -1,65 +1,113 @@
 use std::collections::HashMap;
 
-use crate::graph_lib::{edge::Edge, view::{self, GraphPainter}};
+use crate::{
+    graph_lib::{
+        edge::{Edge, EdgeId},
+        vertice::VerticeKey,
+        view::{self, GraphPainter},
+    },
+    tools::weight::Weight,
+    GraphError,
+};
 
 use super::edge_atribute::EdgeAtt;
 
 
 // Implementação do mapa de fluxo
 ///
-/// tuple `(i32, i32)` -> chave para uma aresta (v, w)
+/// `EdgeId` -> chave para uma aresta do grafo original, o que distingue arestas paralelas
 ///
 /// `EdgeAtt` -> atributos da aresta
-pub struct FluxMap {
-    map: HashMap<(i32, i32), EdgeAtt>, // Mapa (v, w) para atributos de aresta
-    s_t: (i32, i32),                   // source e terminal
-    max_flux: i32,
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(
+        serialize = "K: VerticeKey + serde::Serialize, W: Weight + serde::Serialize",
+        deserialize = "K: VerticeKey + serde::Deserialize<'de>, W: Weight + serde::Deserialize<'de>"
+    ))
+)]
+pub struct FluxMap<K = i32, W = i32> {
+    map: HashMap<EdgeId, (Edge<K, W>, EdgeAtt<W>)>, // Mapa id para a aresta e seus atributos
+    s_t: (K, K),                                    // source e terminal
+    max_flux: W,
 }
 
-impl FluxMap {
+impl<K: VerticeKey, W: Weight> FluxMap<K, W> {
     /// Cria um novo mapa de fluxo a partir de um grafo
     ///
     /// Todos os valores de fluxo são inicializados com 0
-    pub fn from_edges(edges: &Vec<Edge>, s_t: (i32, i32)) -> Self {
+    ///
+    /// # Errors
+    ///
+    /// `GraphError::NegativeCapacity` se alguma aresta tiver peso negativo
+    pub fn from_edges(edges: &[Edge<K, W>], s_t: (K, K)) -> Result<Self, GraphError> {
+        Self::from_edges_by(edges, s_t, |e| e.weight())
+    }
+
+    /// Cria um novo mapa de fluxo usando `capacity` para obter a capacidade de cada aresta
+    pub fn from_edges_by(
+        edges: &[Edge<K, W>],
+        s_t: (K, K),
+        capacity: impl Fn(&Edge<K, W>) -> W,
+    ) -> Result<Self, GraphError> {
         let mut flux_map = HashMap::new();
         for e in edges {
-            let (v, w) = (e.origin_key(), e.destiny_key());
-            flux_map.insert((v, w), EdgeAtt::from_edge(e));
+            let att = EdgeAtt::from_edge_with_capacity(e, capacity(e))?;
+            flux_map.insert(e.id(), (e.clone(), att));
         }
-        FluxMap {
+        Ok(FluxMap {
             map: flux_map,
             s_t,
-            max_flux: 0,
-        }
+            max_flux: W::zero(),
+        })
     }
 
     /// Obtém uma referência imutável para os atributos de uma aresta
-    pub fn get(&self, key: &(i32, i32)) -> Option<&EdgeAtt> {
-        self.map.get(key)
+    pub fn get(&self, id: &EdgeId) -> Option<&EdgeAtt<W>> {
+        self.map.get(id).map(|(_, att)| att)
     }
 
     /// Obtém uma referência mutável para os atributos de uma aresta
-    pub fn get_mut(&mut self, key: &(i32, i32)) -> Option<&mut EdgeAtt> {
-        self.map.get_mut(key)
+    pub fn get_mut(&mut self, id: &EdgeId) -> Option<&mut EdgeAtt<W>> {
+        self.map.get_mut(id).map(|(_, att)| att)
+    }
+
+    /// Obtém a aresta original de id `id`
+    pub fn edge(&self, id: &EdgeId) -> Option<&Edge<K, W>> {
+        self.map.get(id).map(|(e, _)| e)
     }
 
     /// Obtém o fluxo máximo
-    pub fn get_max_flux(&self) -> i32 {
+    pub fn get_max_flux(&self) -> W {
         self.max_flux
     }
 
-    pub fn set_max_flux(&mut self, max_flux: i32) {
+    pub fn set_max_flux(&mut self, max_flux: W) {
         self.max_flux = max_flux;
     }
 
+    /// Retorna as arestas utilizadas com seus respectivos fluxos
+    pub fn get_used_edges(&self) -> Vec<(Edge<K, W>, EdgeAtt<W>)> {
+        let mut edges = vec![];
+        for (e, att) in self.map.values() {
+            if att.get_flux() > W::zero() {
+                edges.push((e.clone(), att.clone()));
+            }
+        }
+        edges
+    }
+}
+
+impl<W: Weight> FluxMap<i32, W> {
     /// Converte o mapa de fluxo para um `GraphPainter` para visualização
     pub fn to_graph_painter(&self) -> GraphPainter {
         let mut painter = GraphPainter::new();
         let (base, antibase) = self.s_t;
 
-        for ((v, w), att) in self.map.iter() {
+        for (e, att) in self.map.values() {
+            let (v, w) = (e.origin_key_ref(), e.destiny_key_ref());
             painter.add_edge(*v, *w, None, None);
-            if att.get_flux() != 0 {
+            if att.get_flux() != W::zero() {
                 painter.update_vertice_color(*v, view::Color::Yellow);
                 let vertice_label = format!("{}", v);
                 painter.update_vertice_label(*v, vertice_label);
@@ -75,16 +123,4 @@ impl FluxMap {
         painter.update_vertice_label(antibase, vertice_label);
         painter
     }
-
-    /// Retorna as arestas utilizadas com seus respectivos fluxos
-    pub fn get_used_edges(&self) -> Vec<(Edge, EdgeAtt)> {
-        let mut edges = vec![];
-        for ((v, w), att) in self.map.iter() {
-            if att.get_flux() > 0 {
-                let e = Edge::new_weighted(*v, *w, att.get_capacity() as i32);
-                edges.push((e, att.clone()));
-            }
-        }
-        edges
-    }
-}
\ No newline at end of file
+}
@@ -1,11 +1,11 @@
-use std::{collections::HashMap, fmt::format, hash::Hash};
-
-use comfy_table::Color;
+use std::collections::HashMap;
 
 use crate::{
-    graph_lib::edge::Edge,
+    graph_lib::edge::{Edge, EdgeId},
+    graph_lib::vertice::VerticeKey,
     graph_lib::view::{self, GraphPainter},
-    DiGraph,
+    tools::weight::Weight,
+    DiGraph, GraphError,
 };
 
 use super::flux_map::FluxMap;
@@ -21,59 +21,71 @@ use super::flux_map::FluxMap;
 /// `graph` o próprio grafo modificado com as arestas invertidas
 ///
 /// `edge_inverted` mapa de arestas invertidas para true se invertida e false para não invertida
-pub struct ResidualGraph {
-    graph: DiGraph,
-    edge_inverted: HashMap<Edge, bool>, // Mapa Edge para bool indicando se está invertida
-    original_edge: HashMap<Edge, Edge>, // Mapa Edge residual para Edge original
+///
+/// `original_edge` mapa do id da aresta residual para o id da aresta no grafo original
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(
+        serialize = "K: VerticeKey + serde::Serialize, W: Weight + serde::Serialize",
+        deserialize = "K: VerticeKey + serde::Deserialize<'de>, W: Weight + serde::Deserialize<'de>"
+    ))
+)]
+pub struct ResidualGraph<K = i32, W = i32> {
+    graph: DiGraph<K, W>,
+    edge_inverted: HashMap<EdgeId, bool>, // Mapa id residual para bool indicando se está invertida
+    original_edge: HashMap<EdgeId, EdgeId>, // Mapa id residual para id original
 }
 
-impl ResidualGraph {
+/// Resultado do fluxo máximo: o mapa de fluxo e o grafo residual final
+pub type MaxFlux<K = i32, W = i32> = (FluxMap<K, W>, ResidualGraph<K, W>);
+
+impl<K: VerticeKey, W: Weight> ResidualGraph<K, W> {
     /// Cria um grafo residual a partir de um grafo original e um mapa de fluxo
-    fn from_graph(g: &DiGraph, flux_map: &FluxMap) -> Self {
-        let mut map: HashMap<Edge, bool> = HashMap::new();
-        let mut original_edge: HashMap<Edge, Edge> = HashMap::new();
-        let mut edges = Vec::<Edge>::new();
+    fn from_graph(g: &DiGraph<K, W>, flux_map: &FluxMap<K, W>) -> Self {
+        let mut map: HashMap<EdgeId, bool> = HashMap::new();
+        let mut original_edge: HashMap<EdgeId, EdgeId> = HashMap::new();
+        let mut graph = DiGraph::new_sized(g.vertices_length() as u32);
 
         for e in g.all_edges() {
             let (v, w) = e.v_w();
-            // Obter os atributos da aresta (v, w)
-            let Some(att) = flux_map.get(&(v, w)) else {
-                panic!("Aresta ({}, {}) não encontrada no FluxMap", v, w);
+            // Obter os atributos da aresta
+            let Some(att) = flux_map.get(&e.id()) else {
+                panic!("Aresta {} não encontrada no FluxMap", e.id());
             };
             let (flux, capacity) = att.tuple();
 
-            if flux > 0 {
+            if flux > W::zero() {
                 // Adiciona a aresta invertida
-                let inverted_edge = Edge::new_weighted(w, v, flux as i32);
-                edges.push(inverted_edge.clone());
-                map.insert(inverted_edge.clone(), true);
-                original_edge.insert(inverted_edge, e.clone());
+                let inverted_id = graph.add_edge(Edge::new_weighted(w.clone(), v.clone(), flux));
+                map.insert(inverted_id, true);
+                original_edge.insert(inverted_id, e.id());
             }
 
             if capacity > flux {
                 // Adiciona a aresta residual
-                let residual_edge = Edge::new_weighted(v, w, (capacity - flux) as i32);
-                edges.push(residual_edge.clone());
-                map.insert(residual_edge.clone(), false);
-                original_edge.insert(residual_edge, e.clone());
+                let residual_id = graph.add_edge(Edge::new_weighted(v, w, capacity - flux));
+                map.insert(residual_id, false);
+                original_edge.insert(residual_id, e.id());
             }
         }
 
-        let graph = DiGraph::from_edges(edges);
         ResidualGraph {
             graph,
             edge_inverted: map,
             original_edge,
         }
     }
+}
 
+impl<W: Weight> ResidualGraph<i32, W> {
     /// Converte o grafo residual para um `GraphPainter` para visualização
     pub fn to_graph_painter(&self) -> GraphPainter {
         let mut painter = GraphPainter::from_digraph(&self.graph);
 
         for e in self.graph.all_edges() {
             let (v, w) = (e.origin_key(), e.destiny_key());
-            if let Some(is_inverted) = self.edge_inverted.get(&e) {
+            if let Some(is_inverted) = self.edge_inverted.get(&e.id()) {
                 if *is_inverted {
                     painter.update_edge_color(v, w, view::Color::Red);
                 } else {
@@ -87,12 +99,36 @@ impl ResidualGraph {
 
 
 
-pub fn max_flux_multi_s_t(
-    g: &DiGraph,
+pub fn max_flux_multi_s_t<W: Weight>(
+    g: &DiGraph<i32, W>,
+    sources: Vec<i32>,
+    terminals: Vec<i32>,
+) -> Result<MaxFlux<i32, W>, GraphError> {
+    max_flux_multi_s_t_by(g, sources, terminals, |e| e.weight())
+}
+
+/// Fluxo máximo com várias fontes e terminais, usando `capacity` para obter a capacidade de cada aresta
+///
+/// As arestas do `FluxMap` retornado têm como peso a capacidade selecionada.
+///
+/// # Errors
+///
+/// `GraphError::MissingVertex` se alguma fonte ou terminal não existir e
+/// `GraphError::NegativeCapacity` se alguma capacidade for negativa
+pub fn max_flux_multi_s_t_by<W: Weight>(
+    g: &DiGraph<i32, W>,
     sources: Vec<i32>,
     terminals: Vec<i32>,
-) -> (FluxMap, ResidualGraph) {
+    capacity: impl Fn(&Edge<i32, W>) -> W,
+) -> Result<MaxFlux<i32, W>, GraphError> {
+    if let Some(missing) = sources.iter().chain(terminals.iter()).find(|&&v| !g.vertice_exists(v)) {
+        return Err(GraphError::missing_vertex(missing));
+    }
     let mut graph = g.clone();
+    graph.mut_edges(|e| {
+        let c = capacity(e);
+        e.set_weight(c);
+    });
 
     let max_source = graph.unused_v_key_from(graph.vertices_length() as i32);
     graph.add_vertice(max_source);
@@ -101,17 +137,17 @@ pub fn max_flux_multi_s_t(
     graph.add_vertice(max_terminal);
 
     for &s in sources.iter() {
-        let mut edge_weight = 0;
+        let mut edge_weight = W::zero();
         for edge in graph.edges_of(s).iter().flatten() {
-            edge_weight += edge.weight();
+            edge_weight = edge_weight + edge.weight();
         }
         graph.add_edge(Edge::new_weighted(s, max_source, edge_weight));
     }
 
     for &t in terminals.iter() {
-        let mut edge_weight = 0;
+        let mut edge_weight = W::zero();
         for p in graph.predecessor_edges(t).iter().flatten() {
-            edge_weight += p.weight()
+            edge_weight = edge_weight + p.weight()
         }
         graph.add_edge(Edge::new_weighted(t, max_terminal, edge_weight));
     }
@@ -120,72 +156,101 @@ pub fn max_flux_multi_s_t(
 }
 
 #[allow(unused)]
-pub fn max_flux(g: &DiGraph, s: i32, t: i32) -> (FluxMap, ResidualGraph) {
-    let mut flux_map = FluxMap::from_edges(&g.all_edges(), (s, t));
+pub fn max_flux<K: VerticeKey, W: Weight>(
+    g: &DiGraph<K, W>,
+    s: K,
+    t: K,
+) -> Result<MaxFlux<K, W>, GraphError> {
+    max_flux_by(g, s, t, |e| e.weight())
+}
+
+/// Fluxo máximo de `s` a `t` usando `capacity` para obter a capacidade de cada aresta
+///
+/// Por exemplo, `max_flux_by(&g, s, t, EdgeAttribute::Capacity.selector_or(0))`
+/// usa a capacidade guardada na aresta, deixando o peso livre para o custo.
+///
+/// # Errors
+///
+/// `GraphError::MissingVertex` se `s` ou `t` não existirem e
+/// `GraphError::NegativeCapacity` se alguma capacidade for negativa
+#[allow(unused)]
+pub fn max_flux_by<K: VerticeKey, W: Weight>(
+    g: &DiGraph<K, W>,
+    s: K,
+    t: K,
+    capacity: impl Fn(&Edge<K, W>) -> W,
+) -> Result<MaxFlux<K, W>, GraphError> {
+    for v in [&s, &t] {
+        if !g.vertice_exists(v.clone()) {
+            return Err(GraphError::missing_vertex(v));
+        }
+    }
+    let mut flux_map = FluxMap::from_edges_by(&g.all_edges(), (s.clone(), t.clone()), capacity)?;
 
     let mut residual_graph = ResidualGraph::from_graph(g, &flux_map);
 
-    let mut increasing_path = IncreasingPath::from_residual_graph(&residual_graph, s, t);
+    let mut increasing_path =
+        IncreasingPath::from_residual_graph(&residual_graph, s.clone(), t.clone());
 
     // se tiver um caminho aumentante
     while let Some(ref path) = increasing_path {
         for e in &path.edges {
-            let (v, w) = (e.v_w());
             //flag se a aresta residual é invertida
             let is_inverted = *residual_graph
                 .edge_inverted
-                .get(e)
+                .get(&e.id())
                 .expect("Aresta não encontrada no ResidualGraph");
             //buscar a aresta correspondente no grafo original
             let original_edge = residual_graph
                 .original_edge
-                .get(e)
+                .get(&e.id())
                 .expect("Aresta não encontrada no ResidualGraph");
 
             // pega o objeto atributo da aresta para modificações
             let att = flux_map
-                .get_mut(&original_edge.v_w())
+                .get_mut(original_edge)
                 .expect("Aresta original não encontrada no FluxMap");
 
             if is_inverted {
                 // Reduz o fluxo na aresta original se for invertida
-                att.set_flux(att.get_flux() - path.gargalo);
+                att.set_flux(att.get_flux() - path.gargalo)?;
             } else {
                 // Aumenta o fluxo na aresta original 
-                att.set_flux(att.get_flux() + path.gargalo);
+                att.set_flux(att.get_flux() + path.gargalo)?;
             }
         }
         residual_graph = ResidualGraph::from_graph(g, &flux_map);
-        increasing_path = IncreasingPath::from_residual_graph(&residual_graph, s, t);
+        increasing_path =
+            IncreasingPath::from_residual_graph(&residual_graph, s.clone(), t.clone());
     }
 
     // Calcula o fluxo máximo a partir das arestas saindo da fonte
     let vertice = g
         .get_vertice_arc(s)
         .expect("Vértice de origem não encontrado");
-    let edges = vertice.edges_vec_ref();
-    for e in edges {
-        let (v, w) = (e.origin_key(), e.destiny_key());
-        if let Some(att) = flux_map.get(&(v, w)) {
-            flux_map.set_max_flux(att.get_flux() as i32);
+    let mut total_flux = W::zero();
+    for e in vertice.edges_vec_ref() {
+        if let Some(att) = flux_map.get(&e.id()) {
+            total_flux = total_flux + att.get_flux();
         }
     }
+    flux_map.set_max_flux(total_flux);
 
-    (flux_map, residual_graph)
+    Ok((flux_map, residual_graph))
 }
 
-struct IncreasingPath {
-    gargalo: u32,
+struct IncreasingPath<K, W> {
+    gargalo: W,
     /// arestas do caminho
-    edges: Vec<Edge>,
+    edges: Vec<Edge<K, W>>,
 }
 
-impl IncreasingPath {
-    fn from_residual_graph(g: &ResidualGraph, s: i32, t: i32) -> Option<Self> {
+impl<K: VerticeKey, W: Weight> IncreasingPath<K, W> {
+    fn from_residual_graph(g: &ResidualGraph<K, W>, s: K, t: K) -> Option<Self> {
         let path = g.graph.path_between(s, t)?;
-        let mut gargalo = u32::MAX;
+        let mut gargalo = path.first()?.weight();
         for e in &path {
-            let weight = e.weight() as u32;
+            let weight = e.weight();
             if weight < gargalo {
                 gargalo = weight;
             }
@@ -0,0 +1,567 @@
+use std::{
+    collections::HashMap,
+    fs::{self, File},
+    io::{BufWriter, Write},
+};
+
+use crate::{
+    graph_lib::{edge::Edge, vertice::VerticeKey},
+    tools::weight::Weight,
+    DiGraph, GraphError,
+};
+
+/// Versão atual do formato binário
+pub const BINARY_VERSION: u16 = 2;
+
+const MAGIC: [u8; 4] = *b"RGBN";
+/// Tamanho do cabeçalho sem o preenchimento até o alinhamento da primeira seção
+const HEADER_LEN: usize = 40;
+/// Alinhamento de cada seção, suficiente para qualquer `BinaryValue` (inclusive `i128`)
+const ALIGN: usize = 16;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Tipo numérico que pode ser chave ou peso no formato binário
+///
+/// Implementado para os inteiros de tamanho fixo (`i8`..`i128`, `u8`..`u128`) e para
+/// `f32`/`f64`. Os valores são gravados em little-endian.
+pub trait BinaryValue: Copy + sealed::Sealed {
+    /// Identificador do tipo no cabeçalho
+    const TAG: u8;
+    const SIZE: usize;
+
+    fn write_le(self, buffer: &mut [u8]);
+
+    fn read_le(bytes: &[u8]) -> Self;
+}
+
+macro_rules! impl_binary_value {
+    ($($t:ty => $tag:expr),*) => {
+        $(
+            impl sealed::Sealed for $t {}
+
+            impl BinaryValue for $t {
+                const TAG: u8 = $tag;
+                const SIZE: usize = std::mem::size_of::<$t>();
+
+                fn write_le(self, buffer: &mut [u8]) {
+                    buffer.copy_from_slice(&self.to_le_bytes());
+                }
+
+                fn read_le(bytes: &[u8]) -> Self {
+                    <$t>::from_le_bytes(bytes.try_into().unwrap())
+                }
+            }
+        )*
+    };
+}
+
+impl_binary_value!(
+    i8 => 1, i16 => 2, i32 => 3, i64 => 4, i128 => 5,
+    u8 => 6, u16 => 7, u32 => 8, u64 => 9, u128 => 10,
+    f32 => 11, f64 => 12
+);
+
+/// Posição (em bytes) de cada seção de um snapshot com `n` vértices e `m` arestas
+///
+/// Depois do cabeçalho (preenchido com zeros até 48 bytes) vêm, cada uma começando em
+/// múltiplo de 16 bytes:
+/// `keys` (`n` chaves em ordem crescente), `offsets` (`n + 1` u64), `targets` (`m` u64),
+/// `weights` (`m` pesos), `edge_ids` (`m` u64), `in_offsets` (`n + 1` u64) e
+/// `in_sources` (`m` u64). O arquivo termina com o checksum (u64) de todos os bytes anteriores.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Layout {
+    pub n: usize,
+    pub m: usize,
+    pub keys: usize,
+    pub offsets: usize,
+    pub targets: usize,
+    pub weights: usize,
+    pub edge_ids: usize,
+    pub in_offsets: usize,
+    pub in_sources: usize,
+    pub checksum: usize,
+}
+
+fn align(position: usize) -> usize {
+    position.div_ceil(ALIGN) * ALIGN
+}
+
+impl Layout {
+    fn new(n: usize, m: usize, key_size: usize, weight_size: usize) -> Option<Layout> {
+        let section = |start: usize, count: usize, size: usize| -> Option<usize> {
+            Some(align(start.checked_add(count.checked_mul(size)?)?))
+        };
+        let keys = align(HEADER_LEN);
+        let offsets = section(keys, n, key_size)?;
+        let targets = section(offsets, n.checked_add(1)?, 8)?;
+        let weights = section(targets, m, 8)?;
+        let edge_ids = section(weights, m, weight_size)?;
+        let in_offsets = section(edge_ids, m, 8)?;
+        let in_sources = section(in_offsets, n + 1, 8)?;
+        let checksum = section(in_sources, m, 8)?;
+        Some(Layout {
+            n,
+            m,
+            keys,
+            offsets,
+            targets,
+            weights,
+            edge_ids,
+            in_offsets,
+            in_sources,
+            checksum,
+        })
+    }
+
+    /// Tamanho total do arquivo
+    pub fn len(&self) -> usize {
+        self.checksum + 8
+    }
+
+    /// Lê e valida um snapshot inteiro: cabeçalho, tipos, tamanho, checksum e a
+    /// consistência dos índices (para que os acessos depois não precisem de verificação)
+    pub fn validate<K: BinaryValue + Ord, W: BinaryValue>(bytes: &[u8]) -> Result<Layout, GraphError> {
+        let invalid = |reason: &str| GraphError::InvalidSnapshot(reason.to_string());
+        if bytes.len() < HEADER_LEN + 8 || bytes[..4] != MAGIC {
+            return Err(invalid("not a rust_graph binary snapshot"));
+        }
+        let version = u16::read_le(&bytes[4..6]);
+        if version != BINARY_VERSION {
+            return Err(GraphError::InvalidSnapshot(format!("unsupported version {}", version)));
+        }
+        if bytes[6] != K::TAG || bytes[7] != W::TAG {
+            return Err(invalid("key or weight type does not match the snapshot"));
+        }
+        let field = |i: usize| usize::try_from(u64::read_le(&bytes[8 + 8 * i..16 + 8 * i])).ok();
+        let (n, m, id_len) = match (field(0), field(1), field(2)) {
+            (Some(n), Some(m), Some(id_len)) => (n, m, id_len),
+            _ => return Err(invalid("graph too large for this platform")),
+        };
+        let layout = Layout::new(n, m, K::SIZE, W::SIZE).ok_or_else(|| invalid("size overflow"))?;
+        if bytes.len() != layout.len() {
+            return Err(invalid("unexpected file size"));
+        }
+        if checksum(&bytes[..layout.checksum]) != u64::read_le(&bytes[layout.checksum..]) {
+            return Err(invalid("checksum mismatch"));
+        }
+
+        let u64_at = |section: usize, i: usize| u64::read_le(&bytes[section + 8 * i..section + 8 * i + 8]);
+        let keys_sorted = (1..n).all(|i| {
+            let key = |i: usize| K::read_le(&bytes[layout.keys + K::SIZE * i..layout.keys + K::SIZE * (i + 1)]);
+            key(i - 1) < key(i)
+        });
+        if !keys_sorted {
+            return Err(invalid("vertex keys are not sorted"));
+        }
+        for (offsets, indices) in [(layout.offsets, layout.targets), (layout.in_offsets, layout.in_sources)] {
+            let monotonic = (0..n).all(|v| u64_at(offsets, v) <= u64_at(offsets, v + 1));
+            if u64_at(offsets, 0) != 0 || u64_at(offsets, n) != m as u64 || !monotonic {
+                return Err(invalid("invalid edge offsets"));
+            }
+            if (0..m).any(|e| u64_at(indices, e) >= n as u64) {
+                return Err(invalid("edge endpoint out of range"));
+            }
+        }
+        if (0..m).any(|e| u64_at(layout.edge_ids, e) >= id_len as u64) {
+            return Err(invalid("edge id out of range"));
+        }
+        Ok(layout)
+    }
+}
+
+/// Checksum do formato: FNV-1a sobre palavras de 8 bytes little-endian
+///
+/// `bytes.len()` é sempre múltiplo de 8, pois todas as seções são alinhadas.
+fn checksum(bytes: &[u8]) -> u64 {
+    let mut hash = Checksum::new();
+    hash.update(bytes);
+    hash.value
+}
+
+struct Checksum {
+    value: u64,
+    pending: Vec<u8>,
+}
+
+impl Checksum {
+    fn new() -> Checksum {
+        Checksum {
+            value: 0xcbf2_9ce4_8422_2325,
+            pending: Vec::with_capacity(8),
+        }
+    }
+
+    fn update(&mut self, mut bytes: &[u8]) {
+        if !self.pending.is_empty() {
+            let missing = (8 - self.pending.len()).min(bytes.len());
+            self.pending.extend_from_slice(&bytes[..missing]);
+            bytes = &bytes[missing..];
+            if self.pending.len() < 8 {
+                return;
+            }
+            let word = u64::read_le(&self.pending);
+            self.word(word);
+            self.pending.clear();
+        }
+        let mut words = bytes.chunks_exact(8);
+        for word in words.by_ref() {
+            self.word(u64::read_le(word));
+        }
+        self.pending.extend_from_slice(words.remainder());
+    }
+
+    fn word(&mut self, word: u64) {
+        self.value = (self.value ^ word).wrapping_mul(0x0000_0100_0000_01b3);
+    }
+}
+
+/// Escritor que calcula o checksum e conta os bytes escritos, para o alinhamento
+struct SnapshotWriter<'w, Wr: Write> {
+    out: &'w mut Wr,
+    checksum: Checksum,
+    written: usize,
+}
+
+impl<Wr: Write> SnapshotWriter<'_, Wr> {
+    fn bytes(&mut self, bytes: &[u8]) -> Result<(), GraphError> {
+        self.out.write_all(bytes)?;
+        self.checksum.update(bytes);
+        self.written += bytes.len();
+        Ok(())
+    }
+
+    fn value<T: BinaryValue>(&mut self, value: T) -> Result<(), GraphError> {
+        let mut buffer = [0u8; 16];
+        value.write_le(&mut buffer[..T::SIZE]);
+        self.bytes(&buffer[..T::SIZE])
+    }
+
+    fn pad(&mut self) -> Result<(), GraphError> {
+        let padding = align(self.written) - self.written;
+        self.bytes(&[0u8; ALIGN][..padding])
+    }
+
+    fn section<T: BinaryValue>(&mut self, values: impl Iterator<Item = T>) -> Result<(), GraphError> {
+        for value in values {
+            self.value(value)?;
+        }
+        self.pad()
+    }
+}
+
+#[allow(unused)]
+impl<K: VerticeKey + BinaryValue + Ord, W: Weight + BinaryValue> DiGraph<K, W> {
+    /// Escreve o grafo no formato binário (ver [`DiGraph::save_binary`])
+    ///
+    /// # Errors
+    /// `GraphError::Io` se a escrita falhar.
+    pub fn write_binary<Wr: Write>(&self, out: &mut Wr) -> Result<(), GraphError> {
+        let mut keys = self.get_vertice_key_array();
+        keys.sort();
+        let index: HashMap<&K, usize> = keys.iter().enumerate().map(|(i, k)| (k, i)).collect();
+        let n = keys.len();
+
+        // CSR das arestas de saída, com as arestas de cada vértice em ordem de id
+        let mut offsets = Vec::with_capacity(n + 1);
+        let mut targets = Vec::with_capacity(self.edges_length());
+        let mut weights = Vec::with_capacity(self.edges_length());
+        let mut edge_ids = Vec::with_capacity(self.edges_length());
+        let mut in_degree = vec![0u64; n];
+        let mut id_len = 0;
+        offsets.push(0u64);
+        for key in keys.iter() {
+            let vertice = self.get_vertice_arc(*key).unwrap();
+            let mut edges = vertice.edges_vec_ref();
+            edges.sort_by_key(|e| e.id());
+            for e in edges {
+                let w = index[e.destiny_key_ref()];
+                targets.push(w as u64);
+                weights.push(e.weight());
+                edge_ids.push(e.id().index() as u64);
+                id_len = id_len.max(e.id().index() + 1);
+                in_degree[w] += 1;
+            }
+            offsets.push(targets.len() as u64);
+        }
+        let m = targets.len();
+
+        let mut in_offsets = Vec::with_capacity(n + 1);
+        in_offsets.push(0u64);
+        for d in in_degree.iter() {
+            in_offsets.push(in_offsets.last().unwrap() + d);
+        }
+        let mut next: Vec<u64> = in_offsets[..n].to_vec();
+        let mut in_sources = vec![0u64; m];
+        for v in 0..n {
+            for &w in &targets[offsets[v] as usize..offsets[v + 1] as usize] {
+                in_sources[next[w as usize] as usize] = v as u64;
+                next[w as usize] += 1;
+            }
+        }
+
+        let mut writer = SnapshotWriter {
+            out,
+            checksum: Checksum::new(),
+            written: 0,
+        };
+        writer.bytes(&MAGIC)?;
+        writer.value(BINARY_VERSION)?;
+        writer.bytes(&[K::TAG, W::TAG])?;
+        for field in [n as u64, m as u64, id_len as u64, 0] {
+            writer.value(field)?;
+        }
+        writer.pad()?;
+        writer.section(keys.iter().cloned())?;
+        writer.section(offsets.into_iter())?;
+        writer.section(targets.into_iter())?;
+        writer.section(weights.into_iter())?;
+        writer.section(edge_ids.into_iter())?;
+        writer.section(in_offsets.into_iter())?;
+        writer.section(in_sources.into_iter())?;
+        let checksum = writer.checksum.value;
+        writer.value(checksum)?;
+        Ok(())
+    }
+
+    /// Salva o grafo em um snapshot binário compacto
+    ///
+    /// O formato é versionado e tem cabeçalho, tabela de chaves dos vértices, arestas em
+    /// CSR (com as arestas de entrada) e checksum. Os ids das arestas são mantidos; os
+    /// atributos além do peso (`EdgeAttribute`) não são salvos.
+    /// O arquivo pode ser lido com [`DiGraph::load_binary`] ou mapeado em memória com
+    /// [`MappedGraph::open`](crate::graph_lib::csr::mapped_graph::MappedGraph::open).
+    /// ```
+    /// # use rust_graph::DiGraph;
+    /// let mut graph: DiGraph = DiGraph::new();
+    /// graph.add_edge(rust_graph::graph_lib::edge::Edge::new_weighted(1, 2, 5));
+    /// let path = std::env::temp_dir().join("rust_graph_doc.bin");
+    /// graph.save_binary(path.to_str().unwrap()).unwrap();
+    /// let loaded: DiGraph = DiGraph::load_binary(path.to_str().unwrap()).unwrap();
+    /// assert_eq!(loaded.edges_length(), 1);
+    /// # std::fs::remove_file(path).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    /// `GraphError::Io` se o arquivo não puder ser escrito.
+    pub fn save_binary(&self, file_path: &str) -> Result<(), GraphError> {
+        let mut out = BufWriter::new(File::create(file_path)?);
+        self.write_binary(&mut out)?;
+        out.flush()?;
+        Ok(())
+    }
+
+    /// Lê um grafo de um snapshot binário em memória (ver [`DiGraph::save_binary`])
+    ///
+    /// # Errors
+    /// `GraphError::InvalidSnapshot` se o snapshot estiver corrompido, for de outra versão
+    /// ou tiver tipos de chave/peso diferentes de `K`/`W`.
+    pub fn from_binary(bytes: &[u8]) -> Result<DiGraph<K, W>, GraphError> {
+        let layout = Layout::validate::<K, W>(bytes)?;
+        let u64_at = |section: usize, i: usize| u64::read_le(&bytes[section + 8 * i..section + 8 * i + 8]) as usize;
+        let keys: Vec<K> = (0..layout.n)
+            .map(|i| K::read_le(&bytes[layout.keys + K::SIZE * i..layout.keys + K::SIZE * (i + 1)]))
+            .collect();
+
+        let mut edges = Vec::with_capacity(layout.m);
+        for v in 0..layout.n {
+            for e in u64_at(layout.offsets, v)..u64_at(layout.offsets, v + 1) {
+                let w = u64_at(layout.targets, e);
+                let weight = W::read_le(&bytes[layout.weights + W::SIZE * e..layout.weights + W::SIZE * (e + 1)]);
+                edges.push((u64_at(layout.edge_ids, e), v, w, weight));
+            }
+        }
+        edges.sort_by_key(|(id, ..)| *id);
+
+        let mut graph = DiGraph::new_sized(layout.n as u32);
+        for key in keys.iter() {
+            graph.add_vertice(*key);
+        }
+        for (id, v, w, weight) in edges {
+            let edge = Edge::new_weighted(keys[v], keys[w], weight);
+            if graph.add_edge_at(edge, id).is_none() {
+                return Err(GraphError::InvalidSnapshot("duplicated edge id".to_string()));
+            }
+        }
+        Ok(graph)
+    }
+
+    /// Lê um grafo salvo com [`DiGraph::save_binary`]
+    ///
+    /// # Errors
+    /// `GraphError::Io` se o arquivo não puder ser lido e os mesmos erros de
+    /// [`DiGraph::from_binary`].
+    pub fn load_binary(file_path: &str) -> Result<DiGraph<K, W>, GraphError> {
+        let bytes = fs::read(file_path)?;
+        DiGraph::from_binary(&bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot() -> Vec<u8> {
+        let mut graph: DiGraph = DiGraph::new();
+        graph.add_edge(Edge::new_weighted(1, 2, 5));
+        graph.add_edge(Edge::new_weighted(2, 3, -1));
+        graph.add_edge(Edge::new_weighted(3, 1, 7));
+        graph.add_vertice(4);
+        let mut bytes = Vec::new();
+        graph.write_binary(&mut bytes).unwrap();
+        bytes
+    }
+
+    /// Recalcula o checksum depois de alterar o conteúdo, para testar as validações seguintes
+    fn reseal(bytes: &mut [u8]) {
+        let start = bytes.len() - 8;
+        let value = checksum(&bytes[..start]);
+        value.write_le(&mut bytes[start..]);
+    }
+
+    fn layout(bytes: &[u8]) -> Layout {
+        Layout::validate::<i32, i32>(bytes).unwrap()
+    }
+
+    fn reason(result: Result<DiGraph, GraphError>) -> String {
+        match result {
+            Err(GraphError::InvalidSnapshot(reason)) => reason,
+            other => panic!(
+                "expected an invalid snapshot, got {:?}",
+                other.map(|g| g.edges_length())
+            ),
+        }
+    }
+
+    #[test]
+    fn round_trip_keeps_vertices_edges_and_ids() {
+        let bytes = snapshot();
+        let graph: DiGraph = DiGraph::from_binary(&bytes).unwrap();
+        assert_eq!(graph.vertices_length(), 4);
+        assert_eq!(graph.edges_length(), 3);
+        let mut edges: Vec<_> = graph
+            .all_edges()
+            .iter()
+            .map(|e| (e.id().index(), *e.origin_key_ref(), *e.destiny_key_ref(), e.weight()))
+            .collect();
+        edges.sort();
+        assert_eq!(edges, vec![(0, 1, 2, 5), (1, 2, 3, -1), (2, 3, 1, 7)]);
+    }
+
+    #[test]
+    fn truncated_snapshots_are_rejected() {
+        let bytes = snapshot();
+        for len in [0, 3, HEADER_LEN, HEADER_LEN + 8, bytes.len() / 2, bytes.len() - 1] {
+            assert!(
+                matches!(
+                    DiGraph::<i32, i32>::from_binary(&bytes[..len]),
+                    Err(GraphError::InvalidSnapshot(_))
+                ),
+                "snapshot truncated to {len} bytes was accepted"
+            );
+        }
+        let mut longer = bytes.clone();
+        longer.extend_from_slice(&[0; 8]);
+        assert_eq!(reason(DiGraph::from_binary(&longer)), "unexpected file size");
+    }
+
+    #[test]
+    fn any_flipped_byte_after_the_header_fails_the_checksum() {
+        let bytes = snapshot();
+        let layout = layout(&bytes);
+        for position in layout.keys..layout.checksum {
+            let mut corrupt = bytes.clone();
+            corrupt[position] ^= 0x40;
+            assert_eq!(
+                reason(DiGraph::from_binary(&corrupt)),
+                "checksum mismatch",
+                "byte {position}"
+            );
+        }
+        let mut corrupt = bytes.clone();
+        corrupt[layout.checksum] ^= 1;
+        assert_eq!(reason(DiGraph::from_binary(&corrupt)), "checksum mismatch");
+    }
+
+    #[test]
+    fn header_mismatches_are_rejected() {
+        let bytes = snapshot();
+
+        let mut magic = bytes.clone();
+        magic[0] = b'X';
+        assert_eq!(reason(DiGraph::from_binary(&magic)), "not a rust_graph binary snapshot");
+
+        let mut version = bytes.clone();
+        (BINARY_VERSION + 1).write_le(&mut version[4..6]);
+        reseal(&mut version);
+        assert_eq!(
+            reason(DiGraph::from_binary(&version)),
+            format!("unsupported version {}", BINARY_VERSION + 1)
+        );
+
+        let wrong_types = DiGraph::<i64, i32>::from_binary(&bytes);
+        assert!(matches!(wrong_types, Err(GraphError::InvalidSnapshot(_))));
+        let wrong_types = DiGraph::<i32, f64>::from_binary(&bytes);
+        assert!(matches!(wrong_types, Err(GraphError::InvalidSnapshot(_))));
+
+        // Quantidades que não batem com o tamanho do arquivo
+        let mut counts = bytes.clone();
+        5u64.write_le(&mut counts[8..16]);
+        reseal(&mut counts);
+        assert_eq!(reason(DiGraph::from_binary(&counts)), "unexpected file size");
+
+        let mut overflow = bytes.clone();
+        u64::MAX.write_le(&mut overflow[16..24]);
+        assert!(matches!(
+            DiGraph::<i32, i32>::from_binary(&overflow),
+            Err(GraphError::InvalidSnapshot(_))
+        ));
+    }
+
+    #[test]
+    fn resealed_inconsistent_indices_are_rejected() {
+        let bytes = snapshot();
+        let layout = layout(&bytes);
+
+        let mut unsorted = bytes.clone();
+        9i32.write_le(&mut unsorted[layout.keys..layout.keys + 4]);
+        reseal(&mut unsorted);
+        assert_eq!(reason(DiGraph::from_binary(&unsorted)), "vertex keys are not sorted");
+
+        let mut offsets = bytes.clone();
+        2u64.write_le(&mut offsets[layout.offsets..layout.offsets + 8]);
+        reseal(&mut offsets);
+        assert_eq!(reason(DiGraph::from_binary(&offsets)), "invalid edge offsets");
+
+        let mut target = bytes.clone();
+        (layout.n as u64).write_le(&mut target[layout.targets..layout.targets + 8]);
+        reseal(&mut target);
+        assert_eq!(reason(DiGraph::from_binary(&target)), "edge endpoint out of range");
+
+        let mut source = bytes.clone();
+        (layout.n as u64).write_le(&mut source[layout.in_sources..layout.in_sources + 8]);
+        reseal(&mut source);
+        assert_eq!(reason(DiGraph::from_binary(&source)), "edge endpoint out of range");
+
+        let mut id = bytes.clone();
+        (layout.m as u64).write_le(&mut id[layout.edge_ids..layout.edge_ids + 8]);
+        reseal(&mut id);
+        assert_eq!(reason(DiGraph::from_binary(&id)), "edge id out of range");
+
+        let mut duplicated = bytes.clone();
+        let first = u64::read_le(&bytes[layout.edge_ids..layout.edge_ids + 8]);
+        first.write_le(&mut duplicated[layout.edge_ids + 8..layout.edge_ids + 16]);
+        reseal(&mut duplicated);
+        assert_eq!(reason(DiGraph::from_binary(&duplicated)), "duplicated edge id");
+    }
+
+    #[test]
+    fn load_binary_reports_missing_files_as_io() {
+        let path = std::env::temp_dir().join("rust_graph_missing_snapshot.bin");
+        let _ = fs::remove_file(&path);
+        let result = DiGraph::<i32, i32>::load_binary(path.to_str().unwrap());
+        assert!(matches!(result, Err(GraphError::Io(_))));
+    }
+}
@@ -0,0 +1,278 @@
+use std::{
+    fs::File,
+    io::{BufRead, BufReader, Read},
+    str::FromStr,
+};
+
+use crate::{
+    graph_lib::{attributes::VerticeMap, edge::Edge, vertice::VerticeKey},
+    tools::weight::Weight,
+    DiGraph, GraphError,
+};
+
+/// Grafo lido de um CSV e os labels de seus vértices
+pub type LabeledGraph<K, W> = (DiGraph<K, W>, VerticeMap<K, String>);
+
+/// Coluna de um arquivo CSV, pelo nome no cabeçalho ou pela posição (começando em 0)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CsvColumn {
+    Name(String),
+    Index(usize),
+}
+
+impl From<&str> for CsvColumn {
+    fn from(name: &str) -> Self {
+        CsvColumn::Name(name.to_string())
+    }
+}
+
+impl From<usize> for CsvColumn {
+    fn from(index: usize) -> Self {
+        CsvColumn::Index(index)
+    }
+}
+
+/// # CsvSchema
+/// Formato dos arquivos CSV lidos por [`DiGraph::from_csv_with`]
+///
+/// O padrão é o formato escrito por [`DiGraph::vertices_to_csv`] e [`DiGraph::edges_to_csv`]
+/// (estilo Gephi): `id,label` e `source,target,weight`, separados por vírgula e com cabeçalho.
+/// ```
+/// # use rust_graph::graph_lib::formats::csv::{CsvColumn, CsvSchema};
+/// let schema = CsvSchema {
+///     delimiter: ';',
+///     label: None,
+///     weight: Some(CsvColumn::from("custo")),
+///     ..CsvSchema::default()
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvSchema {
+    pub delimiter: char,
+    /// Se a primeira linha de cada arquivo é um cabeçalho. Sem cabeçalho as colunas
+    /// devem ser dadas por `CsvColumn::Index`
+    pub has_header: bool,
+    pub id: CsvColumn,
+    /// Coluna opcional com o label dos vértices (ignorada se não estiver no cabeçalho)
+    pub label: Option<CsvColumn>,
+    pub source: CsvColumn,
+    pub target: CsvColumn,
+    /// Coluna opcional com o peso das arestas (ignorada se não estiver no cabeçalho);
+    /// sem ela, ou com a célula vazia, o peso é 1
+    pub weight: Option<CsvColumn>,
+}
+
+impl Default for CsvSchema {
+    fn default() -> Self {
+        CsvSchema {
+            delimiter: ',',
+            has_header: true,
+            id: CsvColumn::from("id"),
+            label: Some(CsvColumn::from("label")),
+            source: CsvColumn::from("source"),
+            target: CsvColumn::from("target"),
+            weight: Some(CsvColumn::from("weight")),
+        }
+    }
+}
+
+/// Linhas não vazias de um CSV já separadas em campos, com o número da linha
+struct CsvRows<R: Read> {
+    lines: std::iter::Enumerate<std::io::Lines<BufReader<R>>>,
+    delimiter: char,
+}
+
+impl<R: Read> CsvRows<R> {
+    fn new(reader: R, delimiter: char) -> Self {
+        CsvRows {
+            lines: BufReader::new(reader).lines().enumerate(),
+            delimiter,
+        }
+    }
+}
+
+impl<R: Read> Iterator for CsvRows<R> {
+    type Item = Result<(usize, String, Vec<String>), GraphError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (index, line) in self.lines.by_ref() {
+            let line = match line {
+                Ok(line) => line,
+                Err(err) => return Some(Err(err.into())),
+            };
+            if line.trim().is_empty() {
+                continue;
+            }
+            let row = index + 1;
+            return Some(match split_fields(&line, self.delimiter) {
+                Some(fields) => Ok((row, line, fields)),
+                None => Err(GraphError::parse(row, &line)),
+            });
+        }
+        None
+    }
+}
+
+/// Separa uma linha em campos, aceitando campos entre aspas com `""` como aspas literais
+///
+/// Retorna `None` se uma aspa não for fechada.
+fn split_fields(line: &str, delimiter: char) -> Option<Vec<String>> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut chars = line.trim_end_matches('\r').chars().peekable();
+    let mut quoted = false;
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' if quoted => quoted = false,
+            '"' if field.trim().is_empty() => {
+                field.clear();
+                quoted = true;
+            }
+            c if c == delimiter && !quoted => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    if quoted {
+        return None;
+    }
+    fields.push(field);
+    Some(fields)
+}
+
+/// Posição das colunas de um arquivo, resolvidas pelo cabeçalho quando houver
+///
+/// `columns` são pares (coluna, obrigatória); uma coluna opcional que não está no
+/// cabeçalho é ignorada.
+fn resolve_columns<R: Read>(
+    rows: &mut CsvRows<R>,
+    schema: &CsvSchema,
+    columns: &[(Option<&CsvColumn>, bool)],
+) -> Result<Vec<Option<usize>>, GraphError> {
+    let header = if schema.has_header {
+        match rows.next().transpose()? {
+            Some(header) => Some(header),
+            None => return Ok(vec![None; columns.len()]),
+        }
+    } else {
+        None
+    };
+    columns
+        .iter()
+        .map(|(column, required)| match (column, &header) {
+            (None, _) => Ok(None),
+            (Some(CsvColumn::Index(i)), _) => Ok(Some(*i)),
+            (Some(CsvColumn::Name(name)), Some((row, line, fields))) => {
+                match fields.iter().position(|f| f.trim() == name) {
+                    None if *required => Err(GraphError::parse(*row, line)),
+                    position => Ok(position),
+                }
+            }
+            (Some(CsvColumn::Name(_)), None) => Err(GraphError::parse(1, "")),
+        })
+        .collect()
+}
+
+#[allow(unused)]
+impl<K: VerticeKey + FromStr, W: Weight + FromStr> DiGraph<K, W> {
+    /// Lê um grafo de dois CSVs no formato escrito por [`DiGraph::to_csv`]
+    /// (ver [`DiGraph::from_csv_with`])
+    pub fn from_csv<Rv: Read, Re: Read>(
+        vertices: Rv,
+        edges: Re,
+    ) -> Result<LabeledGraph<K, W>, GraphError> {
+        DiGraph::from_csv_with(vertices, edges, &CsvSchema::default())
+    }
+
+    /// Lê um grafo de um CSV de vértices e um CSV de arestas com o formato `schema`
+    ///
+    /// Os labels dos vértices (se houver coluna de label) são retornados em um `VerticeMap`.
+    /// Linhas vazias são ignoradas e campos podem estar entre aspas.
+    /// ```
+    /// # use rust_graph::DiGraph;
+    /// let vertices = "id,label\n1,Ana\n2,\"Silva, Bruno\"\n";
+    /// let edges = "source,target,weight\n1,2,7\n";
+    /// let (graph, labels): (DiGraph, _) = DiGraph::from_csv(vertices.as_bytes(), edges.as_bytes()).unwrap();
+    /// assert_eq!(graph.edges_length(), 1);
+    /// assert_eq!(labels.get(&2).map(|l| l.as_str()), Some("Silva, Bruno"));
+    /// ```
+    ///
+    /// # Errors
+    /// `GraphError::Parse` com o número da linha (contando o cabeçalho) se uma coluna não
+    /// existir ou um id/peso for inválido, `GraphError::MissingVertex` se uma aresta usar um
+    /// vértice não declarado e `GraphError::Io` se a leitura falhar.
+    pub fn from_csv_with<Rv: Read, Re: Read>(
+        vertices: Rv,
+        edges: Re,
+        schema: &CsvSchema,
+    ) -> Result<LabeledGraph<K, W>, GraphError> {
+        let mut graph = DiGraph::new();
+        let mut labels = VerticeMap::new();
+
+        let mut rows = CsvRows::new(vertices, schema.delimiter);
+        let columns = resolve_columns(
+            &mut rows,
+            schema,
+            &[(Some(&schema.id), true), (schema.label.as_ref(), false)],
+        )?;
+        for row in rows {
+            let (row, line, fields) = row?;
+            let parse_error = || GraphError::parse(row, &line);
+            let field = |column: Option<usize>| column.and_then(|i| fields.get(i)).map(|f| f.trim());
+            let key: K = field(columns[0])
+                .ok_or_else(parse_error)?
+                .parse()
+                .map_err(|_| parse_error())?;
+            graph.add_vertice(key.clone());
+            if let Some(label) = field(columns[1]) {
+                labels.insert(key, label.to_string());
+            }
+        }
+
+        let mut rows = CsvRows::new(edges, schema.delimiter);
+        let columns = resolve_columns(
+            &mut rows,
+            schema,
+            &[
+                (Some(&schema.source), true),
+                (Some(&schema.target), true),
+                (schema.weight.as_ref(), false),
+            ],
+        )?;
+        for row in rows {
+            let (row, line, fields) = row?;
+            let parse_error = || GraphError::parse(row, &line);
+            let field = |column: Option<usize>| column.and_then(|i| fields.get(i)).map(|f| f.trim());
+            let key = |column: Option<usize>| -> Result<K, GraphError> {
+                let key: K = field(column)
+                    .ok_or_else(parse_error)?
+                    .parse()
+                    .map_err(|_| parse_error())?;
+                if !graph.vertice_exists(key.clone()) {
+                    return Err(GraphError::missing_vertex(&key));
+                }
+                Ok(key)
+            };
+            let (v, w) = (key(columns[0])?, key(columns[1])?);
+            let weight = match field(columns[2]) {
+                Some(weight) if !weight.is_empty() => weight.parse::<W>().map_err(|_| parse_error())?,
+                _ => W::one(),
+            };
+            graph.add_edge(Edge::new_weighted(v, w, weight));
+        }
+
+        Ok((graph, labels))
+    }
+
+    /// Lê um grafo dos arquivos CSV de vértices e arestas (ver [`DiGraph::from_csv_with`])
+    pub fn from_csv_files(
+        vertices_path: &str,
+        edges_path: &str,
+        schema: &CsvSchema,
+    ) -> Result<LabeledGraph<K, W>, GraphError> {
+        DiGraph::from_csv_with(File::open(vertices_path)?, File::open(edges_path)?, schema)
+    }
+}
@@ -0,0 +1,171 @@
+use std::{fs, io::Write, str::FromStr};
+
+use super::dense_ids;
+use crate::{
+    graph_lib::edge::{Edge, EdgeAttribute},
+    tools::weight::Weight,
+    DiGraph, GraphError,
+};
+
+/// Tipo de problema declarado na linha `p` de um arquivo DIMACS
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DimacsProblem {
+    /// `p sp n m`: caminho mínimo, arcos `a u v peso`
+    ShortestPath,
+    /// `p max n m`: fluxo máximo, arcos `a u v capacidade` e linhas `n id s` / `n id t`
+    MaxFlow { source: i32, sink: i32 },
+}
+
+#[allow(unused)]
+impl<W: Weight + FromStr> DiGraph<i32, W> {
+    /// Lê um grafo no formato DIMACS (`p sp` ou `p max`)
+    ///
+    /// Os vértices são `1..=n`, todos adicionados mesmo que isolados. Em `p max` a
+    /// capacidade vira o peso e também o atributo `EdgeAttribute::Capacity` da aresta,
+    /// e a fonte e o sumidouro são retornados em `DimacsProblem::MaxFlow`.
+    /// ```
+    /// # use rust_graph::{DiGraph, graph_lib::{formats::dimacs::DimacsProblem, flux::ford_fulkerson::max_flux}};
+    /// let content = "c exemplo\np max 3 2\nn 1 s\nn 3 t\na 1 2 4\na 2 3 3\n";
+    /// let (graph, problem): (DiGraph, _) = DiGraph::from_dimacs(content).unwrap();
+    /// let DimacsProblem::MaxFlow { source, sink } = problem else { unreachable!() };
+    /// let (flux, _) = max_flux(&graph, source, sink).unwrap();
+    /// assert_eq!(flux.get_max_flux(), 3);
+    /// ```
+    ///
+    /// # Errors
+    /// `GraphError::Parse` com a linha inválida (linha `p` ausente ou de tipo desconhecido,
+    /// vértice fora de `1..=n`, `s`/`t` ausentes em `p max`) e
+    /// `GraphError::HeaderMismatch` se a quantidade de arcos não bater com a linha `p`.
+    /// `n` precisa caber nas chaves `i32`; se não houver memória para os `n` vértices o erro
+    /// é `GraphError::Io` com `ErrorKind::OutOfMemory`.
+    pub fn from_dimacs(content: &str) -> Result<(DiGraph<i32, W>, DimacsProblem), GraphError> {
+        let mut graph = DiGraph::new();
+        let mut header: Option<(usize, &str, usize, usize)> = None;
+        let (mut source, mut sink) = (None, None);
+
+        for (index, line) in content.lines().enumerate() {
+            let line_number = index + 1;
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            let parse_error = || GraphError::parse(line_number, line);
+            match tokens.as_slice() {
+                [] | ["c", ..] => {}
+                ["p", kind @ ("sp" | "max"), n, m] => {
+                    if header.is_some() {
+                        return Err(parse_error());
+                    }
+                    let n: usize = n.parse().map_err(|_| parse_error())?;
+                    let m: usize = m.parse().map_err(|_| parse_error())?;
+                    i32::try_from(n).map_err(|_| parse_error())?;
+                    header = Some((line_number, kind, n, m));
+                }
+                ["n", v, role @ ("s" | "t")] => {
+                    let Some((_, "max", n, _)) = header else {
+                        return Err(parse_error());
+                    };
+                    let v = parse_vertice(v, n).ok_or_else(parse_error)?;
+                    if *role == "s" {
+                        source = Some(v);
+                    } else {
+                        sink = Some(v);
+                    }
+                }
+                ["a", v, w, weight] => {
+                    let Some((_, kind, n, _)) = header else {
+                        return Err(parse_error());
+                    };
+                    let v = parse_vertice(v, n).ok_or_else(parse_error)?;
+                    let w = parse_vertice(w, n).ok_or_else(parse_error)?;
+                    let weight = weight.parse::<W>().map_err(|_| parse_error())?;
+                    let mut edge = Edge::new_weighted(v, w, weight);
+                    if kind == "max" {
+                        edge.set_attribute(EdgeAttribute::Capacity, weight);
+                    }
+                    graph.add_edge(edge);
+                }
+                _ => return Err(parse_error()),
+            }
+        }
+
+        let Some((header_line, kind, n, m)) = header else {
+            return Err(GraphError::parse(1, content.lines().next().unwrap_or("")));
+        };
+        if graph.edges_length() != m {
+            return Err(GraphError::HeaderMismatch {
+                declared: (n, m),
+                found: (n, graph.edges_length()),
+            });
+        }
+        let problem = match (kind, source, sink) {
+            ("sp", _, _) => DimacsProblem::ShortestPath,
+            (_, Some(source), Some(sink)) => DimacsProblem::MaxFlow { source, sink },
+            _ => {
+                let header = content.lines().nth(header_line - 1).unwrap_or("");
+                return Err(GraphError::parse(header_line, header));
+            }
+        };
+        // Os vértices isolados só são criados depois de validar o arquivo, já que `n` vem de
+        // uma entrada não confiável
+        graph.try_reserve_vertices(n - graph.vertices_length())?;
+        for v in 1..=n as i32 {
+            graph.add_vertice(v);
+        }
+        Ok((graph, problem))
+    }
+
+    /// Lê um arquivo DIMACS (ver [`DiGraph::from_dimacs`])
+    pub fn from_dimacs_file(file_path: &str) -> Result<(DiGraph<i32, W>, DimacsProblem), GraphError> {
+        let content = fs::read_to_string(file_path)?;
+        DiGraph::from_dimacs(&content)
+    }
+}
+
+#[allow(unused)]
+impl<W: Weight> DiGraph<i32, W> {
+    /// Escreve o grafo no formato DIMACS do problema `problem`
+    ///
+    /// Os vértices são renumerados para `1..=n` em ordem crescente de chave (a numeração
+    /// não muda se as chaves já forem `1..=n`). Em `MaxFlow` a capacidade escrita é o
+    /// atributo `EdgeAttribute::Capacity`, ou o peso se a aresta não o tiver.
+    ///
+    /// # Errors
+    /// `GraphError::MissingVertex` se a fonte ou o sumidouro não existirem e
+    /// `GraphError::Io` se a escrita falhar.
+    pub fn write_dimacs<Wr: Write>(&self, out: &mut Wr, problem: DimacsProblem) -> Result<(), GraphError> {
+        let (_, ids) = dense_ids(self);
+        let kind = match problem {
+            DimacsProblem::ShortestPath => "sp",
+            DimacsProblem::MaxFlow { .. } => "max",
+        };
+        writeln!(out, "p {} {} {}", kind, self.vertices_length(), self.edges_length())?;
+        if let DimacsProblem::MaxFlow { source, sink } = problem {
+            let source_id = ids.get(&source).ok_or_else(|| GraphError::missing_vertex(&source))?;
+            let sink_id = ids.get(&sink).ok_or_else(|| GraphError::missing_vertex(&sink))?;
+            writeln!(out, "n {} s", source_id)?;
+            writeln!(out, "n {} t", sink_id)?;
+        }
+
+        let mut edges = self.all_edges();
+        edges.sort_by_key(|e| e.id());
+        for e in edges {
+            let value = match problem {
+                DimacsProblem::ShortestPath => e.weight(),
+                DimacsProblem::MaxFlow { .. } => e.attribute(EdgeAttribute::Capacity).unwrap_or(e.weight()),
+            };
+            writeln!(
+                out,
+                "a {} {} {}",
+                ids[e.origin_key_ref()],
+                ids[e.destiny_key_ref()],
+                value
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Lê um vértice DIMACS, que deve estar em `1..=n`
+fn parse_vertice(token: &str, n: usize) -> Option<i32> {
+    let v: i32 = token.parse().ok()?;
+    (v >= 1 && v as usize <= n).then_some(v)
+}
@@ -0,0 +1,600 @@
+use std::{collections::HashMap, fs, str::FromStr};
+
+use super::{build_styled_graph, StyledEdge, StyledNode};
+use crate::{
+    graph_lib::view::{Color, GraphPainter},
+    tools::weight::Weight,
+    DiGraph, GraphError,
+};
+
+/// Token DOT: identificador (com aspas ou não) ou pontuação (`{`, `->`, `=`, ...)
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Id { text: String, quoted: bool },
+    Punct(&'static str),
+}
+
+/// Separa o texto DOT em tokens com suas linhas
+///
+/// Comentários (`//`, `/* */` e linhas começando com `#`) são descartados, strings HTML
+/// (`<...>`) viram identificadores e strings entre aspas podem ser concatenadas com `+`.
+fn tokenize(content: &str) -> Result<Vec<(usize, Token)>, GraphError> {
+    let parse_error = |line: usize| GraphError::parse(line, content.lines().nth(line - 1).unwrap_or(""));
+    let chars: Vec<char> = content.chars().collect();
+    let mut tokens: Vec<(usize, Token)> = Vec::new();
+    let mut line = 1;
+    let mut i = 0;
+    let mut line_start = true;
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '\n' {
+            line += 1;
+            line_start = true;
+            i += 1;
+            continue;
+        }
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+        let at_line_start = line_start;
+        line_start = false;
+        match c {
+            '#' if at_line_start => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+            }
+            '/' if chars.get(i + 1) == Some(&'/') => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+            }
+            '/' if chars.get(i + 1) == Some(&'*') => {
+                let start = line;
+                i += 2;
+                while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                    if chars[i] == '\n' {
+                        line += 1;
+                    }
+                    i += 1;
+                }
+                if i >= chars.len() {
+                    return Err(parse_error(start));
+                }
+                i += 2;
+            }
+            '{' | '}' | '[' | ']' | '=' | ';' | ',' | ':' => {
+                let punct = match c {
+                    '{' => "{",
+                    '}' => "}",
+                    '[' => "[",
+                    ']' => "]",
+                    '=' => "=",
+                    ';' => ";",
+                    ',' => ",",
+                    _ => ":",
+                };
+                tokens.push((line, Token::Punct(punct)));
+                i += 1;
+            }
+            '-' if matches!(chars.get(i + 1), Some('>') | Some('-')) => {
+                let op = if chars[i + 1] == '>' { "->" } else { "--" };
+                tokens.push((line, Token::Punct(op)));
+                i += 2;
+            }
+            '"' => {
+                let start = line;
+                let mut text = String::new();
+                i += 1;
+                loop {
+                    match chars.get(i) {
+                        None => return Err(parse_error(start)),
+                        Some('"') => break,
+                        Some('\\') if chars.get(i + 1) == Some(&'"') => {
+                            text.push('"');
+                            i += 1;
+                        }
+                        Some('\\') if chars.get(i + 1) == Some(&'\n') => {
+                            line += 1;
+                            i += 1;
+                        }
+                        Some(&c) => {
+                            if c == '\n' {
+                                line += 1;
+                            }
+                            text.push(c);
+                        }
+                    }
+                    i += 1;
+                }
+                i += 1;
+                // "a" + "b" é a concatenação das strings
+                if let Some((_, Token::Punct("+"))) = tokens.last() {
+                    tokens.pop();
+                    if let Some((
+                        _,
+                        Token::Id {
+                            text: previous,
+                            quoted: true,
+                        },
+                    )) = tokens.last_mut()
+                    {
+                        previous.push_str(&text);
+                        continue;
+                    }
+                    return Err(parse_error(start));
+                }
+                tokens.push((start, Token::Id { text, quoted: true }));
+            }
+            '+' => {
+                tokens.push((line, Token::Punct("+")));
+                i += 1;
+            }
+            '<' => {
+                let start = line;
+                let mut depth = 1;
+                let mut text = String::new();
+                i += 1;
+                while depth > 0 {
+                    match chars.get(i) {
+                        None => return Err(parse_error(start)),
+                        Some('<') => depth += 1,
+                        Some('>') => depth -= 1,
+                        Some('\n') => line += 1,
+                        _ => {}
+                    }
+                    if depth > 0 {
+                        text.push(chars[i]);
+                    }
+                    i += 1;
+                }
+                tokens.push((start, Token::Id { text, quoted: true }));
+            }
+            _ => {
+                let mut text = String::new();
+                while let Some(&c) = chars.get(i) {
+                    if !(c.is_alphanumeric() || c == '_' || c == '.' || (c == '-' && text.is_empty()) || !c.is_ascii())
+                    {
+                        break;
+                    }
+                    text.push(c);
+                    i += 1;
+                }
+                if text.is_empty() {
+                    return Err(parse_error(line));
+                }
+                tokens.push((line, Token::Id { text, quoted: false }));
+            }
+        }
+    }
+    if tokens.iter().any(|(_, t)| *t == Token::Punct("+")) {
+        let line = tokens.iter().find(|(_, t)| *t == Token::Punct("+")).unwrap().0;
+        return Err(parse_error(line));
+    }
+    Ok(tokens)
+}
+
+/// Maior profundidade de subgrafos aninhados aceita
+const MAX_DEPTH: usize = 64;
+
+/// Atributos padrão de `node [...]` e `edge [...]`, válidos até o fim do (sub)grafo
+#[derive(Clone, Default)]
+struct Scope {
+    node: HashMap<String, String>,
+    edge: HashMap<String, String>,
+}
+
+struct DotParser<'c> {
+    content: &'c str,
+    tokens: Vec<(usize, Token)>,
+    position: usize,
+    directed: bool,
+    /// Subgrafos abertos, limitado a `MAX_DEPTH` para que `{{{...` não estoure a pilha
+    depth: usize,
+    nodes: Vec<StyledNode>,
+    node_index: HashMap<String, usize>,
+    edges: Vec<StyledEdge>,
+}
+
+impl DotParser<'_> {
+    fn error_at(&self, line: usize) -> GraphError {
+        GraphError::parse(line, self.content.lines().nth(line - 1).unwrap_or(""))
+    }
+
+    /// Erro na linha do token atual (ou na última linha, se o texto acabou)
+    fn error(&self) -> GraphError {
+        let line = match self.tokens.get(self.position) {
+            Some((line, _)) => *line,
+            None => self.tokens.last().map(|(line, _)| *line).unwrap_or(1),
+        };
+        self.error_at(line)
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position).map(|(_, t)| t)
+    }
+
+    fn peek_is(&self, punct: &str) -> bool {
+        matches!(self.peek(), Some(Token::Punct(p)) if *p == punct)
+    }
+
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(Token::Id { text, quoted: false }) if text.eq_ignore_ascii_case(keyword))
+    }
+
+    fn line(&self) -> usize {
+        self.tokens.get(self.position).map(|(line, _)| *line).unwrap_or(1)
+    }
+
+    fn expect(&mut self, punct: &str) -> Result<(), GraphError> {
+        if !self.peek_is(punct) {
+            return Err(self.error());
+        }
+        self.position += 1;
+        Ok(())
+    }
+
+    fn id(&mut self) -> Result<String, GraphError> {
+        match self.peek() {
+            Some(Token::Id { text, .. }) => {
+                let text = text.clone();
+                self.position += 1;
+                Ok(text)
+            }
+            _ => Err(self.error()),
+        }
+    }
+
+    /// `graph`: `[strict] (graph | digraph) [ID] { stmt_list }`
+    fn graph(&mut self) -> Result<(), GraphError> {
+        if self.peek_keyword("strict") {
+            self.position += 1;
+        }
+        if self.peek_keyword("digraph") {
+            self.directed = true;
+        } else if !self.peek_keyword("graph") {
+            return Err(self.error());
+        }
+        self.position += 1;
+        if !self.peek_is("{") {
+            self.id()?;
+        }
+        self.expect("{")?;
+        self.stmt_list(&mut Scope::default())?;
+        self.expect("}")?;
+        if self.position < self.tokens.len() {
+            return Err(self.error());
+        }
+        Ok(())
+    }
+
+    /// Lê comandos até o `}` e retorna os vértices mencionados, para subgrafos usados como extremo
+    fn stmt_list(&mut self, scope: &mut Scope) -> Result<Vec<usize>, GraphError> {
+        let mut mentioned = Vec::new();
+        while self.peek().is_some() && !self.peek_is("}") {
+            mentioned.extend(self.stmt(scope)?);
+            if self.peek_is(";") {
+                self.position += 1;
+            }
+        }
+        Ok(mentioned)
+    }
+
+    fn stmt(&mut self, scope: &mut Scope) -> Result<Vec<usize>, GraphError> {
+        let line = self.line();
+        if self.peek_keyword("node") || self.peek_keyword("edge") || self.peek_keyword("graph") {
+            let is_node = self.peek_keyword("node");
+            let is_edge = self.peek_keyword("edge");
+            self.position += 1;
+            let attributes = self.attr_list()?;
+            if is_node {
+                scope.node.extend(attributes);
+            } else if is_edge {
+                scope.edge.extend(attributes);
+            }
+            return Ok(Vec::new());
+        }
+
+        let first = if self.peek_keyword("subgraph") || self.peek_is("{") {
+            self.subgraph(scope)?
+        } else {
+            let id = self.id()?;
+            if self.peek_is("=") {
+                // atributo do grafo (`label = "..."`)
+                self.position += 1;
+                self.id()?;
+                return Ok(Vec::new());
+            }
+            self.port()?;
+            vec![self.node(&id, scope)]
+        };
+
+        if !(self.peek_is("->") || self.peek_is("--")) {
+            let attributes = self.attr_list()?;
+            for &node in first.iter() {
+                self.apply_node_attributes(node, &attributes);
+            }
+            return Ok(first);
+        }
+
+        let mut operands = vec![first];
+        while self.peek_is("->") || self.peek_is("--") {
+            if self.peek_is("->") != self.directed {
+                return Err(self.error());
+            }
+            self.position += 1;
+            let operand = if self.peek_keyword("subgraph") || self.peek_is("{") {
+                self.subgraph(scope)?
+            } else {
+                let id = self.id()?;
+                self.port()?;
+                vec![self.node(&id, scope)]
+            };
+            operands.push(operand);
+        }
+        let mut attributes = scope.edge.clone();
+        attributes.extend(self.attr_list()?);
+
+        for pair in operands.windows(2) {
+            for &v in pair[0].iter() {
+                for &w in pair[1].iter() {
+                    self.add_edge(line, v, w, &attributes);
+                }
+            }
+        }
+        Ok(operands.concat())
+    }
+
+    /// `subgraph [ID] { stmt_list }` ou `{ stmt_list }`, com escopo próprio de atributos
+    fn subgraph(&mut self, scope: &Scope) -> Result<Vec<usize>, GraphError> {
+        if self.peek_keyword("subgraph") {
+            self.position += 1;
+            if !self.peek_is("{") {
+                self.id()?;
+            }
+        }
+        if self.depth == MAX_DEPTH {
+            return Err(self.error());
+        }
+        self.expect("{")?;
+        self.depth += 1;
+        let mut inner = scope.clone();
+        let mentioned = self.stmt_list(&mut inner)?;
+        self.expect("}")?;
+        self.depth -= 1;
+        Ok(mentioned)
+    }
+
+    /// Porta de um vértice (`a:porta:n`), ignorada
+    fn port(&mut self) -> Result<(), GraphError> {
+        for _ in 0..2 {
+            if !self.peek_is(":") {
+                break;
+            }
+            self.position += 1;
+            self.id()?;
+        }
+        Ok(())
+    }
+
+    /// `[a=b, c=d][e=f]`, possivelmente vazio
+    fn attr_list(&mut self) -> Result<HashMap<String, String>, GraphError> {
+        let mut attributes = HashMap::new();
+        while self.peek_is("[") {
+            self.position += 1;
+            while !self.peek_is("]") {
+                let key = self.id()?;
+                self.expect("=")?;
+                let value = self.id()?;
+                attributes.insert(key, value);
+                if self.peek_is(",") || self.peek_is(";") {
+                    self.position += 1;
+                }
+            }
+            self.position += 1;
+        }
+        Ok(attributes)
+    }
+
+    /// Índice do vértice `id`, criando-o com os atributos padrão se ainda não existir
+    fn node(&mut self, id: &str, scope: &Scope) -> usize {
+        if let Some(&index) = self.node_index.get(id) {
+            return index;
+        }
+        let index = self.nodes.len();
+        self.nodes.push(StyledNode {
+            id: id.to_string(),
+            label: None,
+            color: None,
+        });
+        self.node_index.insert(id.to_string(), index);
+        self.apply_node_attributes(index, &scope.node);
+        index
+    }
+
+    fn apply_node_attributes(&mut self, index: usize, attributes: &HashMap<String, String>) {
+        let node = &mut self.nodes[index];
+        // `\N` é o id do vértice, que já é o label padrão
+        if let Some(label) = attributes.get("label").filter(|l| l.as_str() != "\\N") {
+            node.label = Some(label.clone());
+        }
+        let color = attributes.get("fillcolor").or(attributes.get("color"));
+        if let Some(color) = color.and_then(|c| Color::parse(c)) {
+            node.color = Some(color);
+        }
+    }
+
+    fn add_edge(&mut self, line: usize, v: usize, w: usize, attributes: &HashMap<String, String>) {
+        let (source, target) = (self.nodes[v].id.clone(), self.nodes[w].id.clone());
+        let reversed = !self.directed && v != w;
+        let edge = StyledEdge {
+            line,
+            source,
+            target,
+            weight: attributes.get("weight").cloned(),
+            label: attributes.get("label").cloned(),
+            color: attributes.get("color").and_then(|c| Color::parse(c)),
+        };
+        if reversed {
+            let back = StyledEdge {
+                line,
+                source: edge.target.clone(),
+                target: edge.source.clone(),
+                weight: edge.weight.clone(),
+                label: edge.label.clone(),
+                color: edge.color,
+            };
+            self.edges.push(back);
+        }
+        self.edges.push(edge);
+    }
+}
+
+#[allow(unused)]
+impl<W: Weight + FromStr> DiGraph<i32, W> {
+    /// Lê um grafo no formato DOT (Graphviz), `digraph` ou `graph`
+    ///
+    /// O peso de cada aresta é o atributo `weight`; sem ele, o `label` se for um número
+    /// (como em [`GraphPainter::to_dot`]); sem nenhum dos dois, 1. Os atributos `label` e
+    /// `fillcolor`/`color` dos vértices e `label` e `color` das arestas vão para o
+    /// `GraphPainter` retornado; cores que `Color` não conhece são ignoradas.
+    ///
+    /// Atributos padrão (`node [...]`, `edge [...]`), subgrafos e extremos como
+    /// `a -> {b c}` são aceitos; em `graph` cada aresta (exceto laços) vira dois arcos.
+    /// Ver `build_styled_graph` para como os ids dos vértices viram chaves.
+    /// ```
+    /// # use rust_graph::{DiGraph, graph_lib::view::Color};
+    /// let content = r#"digraph G {
+    ///     node [color=red];
+    ///     a -> b [weight=4];
+    ///     b -> {c d} [label="2"];
+    ///     c [label="fim", fillcolor=blue];
+    /// }"#;
+    /// let (graph, painter): (DiGraph, _) = DiGraph::from_dot(content).unwrap();
+    /// assert_eq!(graph.edges_length(), 3);
+    /// assert_eq!(painter.vertice_label(0), Some("a"));
+    /// assert_eq!(painter.vertice_color(0), Some(Color::Red));
+    /// assert_eq!(painter.vertice_color(2), Some(Color::Blue));
+    /// assert!(graph.get_edges(1, 2).unwrap().iter().all(|e| e.weight() == 2));
+    /// ```
+    ///
+    /// # Errors
+    /// `GraphError::Parse` com a linha inválida (inclusive `--` em `digraph`, `->` em
+    /// `graph` e subgrafos aninhados em mais de 64 níveis) e para pesos inválidos.
+    pub fn from_dot(content: &str) -> Result<(DiGraph<i32, W>, GraphPainter), GraphError> {
+        let mut parser = DotParser {
+            content,
+            tokens: tokenize(content)?,
+            position: 0,
+            directed: false,
+            depth: 0,
+            nodes: Vec::new(),
+            node_index: HashMap::new(),
+            edges: Vec::new(),
+        };
+        parser.graph()?;
+
+        let edges = parser
+            .edges
+            .into_iter()
+            .map(|mut edge| {
+                // sem `weight`, o label é o peso se for um número
+                if edge.weight.is_none() {
+                    edge.weight = edge.label.clone().filter(|l| l.trim().parse::<W>().is_ok());
+                }
+                edge
+            })
+            .collect();
+        build_styled_graph(parser.nodes, edges)
+    }
+
+    /// Lê um arquivo DOT (ver [`DiGraph::from_dot`])
+    pub fn from_dot_file(file_path: &str) -> Result<(DiGraph<i32, W>, GraphPainter), GraphError> {
+        let content = fs::read_to_string(file_path)?;
+        DiGraph::from_dot(&content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(content: &str) -> Result<(DiGraph, GraphPainter), GraphError> {
+        DiGraph::from_dot(content)
+    }
+
+    /// Linha do erro de leitura, falhando o teste se o texto for aceito ou o erro for outro
+    fn error_line(content: &str) -> usize {
+        match parse(content) {
+            Err(GraphError::Parse { line, .. }) => line,
+            Err(other) => panic!("expected a parse error for {content:?}, got {other}"),
+            Ok(_) => panic!("malformed DOT was accepted: {content:?}"),
+        }
+    }
+
+    #[test]
+    fn unterminated_input_is_a_parse_error() {
+        assert_eq!(error_line(""), 1);
+        assert_eq!(error_line("digraph"), 1);
+        assert_eq!(error_line("digraph G {\n a -> b;\n"), 2);
+        assert_eq!(error_line("digraph G {\n a -> b [label=x\n}"), 3);
+        assert_eq!(error_line("digraph G {\n a -> \"b;\n}"), 2);
+        assert_eq!(error_line("digraph G {\n /* a -> b;\n}"), 2);
+        assert_eq!(error_line("digraph G {\n a [label=<<b>x</b>];\n}"), 2);
+        assert_eq!(error_line("digraph G {\n subgraph { a -> b;\n}"), 3);
+    }
+
+    #[test]
+    fn missing_ids_and_extra_tokens_are_parse_errors() {
+        assert_eq!(error_line("graph G { }\n}"), 2);
+        assert_eq!(error_line("tree G { a }"), 1);
+        assert_eq!(error_line("digraph G {\n a -> ;\n}"), 2);
+        assert_eq!(error_line("digraph G {\n -> b;\n}"), 2);
+        assert_eq!(error_line("digraph G {\n a -> b -> ;\n}"), 2);
+        assert_eq!(error_line("digraph G {\n label = ;\n}"), 2);
+        assert_eq!(error_line("digraph G {\n a:;\n}"), 2);
+        assert_eq!(error_line("digraph G {\n a @ b;\n}"), 2);
+        assert_eq!(error_line("digraph G {\n \"a\" + b;\n}"), 2);
+    }
+
+    #[test]
+    fn malformed_attributes_are_parse_errors() {
+        assert_eq!(error_line("digraph G {\n a [label];\n}"), 2);
+        assert_eq!(error_line("digraph G {\n a [label=];\n}"), 2);
+        assert_eq!(error_line("digraph G {\n a [=x];\n}"), 2);
+        assert_eq!(error_line("digraph G {\n node [color=red;\n}"), 3);
+    }
+
+    #[test]
+    fn wrong_edge_operator_is_a_parse_error() {
+        assert_eq!(error_line("digraph G {\n a -- b;\n}"), 2);
+        assert_eq!(error_line("graph G {\n a -- b;\n a -> c;\n}"), 3);
+    }
+
+    #[test]
+    fn invalid_weights_report_the_edge_line() {
+        assert_eq!(error_line("digraph G {\n a -> b;\n b -> c [weight=heavy];\n}"), 3);
+        // Um label que não é número não é peso
+        let (graph, _) = parse("digraph G { a -> b [label=heavy] }").unwrap();
+        assert!(graph.get_edges(0, 1).unwrap().iter().all(|e| e.weight() == 1));
+    }
+
+    #[test]
+    fn deep_subgraph_nesting_is_a_parse_error() {
+        let nested = |depth: usize| format!("digraph G {{ {} a {} }}", "{".repeat(depth), "}".repeat(depth));
+        let (graph, _) = parse(&nested(MAX_DEPTH)).unwrap();
+        assert_eq!(graph.vertices_length(), 1);
+        assert_eq!(error_line(&nested(MAX_DEPTH + 1)), 1);
+        assert_eq!(error_line(&nested(100_000)), 1);
+    }
+
+    #[test]
+    fn comments_and_undirected_edges() {
+        let content = "# cabeçalho\ngraph {\n // comentário\n a -- b /* c -- d */;\n c;\n}";
+        let (graph, painter) = parse(content).unwrap();
+        assert_eq!(graph.vertices_length(), 3);
+        assert_eq!(graph.edges_length(), 2);
+        assert_eq!(painter.vertice_label(2), Some("c"));
+    }
+}
@@ -0,0 +1,294 @@
+use std::{fs, io::Write, str::FromStr};
+
+use super::{build_styled_graph, StyledEdge, StyledNode};
+use crate::{
+    graph_lib::view::{Color, GraphPainter},
+    tools::weight::Weight,
+    DiGraph, GraphError,
+};
+
+/// Valor de um par `chave valor` do GML: número, texto entre aspas ou lista `[ ... ]`
+enum GmlValue {
+    Scalar(String),
+    List(Vec<GmlPair>),
+}
+
+/// Par `chave valor` com a linha onde a chave aparece
+struct GmlPair {
+    key: String,
+    value: GmlValue,
+    line: usize,
+}
+
+impl GmlValue {
+    fn scalar(&self) -> Option<&str> {
+        match self {
+            GmlValue::Scalar(value) => Some(value),
+            GmlValue::List(_) => None,
+        }
+    }
+}
+
+/// Primeiro valor escalar com a chave `key` em uma lista
+fn find_scalar<'a>(pairs: &'a [GmlPair], key: &str) -> Option<&'a str> {
+    pairs.iter().find(|p| p.key == key).and_then(|p| p.value.scalar())
+}
+
+/// Separa o texto em tokens (`[`, `]`, chaves, números e textos entre aspas) com suas linhas
+///
+/// Textos entre aspas mantêm as aspas para diferenciá-los das chaves.
+fn tokenize(content: &str) -> Result<Vec<(usize, String)>, GraphError> {
+    let mut tokens = Vec::new();
+    let mut chars = content.chars().peekable();
+    let mut line = 1;
+    while let Some(&c) = chars.peek() {
+        match c {
+            '\n' => {
+                line += 1;
+                chars.next();
+            }
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '#' => {
+                while chars.peek().is_some_and(|&c| c != '\n') {
+                    chars.next();
+                }
+            }
+            '[' | ']' => {
+                tokens.push((line, c.to_string()));
+                chars.next();
+            }
+            '"' => {
+                let start = line;
+                let mut text = String::from('"');
+                chars.next();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some(c) => {
+                            if c == '\n' {
+                                line += 1;
+                            }
+                            text.push(c);
+                        }
+                        None => {
+                            let content = content.lines().nth(start - 1).unwrap_or("");
+                            return Err(GraphError::parse(start, content));
+                        }
+                    }
+                }
+                tokens.push((start, text));
+            }
+            _ => {
+                let mut word = String::new();
+                while chars
+                    .peek()
+                    .is_some_and(|&c| !c.is_whitespace() && c != '[' && c != ']' && c != '"')
+                {
+                    word.push(chars.next().unwrap());
+                }
+                tokens.push((line, word));
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+/// Maior profundidade de listas `[ ... ]` aceita; o grafo usa 3 (`graph [ node [ graphics [`)
+const MAX_DEPTH: usize = 64;
+
+/// Lê uma lista de pares até o `]` correspondente (ou o fim, no nível mais externo, `depth` 0)
+///
+/// Listas mais profundas que `MAX_DEPTH` são um erro, para que arquivos com muitos `[`
+/// não estourem a pilha.
+fn parse_list<I: Iterator<Item = (usize, String)>>(
+    tokens: &mut I,
+    content: &str,
+    depth: usize,
+) -> Result<Vec<GmlPair>, GraphError> {
+    let nested = depth > 0;
+    let parse_error = |line: usize| GraphError::parse(line, content.lines().nth(line - 1).unwrap_or(""));
+    let mut pairs = Vec::new();
+    while let Some((line, key)) = tokens.next() {
+        if key == "]" {
+            return if nested { Ok(pairs) } else { Err(parse_error(line)) };
+        }
+        if key == "[" || key.starts_with('"') {
+            return Err(parse_error(line));
+        }
+        let value = match tokens.next() {
+            Some((line, token)) if token == "[" && depth == MAX_DEPTH => return Err(parse_error(line)),
+            Some((_, token)) if token == "[" => GmlValue::List(parse_list(tokens, content, depth + 1)?),
+            Some((line, token)) if token == "]" => return Err(parse_error(line)),
+            Some((_, token)) => GmlValue::Scalar(token.strip_prefix('"').map(unescape).unwrap_or(token)),
+            None => return Err(parse_error(line)),
+        };
+        pairs.push(GmlPair { key, value, line });
+    }
+    if nested {
+        let last = content.lines().count().max(1);
+        return Err(parse_error(last));
+    }
+    Ok(pairs)
+}
+
+/// Troca as entidades `&quot;`, `&amp;`, ... que o GML usa dentro de textos
+fn unescape(text: &str) -> String {
+    text.replace("&quot;", "\"")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('"', "&quot;")
+}
+
+/// Cor de um vértice ou aresta: `graphics [ fill "#RRGGBB" ]` (ou `color`)
+fn color_of(pairs: &[GmlPair]) -> Option<Color> {
+    let graphics = pairs.iter().find_map(|p| match (&p.value, p.key.as_str()) {
+        (GmlValue::List(graphics), "graphics") => Some(graphics),
+        _ => None,
+    });
+    graphics
+        .and_then(|g| find_scalar(g, "fill").or(find_scalar(g, "color")))
+        .or(find_scalar(pairs, "color"))
+        .and_then(Color::parse)
+}
+
+#[allow(unused)]
+impl<W: Weight + FromStr> DiGraph<i32, W> {
+    /// Lê um grafo no formato GML
+    ///
+    /// São lidos `id`, `label` e `graphics [ fill ]` dos vértices e `source`, `target`,
+    /// `weight` (ou `value`), `label` e `graphics [ fill ]` das arestas; as demais chaves
+    /// são ignoradas. Se o grafo não tiver `directed 1` cada aresta (exceto laços) vira
+    /// dois arcos.
+    ///
+    /// Os labels e cores são retornados em um `GraphPainter`; ver `build_styled_graph`
+    /// para como os ids dos vértices viram chaves.
+    /// ```
+    /// # use rust_graph::{DiGraph, graph_lib::view::Color};
+    /// let content = r##"graph [ directed 1
+    ///     node [ id 1 label "a" graphics [ fill "#FF0000" ] ]
+    ///     node [ id 2 label "b" ]
+    ///     edge [ source 1 target 2 weight 5 ]
+    /// ]"##;
+    /// let (graph, painter): (DiGraph, _) = DiGraph::from_gml(content).unwrap();
+    /// assert_eq!(graph.edges_length(), 1);
+    /// assert_eq!(painter.vertice_label(1), Some("a"));
+    /// assert_eq!(painter.vertice_color(1), Some(Color::Red));
+    /// ```
+    ///
+    /// # Errors
+    /// `GraphError::Parse` com a linha inválida (inclusive listas aninhadas em mais de 64
+    /// níveis) e `GraphError::MissingVertex` se uma aresta usar um vértice não declarado.
+    pub fn from_gml(content: &str) -> Result<(DiGraph<i32, W>, GraphPainter), GraphError> {
+        let mut tokens = tokenize(content)?.into_iter();
+        let root = parse_list(&mut tokens, content, 0)?;
+        let graph = root
+            .iter()
+            .find_map(|p| match (&p.value, p.key.as_str()) {
+                (GmlValue::List(graph), "graph") => Some(graph),
+                _ => None,
+            })
+            .ok_or_else(|| GraphError::parse(1, content.lines().next().unwrap_or("")))?;
+        let directed = find_scalar(graph, "directed") == Some("1");
+
+        let mut nodes = Vec::new();
+        let mut edges = Vec::new();
+        for pair in graph {
+            let GmlValue::List(items) = &pair.value else {
+                continue;
+            };
+            let line_error = || GraphError::parse(pair.line, content.lines().nth(pair.line - 1).unwrap_or(""));
+            match pair.key.as_str() {
+                "node" => nodes.push(StyledNode {
+                    id: find_scalar(items, "id").ok_or_else(line_error)?.to_string(),
+                    label: find_scalar(items, "label").map(String::from),
+                    color: color_of(items),
+                }),
+                "edge" => {
+                    let edge = StyledEdge {
+                        line: pair.line,
+                        source: find_scalar(items, "source").ok_or_else(line_error)?.to_string(),
+                        target: find_scalar(items, "target").ok_or_else(line_error)?.to_string(),
+                        weight: find_scalar(items, "weight")
+                            .or(find_scalar(items, "value"))
+                            .map(String::from),
+                        label: find_scalar(items, "label").map(String::from),
+                        color: color_of(items),
+                    };
+                    if !directed && edge.source != edge.target {
+                        edges.push(StyledEdge {
+                            line: edge.line,
+                            source: edge.target.clone(),
+                            target: edge.source.clone(),
+                            weight: edge.weight.clone(),
+                            label: edge.label.clone(),
+                            color: edge.color,
+                        });
+                    }
+                    edges.push(edge);
+                }
+                _ => {}
+            }
+        }
+
+        build_styled_graph(nodes, edges)
+    }
+
+    /// Lê um arquivo GML (ver [`DiGraph::from_gml`])
+    pub fn from_gml_file(file_path: &str) -> Result<(DiGraph<i32, W>, GraphPainter), GraphError> {
+        let content = fs::read_to_string(file_path)?;
+        DiGraph::from_gml(&content)
+    }
+}
+
+#[allow(unused)]
+impl<W: Weight> DiGraph<i32, W> {
+    /// Escreve o grafo no formato GML (`directed 1`)
+    ///
+    /// Cada vértice recebe `label` (a chave, ou o label do `painter`) e cada aresta recebe
+    /// `weight`. Se houver `painter`, as cores são escritas em `graphics [ fill "#RRGGBB" ]`
+    /// e os labels das arestas em `label`.
+    ///
+    /// # Errors
+    /// `GraphError::Io` se a escrita falhar.
+    pub fn write_gml<Wr: Write>(&self, out: &mut Wr, painter: Option<&GraphPainter>) -> Result<(), GraphError> {
+        writeln!(out, "graph [")?;
+        writeln!(out, "  directed 1")?;
+
+        let mut keys = self.get_vertice_key_array();
+        keys.sort();
+        for v in keys {
+            let label = painter
+                .and_then(|p| p.vertice_label(v))
+                .map(|l| l.to_string())
+                .unwrap_or(v.to_string());
+            write!(out, "  node [ id {} label \"{}\"", v, escape(&label))?;
+            if let Some(color) = painter.and_then(|p| p.vertice_color(v)) {
+                write!(out, " graphics [ fill \"{}\" ]", color.to_hex())?;
+            }
+            writeln!(out, " ]")?;
+        }
+
+        let mut edges = self.all_edges();
+        edges.sort_by_key(|e| e.id());
+        for e in edges {
+            let (v, w) = e.v_w();
+            write!(out, "  edge [ source {} target {} weight {}", v, w, e.weight())?;
+            if let Some(label) = painter.and_then(|p| p.edge_label(v, w)) {
+                write!(out, " label \"{}\"", escape(label))?;
+            }
+            if let Some(color) = painter.and_then(|p| p.edge_color(v, w)) {
+                write!(out, " graphics [ fill \"{}\" ]", color.to_hex())?;
+            }
+            writeln!(out, " ]")?;
+        }
+
+        writeln!(out, "]")?;
+        Ok(())
+    }
+}
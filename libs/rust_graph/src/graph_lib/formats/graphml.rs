@@ -0,0 +1,360 @@
+use std::{collections::HashMap, fs, io::Write, str::FromStr};
+
+use quick_xml::{
+    escape::escape,
+    events::{BytesStart, Event},
+    Reader,
+};
+
+use super::{build_styled_graph, StyledEdge, StyledNode};
+use crate::{
+    graph_lib::view::{Color, GraphPainter},
+    tools::weight::Weight,
+    DiGraph, GraphError,
+};
+
+/// Atributo GraphML (`<key>`) reconhecido pelo leitor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum KeyKind {
+    Label,
+    Color,
+    Weight,
+    Red,
+    Green,
+    Blue,
+}
+
+/// Converte posições do texto em números de linha (começando em 1)
+///
+/// As posições chegam em ordem crescente, então cada trecho é percorrido uma vez só.
+struct LineCounter<'a> {
+    content: &'a [u8],
+    position: usize,
+    line: usize,
+}
+
+impl<'a> LineCounter<'a> {
+    fn new(content: &'a str) -> Self {
+        LineCounter {
+            content: content.as_bytes(),
+            position: 0,
+            line: 1,
+        }
+    }
+
+    fn line_of(&mut self, position: u64) -> usize {
+        let position = (position as usize).min(self.content.len());
+        if position < self.position {
+            self.position = 0;
+            self.line = 1;
+        }
+        self.line += self.content[self.position..position]
+            .iter()
+            .filter(|&&b| b == b'\n')
+            .count();
+        self.position = position;
+        self.line
+    }
+}
+
+/// Elemento cujos `<data>` estão sendo lidos
+enum Current {
+    None,
+    Node(usize),
+    Edge(usize),
+}
+
+#[allow(unused)]
+impl<W: Weight + FromStr> DiGraph<i32, W> {
+    /// Lê um grafo no formato GraphML
+    ///
+    /// São lidos os atributos (`<key attr.name=...>`) `label` e `color` dos vértices e
+    /// `weight`, `label` e `color` das arestas, além de `r`, `g`, `b` dos vértices (Gephi).
+    /// Os demais atributos são ignorados. Em grafos `edgedefault="undirected"` cada aresta
+    /// não direcionada (exceto laços) vira dois arcos.
+    ///
+    /// Os labels e cores são retornados em um `GraphPainter`; ver `build_styled_graph`
+    /// para como os ids dos vértices viram chaves.
+    ///
+    /// # Errors
+    /// `GraphError::Parse` para XML ou pesos inválidos e `GraphError::MissingVertex`
+    /// se uma aresta usar um vértice não declarado.
+    pub fn from_graphml(content: &str) -> Result<(DiGraph<i32, W>, GraphPainter), GraphError> {
+        let mut lines = LineCounter::new(content);
+        let mut reader = Reader::from_str(content);
+        reader.config_mut().trim_text(true);
+
+        let mut keys: HashMap<String, (bool, KeyKind)> = HashMap::new(); // id -> (é de aresta, tipo)
+        let mut defaults: HashMap<String, String> = HashMap::new();
+        let mut nodes: Vec<StyledNode> = Vec::new();
+        let mut rgb: Vec<[Option<u8>; 3]> = Vec::new();
+        let mut edges: Vec<StyledEdge> = Vec::new();
+        let mut undirected = false;
+        let mut undirected_edges: Vec<usize> = Vec::new();
+        let mut current = Current::None;
+        let mut data_key: Option<String> = None;
+        let mut default_key: Option<String> = None;
+
+        loop {
+            let position = reader.buffer_position();
+            let parse_error = |line: usize| GraphError::parse(line, content.lines().nth(line - 1).unwrap_or(""));
+            let event = reader
+                .read_event()
+                .map_err(|_| parse_error(lines.line_of(reader.error_position())))?;
+            let line = lines.line_of(position);
+            match event {
+                Event::Start(ref e) | Event::Empty(ref e) => {
+                    let is_empty = matches!(event, Event::Empty(_));
+                    let attributes = attributes_of(e).map_err(|_| parse_error(line))?;
+                    match e.local_name().as_ref() {
+                        b"key" => {
+                            let id = attributes.get("id").cloned().unwrap_or_default();
+                            let for_edge = attributes.get("for").map(|f| f == "edge").unwrap_or(false);
+                            let kind = match attributes.get("attr.name").map(|n| n.as_str()) {
+                                Some("label") => Some(KeyKind::Label),
+                                Some("color") => Some(KeyKind::Color),
+                                Some("weight") if for_edge => Some(KeyKind::Weight),
+                                Some("r") if !for_edge => Some(KeyKind::Red),
+                                Some("g") if !for_edge => Some(KeyKind::Green),
+                                Some("b") if !for_edge => Some(KeyKind::Blue),
+                                _ => None,
+                            };
+                            if let Some(kind) = kind {
+                                keys.insert(id.clone(), (for_edge, kind));
+                            }
+                            if !is_empty {
+                                default_key = Some(id);
+                            }
+                        }
+                        b"default" => {}
+                        b"graph" => {
+                            undirected = attributes
+                                .get("edgedefault")
+                                .map(|d| d == "undirected")
+                                .unwrap_or(false);
+                        }
+                        b"node" => {
+                            let id = attributes.get("id").cloned().ok_or_else(|| parse_error(line))?;
+                            nodes.push(StyledNode {
+                                id,
+                                label: None,
+                                color: None,
+                            });
+                            rgb.push([None; 3]);
+                            if !is_empty {
+                                current = Current::Node(nodes.len() - 1);
+                            }
+                        }
+                        b"edge" => {
+                            let source = attributes.get("source").cloned().ok_or_else(|| parse_error(line))?;
+                            let target = attributes.get("target").cloned().ok_or_else(|| parse_error(line))?;
+                            let directed = match attributes.get("directed").map(|d| d.as_str()) {
+                                Some("true") => true,
+                                Some("false") => false,
+                                _ => !undirected,
+                            };
+                            edges.push(StyledEdge {
+                                line,
+                                source,
+                                target,
+                                weight: None,
+                                label: None,
+                                color: None,
+                            });
+                            if !directed && edges.last().is_some_and(|e| e.source != e.target) {
+                                undirected_edges.push(edges.len() - 1);
+                            }
+                            if !is_empty {
+                                current = Current::Edge(edges.len() - 1);
+                            }
+                        }
+                        b"data" => {
+                            data_key = attributes.get("key").cloned();
+                        }
+                        _ => {}
+                    }
+                }
+                Event::Text(ref t) => {
+                    let text = t.unescape().map_err(|_| parse_error(line))?.into_owned();
+                    if let Some(key) = default_key.as_ref() {
+                        defaults.insert(key.clone(), text);
+                        continue;
+                    }
+                    let Some(key) = data_key.as_ref() else {
+                        continue;
+                    };
+                    match (&current, keys.get(key)) {
+                        (Current::Node(i), Some((false, kind))) => match kind {
+                            KeyKind::Label => nodes[*i].label = Some(text),
+                            KeyKind::Color => nodes[*i].color = Color::parse(&text),
+                            KeyKind::Red | KeyKind::Green | KeyKind::Blue => {
+                                let channel = *kind as usize - KeyKind::Red as usize;
+                                rgb[*i][channel] = text.trim().parse::<f64>().ok().map(|c| c as u8);
+                            }
+                            KeyKind::Weight => {}
+                        },
+                        (Current::Edge(i), Some((true, kind))) => match kind {
+                            KeyKind::Label => edges[*i].label = Some(text),
+                            KeyKind::Color => edges[*i].color = Color::parse(&text),
+                            KeyKind::Weight => edges[*i].weight = Some(text),
+                            _ => {}
+                        },
+                        _ => {}
+                    }
+                }
+                Event::End(ref e) => match e.local_name().as_ref() {
+                    b"key" => default_key = None,
+                    b"data" => data_key = None,
+                    b"node" | b"edge" => current = Current::None,
+                    _ => {}
+                },
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+
+        // Valores padrão dos atributos e cores RGB (Gephi)
+        for (i, node) in nodes.iter_mut().enumerate() {
+            if let [Some(r), Some(g), Some(b)] = rgb[i] {
+                node.color = node.color.or(Color::parse(&Color::Rgb(r, g, b).to_hex()));
+            }
+            for (key, (for_edge, kind)) in keys.iter() {
+                match (defaults.get(key), for_edge, kind) {
+                    (Some(value), false, KeyKind::Label) => {
+                        node.label.get_or_insert_with(|| value.clone());
+                    }
+                    (Some(value), false, KeyKind::Color) => node.color = node.color.or(Color::parse(value)),
+                    _ => {}
+                }
+            }
+        }
+        for edge in edges.iter_mut() {
+            for (key, (for_edge, kind)) in keys.iter() {
+                match (defaults.get(key), for_edge, kind) {
+                    (Some(value), true, KeyKind::Weight) => {
+                        edge.weight.get_or_insert_with(|| value.clone());
+                    }
+                    (Some(value), true, KeyKind::Label) => {
+                        edge.label.get_or_insert_with(|| value.clone());
+                    }
+                    (Some(value), true, KeyKind::Color) => edge.color = edge.color.or(Color::parse(value)),
+                    _ => {}
+                }
+            }
+        }
+
+        // Arestas não direcionadas viram dois arcos
+        for i in undirected_edges {
+            let edge = &edges[i];
+            let reversed = StyledEdge {
+                line: edge.line,
+                source: edge.target.clone(),
+                target: edge.source.clone(),
+                weight: edge.weight.clone(),
+                label: edge.label.clone(),
+                color: edge.color,
+            };
+            edges.push(reversed);
+        }
+
+        build_styled_graph(nodes, edges)
+    }
+
+    /// Lê um arquivo GraphML (ver [`DiGraph::from_graphml`])
+    pub fn from_graphml_file(file_path: &str) -> Result<(DiGraph<i32, W>, GraphPainter), GraphError> {
+        let content = fs::read_to_string(file_path)?;
+        DiGraph::from_graphml(&content)
+    }
+}
+
+#[allow(unused)]
+impl<W: Weight> DiGraph<i32, W> {
+    /// Escreve o grafo no formato GraphML
+    ///
+    /// Cada vértice recebe os atributos `label` (a chave, ou o label do `painter`) e, se houver
+    /// `painter`, `color`; cada aresta recebe `weight` e, se houver `painter`, `label` e `color`.
+    /// As cores são escritas como `#RRGGBB`.
+    ///
+    /// # Errors
+    /// `GraphError::Io` se a escrita falhar.
+    pub fn write_graphml<Wr: Write>(&self, out: &mut Wr, painter: Option<&GraphPainter>) -> Result<(), GraphError> {
+        writeln!(out, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(out, r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#)?;
+        writeln!(
+            out,
+            r#"  <key id="label" for="node" attr.name="label" attr.type="string"/>"#
+        )?;
+        writeln!(
+            out,
+            r#"  <key id="color" for="node" attr.name="color" attr.type="string"/>"#
+        )?;
+        writeln!(
+            out,
+            r#"  <key id="weight" for="edge" attr.name="weight" attr.type="double"/>"#
+        )?;
+        writeln!(
+            out,
+            r#"  <key id="edge_label" for="edge" attr.name="label" attr.type="string"/>"#
+        )?;
+        writeln!(
+            out,
+            r#"  <key id="edge_color" for="edge" attr.name="color" attr.type="string"/>"#
+        )?;
+        writeln!(out, r#"  <graph id="G" edgedefault="directed">"#)?;
+
+        let mut keys = self.get_vertice_key_array();
+        keys.sort();
+        for v in keys {
+            let label = painter
+                .and_then(|p| p.vertice_label(v))
+                .map(|l| l.to_string())
+                .unwrap_or(v.to_string());
+            write!(
+                out,
+                r#"    <node id="{}"><data key="label">{}</data>"#,
+                v,
+                escape(&label)
+            )?;
+            if let Some(color) = painter.and_then(|p| p.vertice_color(v)) {
+                write!(out, r#"<data key="color">{}</data>"#, color.to_hex())?;
+            }
+            writeln!(out, "</node>")?;
+        }
+
+        let mut edges = self.all_edges();
+        edges.sort_by_key(|e| e.id());
+        for e in edges {
+            let (v, w) = e.v_w();
+            write!(
+                out,
+                r#"    <edge id="e{}" source="{}" target="{}"><data key="weight">{}</data>"#,
+                e.id(),
+                v,
+                w,
+                e.weight()
+            )?;
+            if let Some(label) = painter.and_then(|p| p.edge_label(v, w)) {
+                write!(out, r#"<data key="edge_label">{}</data>"#, escape(label))?;
+            }
+            if let Some(color) = painter.and_then(|p| p.edge_color(v, w)) {
+                write!(out, r#"<data key="edge_color">{}</data>"#, color.to_hex())?;
+            }
+            writeln!(out, "</edge>")?;
+        }
+
+        writeln!(out, "  </graph>")?;
+        writeln!(out, "</graphml>")?;
+        Ok(())
+    }
+}
+
+/// Atributos de um elemento, já sem escape
+fn attributes_of(e: &BytesStart) -> Result<HashMap<String, String>, quick_xml::Error> {
+    let mut map = HashMap::new();
+    for attribute in e.attributes() {
+        let attribute = attribute.map_err(quick_xml::Error::from)?;
+        let key = String::from_utf8_lossy(attribute.key.local_name().as_ref()).into_owned();
+        map.insert(key, attribute.unescape_value()?.into_owned());
+    }
+    Ok(map)
+}
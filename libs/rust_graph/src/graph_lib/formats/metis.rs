@@ -0,0 +1,157 @@
+use std::{collections::HashMap, fs, io::Write, str::FromStr};
+
+use super::{dense_ids, MAX_PRESIZE};
+use crate::{graph_lib::edge::Edge, tools::weight::Weight, DiGraph, GraphError};
+
+#[allow(unused)]
+impl<W: Weight + FromStr> DiGraph<i32, W> {
+    /// Lê um grafo no formato METIS
+    ///
+    /// METIS descreve grafos não direcionados: a primeira linha é `n m [fmt [ncon]]` e a
+    /// linha `i` seguinte lista os vizinhos do vértice `i` (`1..=n`), cada um seguido do
+    /// peso da aresta quando `fmt` termina em 1. Linhas começando com `%` são comentários.
+    ///
+    /// Cada vizinho listado vira um arco, então cada aresta `{v, w}` do arquivo vira
+    /// `v -> w` e `w -> v`. Pesos de vértices (`fmt` 10/11) são lidos e descartados.
+    ///
+    /// # Errors
+    /// `GraphError::Parse` com a linha inválida (ou com o cabeçalho, se `n` não couber nas
+    /// chaves `i32`) e `GraphError::HeaderMismatch` se a quantidade de linhas de vértices ou de arestas não bater com o cabeçalho.
+    pub fn from_metis(content: &str) -> Result<DiGraph<i32, W>, GraphError> {
+        // Comentários não contam na numeração dos vértices, mas linhas vazias sim
+        let mut lines = content
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim_start().starts_with('%'));
+
+        let Some((header_index, header)) = lines.next() else {
+            return Err(GraphError::parse(1, ""));
+        };
+        let header_error = || GraphError::parse(header_index + 1, header);
+        let fields: Vec<usize> = header
+            .split_whitespace()
+            .map(|t| t.parse().map_err(|_| header_error()))
+            .collect::<Result<_, _>>()?;
+        let (n, m, fmt, ncon) = match fields.as_slice() {
+            [n, m] => (*n, *m, 0, 1),
+            [n, m, fmt] => (*n, *m, *fmt, 1),
+            [n, m, fmt, ncon] => (*n, *m, *fmt, *ncon),
+            _ => return Err(header_error()),
+        };
+        let edge_weights = fmt % 10 == 1;
+        let vertice_weights = if (fmt / 10) % 10 == 1 { ncon } else { 0 };
+        let vertice_sizes = usize::from((fmt / 100) % 10 == 1);
+
+        // As chaves são `1..=n`; o grafo cresce conforme as linhas dos vértices chegam
+        i32::try_from(n).map_err(|_| header_error())?;
+        let mut graph = DiGraph::new_sized(n.min(MAX_PRESIZE) as u32);
+
+        let mut v = 0;
+        for (index, line) in lines {
+            let parse_error = || GraphError::parse(index + 1, line);
+            v += 1;
+            if v > n {
+                if line.trim().is_empty() {
+                    continue;
+                }
+                return Err(GraphError::HeaderMismatch {
+                    declared: (n, m),
+                    found: (v, graph.edges_length() / 2),
+                });
+            }
+            let key = i32::try_from(v).map_err(|_| parse_error())?;
+            graph.add_vertice(key);
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            let skip = vertice_sizes + vertice_weights;
+            if tokens.len() < skip {
+                return Err(parse_error());
+            }
+            let step = if edge_weights { 2 } else { 1 };
+            let neighbours = &tokens[skip..];
+            if !neighbours.len().is_multiple_of(step) {
+                return Err(parse_error());
+            }
+            for pair in neighbours.chunks(step) {
+                let w: i32 = pair[0].parse().map_err(|_| parse_error())?;
+                if w < 1 || w as usize > n {
+                    return Err(parse_error());
+                }
+                let weight = match pair.get(1) {
+                    Some(weight) => weight.parse::<W>().map_err(|_| parse_error())?,
+                    None => W::one(),
+                };
+                graph.add_edge(Edge::new_weighted(key, w, weight));
+            }
+        }
+
+        if v < n || graph.edges_length() != 2 * m {
+            return Err(GraphError::HeaderMismatch {
+                declared: (n, m),
+                found: (v.min(n), graph.edges_length() / 2),
+            });
+        }
+        Ok(graph)
+    }
+
+    /// Lê um arquivo METIS (ver [`DiGraph::from_metis`])
+    pub fn from_metis_file(file_path: &str) -> Result<DiGraph<i32, W>, GraphError> {
+        let content = fs::read_to_string(file_path)?;
+        DiGraph::from_metis(&content)
+    }
+}
+
+#[allow(unused)]
+impl<W: Weight> DiGraph<i32, W> {
+    /// Escreve o grafo no formato METIS
+    ///
+    /// Como METIS é não direcionado, cada par de vértices ligados por um arco em qualquer
+    /// direção vira uma aresta, com o menor peso entre os arcos do par. Laços são descartados
+    /// (METIS não os aceita). Os vértices são renumerados para `1..=n` em ordem crescente de
+    /// chave, e os pesos só são escritos (`fmt` 1) se algum for diferente de 1.
+    ///
+    /// # Errors
+    /// `GraphError::Io` se a escrita falhar.
+    pub fn write_metis<Wr: Write>(&self, out: &mut Wr) -> Result<(), GraphError> {
+        let (keys, ids) = dense_ids(self);
+        let n = keys.len();
+
+        // adjacência não direcionada: vizinho -> menor peso
+        let mut neighbours: Vec<HashMap<usize, W>> = vec![HashMap::new(); n + 1];
+        for e in self.all_edges() {
+            let (v, w) = (ids[e.origin_key_ref()], ids[e.destiny_key_ref()]);
+            if v == w {
+                continue;
+            }
+            for (a, b) in [(v, w), (w, v)] {
+                let weight = neighbours[a].entry(b).or_insert(e.weight());
+                if e.weight() < *weight {
+                    *weight = e.weight();
+                }
+            }
+        }
+
+        let m: usize = neighbours.iter().map(|adj| adj.len()).sum::<usize>() / 2;
+        let weighted = neighbours.iter().flat_map(|adj| adj.values()).any(|w| *w != W::one());
+        if weighted {
+            writeln!(out, "{} {} 1", n, m)?;
+        } else {
+            writeln!(out, "{} {}", n, m)?;
+        }
+        for adj in neighbours.iter().skip(1) {
+            let mut adj: Vec<(&usize, &W)> = adj.iter().collect();
+            adj.sort_by_key(|(w, _)| **w);
+            let line: Vec<String> = adj
+                .iter()
+                .map(|(w, weight)| {
+                    if weighted {
+                        format!("{} {}", w, weight)
+                    } else {
+                        w.to_string()
+                    }
+                })
+                .collect();
+            writeln!(out, "{}", line.join(" "))?;
+        }
+        Ok(())
+    }
+}
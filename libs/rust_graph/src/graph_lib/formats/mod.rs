@@ -0,0 +1,109 @@
+pub mod binary;
+pub mod csv;
+pub mod dimacs;
+pub mod dot;
+pub mod gml;
+pub mod graphml;
+pub mod metis;
+pub mod stream;
+
+use std::{collections::HashMap, str::FromStr};
+
+use crate::{
+    graph_lib::{
+        edge::Edge,
+        view::{Color, GraphPainter},
+    },
+    tools::weight::Weight,
+    DiGraph, GraphError,
+};
+
+/// Maior quantidade de vértices reservada antecipadamente a partir de um cabeçalho
+///
+/// Os cabeçalhos vêm de arquivos não confiáveis: acima disso o mapa de vértices cresce
+/// conforme os vértices chegam.
+pub(crate) const MAX_PRESIZE: usize = 1 << 16;
+
+/// Numera os vértices de `1` a `n` em ordem crescente de chave, como exigem
+/// os formatos que só aceitam vértices numerados (DIMACS, METIS)
+///
+/// Se as chaves já forem `1..=n` a numeração é a identidade.
+pub(crate) fn dense_ids<W: Weight>(graph: &DiGraph<i32, W>) -> (Vec<i32>, HashMap<i32, usize>) {
+    let mut keys = graph.get_vertice_key_array();
+    keys.sort();
+    let ids = keys.iter().enumerate().map(|(i, &k)| (k, i + 1)).collect();
+    (keys, ids)
+}
+
+/// Vértice lido de um formato com estilo (GraphML, GML)
+pub(crate) struct StyledNode {
+    pub id: String,
+    pub label: Option<String>,
+    pub color: Option<Color>,
+}
+
+/// Aresta lida de um formato com estilo (GraphML, GML), com a linha onde foi declarada
+pub(crate) struct StyledEdge {
+    pub line: usize,
+    pub source: String,
+    pub target: String,
+    pub weight: Option<String>,
+    pub label: Option<String>,
+    pub color: Option<Color>,
+}
+
+/// Monta o `DiGraph` e o `GraphPainter` a partir dos vértices e arestas lidos
+///
+/// Se todos os ids dos vértices forem inteiros eles viram as chaves; caso contrário os
+/// vértices são numerados a partir de 0 na ordem do arquivo e o id vira o label padrão.
+pub(crate) fn build_styled_graph<W: Weight + FromStr>(
+    nodes: Vec<StyledNode>,
+    edges: Vec<StyledEdge>,
+) -> Result<(DiGraph<i32, W>, GraphPainter), GraphError> {
+    let numeric = nodes.iter().all(|n| n.id.parse::<i32>().is_ok());
+    let mut keys: HashMap<String, i32> = HashMap::with_capacity(nodes.len());
+    for (i, node) in nodes.iter().enumerate() {
+        let key = if numeric { node.id.parse().unwrap() } else { i as i32 };
+        keys.insert(node.id.clone(), key);
+    }
+
+    let mut graph = DiGraph::new_sized(nodes.len() as u32);
+    for node in nodes.iter() {
+        graph.add_vertice(keys[&node.id]);
+    }
+    for edge in edges.iter() {
+        let key = |id: &String| keys.get(id).copied().ok_or_else(|| GraphError::MissingVertex(id.clone()));
+        let (v, w) = (key(&edge.source)?, key(&edge.target)?);
+        let weight = match &edge.weight {
+            Some(weight) => weight
+                .trim()
+                .parse::<W>()
+                .map_err(|_| GraphError::parse(edge.line, weight))?,
+            None => W::one(),
+        };
+        graph.add_edge(Edge::new_weighted(v, w, weight));
+    }
+
+    let mut painter = GraphPainter::from_digraph(&graph);
+    for node in nodes {
+        let key = keys[&node.id];
+        match node.label {
+            Some(label) => painter.update_vertice_label(key, label),
+            None if !numeric => painter.update_vertice_label(key, node.id),
+            None => {}
+        }
+        if let Some(color) = node.color {
+            painter.update_vertice_color(key, color);
+        }
+    }
+    for edge in edges {
+        let (v, w) = (keys[&edge.source], keys[&edge.target]);
+        if let Some(label) = edge.label {
+            painter.update_edge_label(v, w, label);
+        }
+        if let Some(color) = edge.color {
+            painter.update_edge_color(v, w, color);
+        }
+    }
+    Ok((graph, painter))
+}
@@ -0,0 +1,300 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader},
+    str::FromStr,
+};
+
+use scan_fmt::scan_fmt;
+
+use super::MAX_PRESIZE;
+use crate::{graph_lib::edge::Edge, tools::weight::Weight, DiGraph, GraphError};
+
+/// Quantidade de linhas entre dois relatórios de progresso no padrão de [`StreamOptions`]
+pub const DEFAULT_PROGRESS_INTERVAL: usize = 100_000;
+
+/// # StreamOptions
+/// Limites e frequência de progresso da leitura incremental de uma lista de arestas
+/// (ver [`DiGraph::from_edge_list_reader`])
+///
+/// O padrão lê o arquivo inteiro, sem limites.
+/// ```
+/// # use rust_graph::graph_lib::formats::stream::StreamOptions;
+/// // Amostra com no máximo 10 mil vértices
+/// let options = StreamOptions {
+///     max_vertices: Some(10_000),
+///     ..StreamOptions::default()
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StreamOptions {
+    /// Para a leitura antes de uma linha que criaria mais vértices que o limite
+    pub max_vertices: Option<usize>,
+    /// Para a leitura ao atingir essa quantidade de arestas
+    pub max_edges: Option<usize>,
+    /// A cada quantas linhas o progresso é reportado (0 reporta só ao final)
+    pub progress_interval: usize,
+}
+
+impl Default for StreamOptions {
+    fn default() -> Self {
+        StreamOptions {
+            max_vertices: None,
+            max_edges: None,
+            progress_interval: DEFAULT_PROGRESS_INTERVAL,
+        }
+    }
+}
+
+/// Estado da leitura, passado ao callback de progresso
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct StreamProgress {
+    /// Linhas lidas (inclusive comentários e linhas vazias)
+    pub lines: usize,
+    /// Bytes lidos (depois de descomprimir, se a entrada for gzip)
+    pub bytes: u64,
+    pub vertices: usize,
+    pub edges: usize,
+}
+
+/// Motivo do fim da leitura
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamStop {
+    /// A entrada foi lida até o fim
+    EndOfInput,
+    /// `StreamOptions::max_vertices` foi atingido
+    VertexLimit,
+    /// `StreamOptions::max_edges` foi atingido
+    EdgeLimit,
+}
+
+/// Resultado de uma leitura incremental
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StreamSummary {
+    pub progress: StreamProgress,
+    pub stop: StreamStop,
+}
+
+/// Grafo lido incrementalmente e o resumo da leitura
+pub type StreamedGraph<W> = (DiGraph<i32, W>, StreamSummary);
+
+/// Abre um arquivo para leitura, descomprimindo se começar com o cabeçalho gzip
+///
+/// Sem a feature `gzip` um arquivo comprimido é um erro de leitura.
+fn open_maybe_gzip(file_path: &str) -> Result<Box<dyn BufRead>, GraphError> {
+    let mut reader = BufReader::new(File::open(file_path)?);
+    let gzip = reader.fill_buf()?.starts_with(&[0x1f, 0x8b]);
+    if !gzip {
+        return Ok(Box::new(reader));
+    }
+    #[cfg(feature = "gzip")]
+    {
+        Ok(Box::new(BufReader::new(flate2::bufread::MultiGzDecoder::new(reader))))
+    }
+    #[cfg(not(feature = "gzip"))]
+    {
+        Err(GraphError::Io(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "gzip input requires the `gzip` feature",
+        )))
+    }
+}
+
+/// Lê a próxima linha com conteúdo, sem o comentário, atualizando `progress`
+///
+/// Retorna o número da linha (começando em 1), ou `None` no fim da entrada.
+fn next_line<R: BufRead>(
+    reader: &mut R,
+    buffer: &mut String,
+    progress: &mut StreamProgress,
+) -> io::Result<Option<usize>> {
+    loop {
+        buffer.clear();
+        let read = reader.read_line(buffer)?;
+        if read == 0 {
+            return Ok(None);
+        }
+        progress.lines += 1;
+        progress.bytes += read as u64;
+        let content = buffer.split('#').next().unwrap_or("").trim();
+        if !content.is_empty() {
+            let content = content.to_string();
+            buffer.clear();
+            buffer.push_str(&content);
+            return Ok(Some(progress.lines));
+        }
+    }
+}
+
+#[allow(unused)]
+impl<W: Weight + FromStr> DiGraph<i32, W> {
+    /// Cria um grafo lendo uma lista de arestas linha a linha de qualquer `BufRead`
+    ///
+    /// O formato é o de [`DiGraph::from_edge_list`]. O grafo é montado enquanto a entrada é
+    /// lida, sem carregá-la inteira na memória, e a leitura pode parar antes do fim ao
+    /// atingir os limites de `options`, o que permite amostrar arquivos enormes.
+    /// `progress` é chamado a cada `options.progress_interval` linhas e uma última vez
+    /// ao final.
+    ///
+    /// As verificações do cabeçalho (e a criação dos vértices isolados que ele declara)
+    /// só são feitas se a entrada for lida até o fim. A quantidade declarada não é confiável:
+    /// o grafo reserva no máximo 65536 vértices no início e cresce conforme eles chegam, e os
+    /// vértices isolados só são criados se as arestas baterem com o cabeçalho.
+    /// ```
+    /// # use rust_graph::{DiGraph, graph_lib::formats::stream::{StreamOptions, StreamStop}};
+    /// let content = "4 3\n0 1\n1 2\n2 3\n";
+    /// let options = StreamOptions {
+    ///     max_edges: Some(2),
+    ///     ..StreamOptions::default()
+    /// };
+    /// let mut reports = 0;
+    /// let (graph, summary): (DiGraph, _) =
+    ///     DiGraph::from_edge_list_reader(content.as_bytes(), &options, |_| reports += 1).unwrap();
+    /// assert_eq!(graph.edges_length(), 2);
+    /// assert_eq!(summary.stop, StreamStop::EdgeLimit);
+    /// assert_eq!(reports, 1);
+    ///
+    /// // O cabeçalho declara 2 bilhões de vértices, mas nada é reservado para eles
+    /// let huge = "2000000000 2\n0 1\n";
+    /// let result = DiGraph::<i32>::from_edge_list_reader(huge.as_bytes(), &StreamOptions::default(), |_| {});
+    /// assert!(result.is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// `GraphError::Io` se a leitura falhar, `GraphError::Parse` com a linha inválida (ou com o
+    /// cabeçalho, se ele declarar mais vértices do que cabem nas chaves `i32`) e
+    /// `GraphError::HeaderMismatch` se as quantidades do cabeçalho não baterem com o conteúdo.
+    /// Se não houver memória para os vértices isolados declarados o erro é `GraphError::Io`
+    /// com `ErrorKind::OutOfMemory`.
+    pub fn from_edge_list_reader<R: BufRead>(
+        mut reader: R,
+        options: &StreamOptions,
+        mut progress: impl FnMut(&StreamProgress),
+    ) -> Result<StreamedGraph<W>, GraphError> {
+        let mut state = StreamProgress::default();
+        let mut buffer = String::new();
+
+        let Some(header_line) = next_line(&mut reader, &mut buffer, &mut state)? else {
+            return Err(GraphError::parse(1, ""));
+        };
+        let (vert_num, edge_num) =
+            scan_fmt!(&buffer, "{} {}", usize, usize).map_err(|_| GraphError::parse(header_line, &buffer))?;
+        // As chaves dos vértices isolados vão até `1 + vert_num`, que precisa caber em um i32
+        let vert_count = i32::try_from(vert_num)
+            .ok()
+            .filter(|n| n.checked_add(1).is_some())
+            .ok_or_else(|| GraphError::parse(header_line, &buffer))?;
+
+        let capacity = vert_num
+            .min(options.max_vertices.unwrap_or(usize::MAX))
+            .min(MAX_PRESIZE);
+        let mut graph = DiGraph::new_sized(capacity as u32);
+        let vertex_room = |graph: &DiGraph<i32, W>, needed: usize| {
+            options
+                .max_vertices
+                .is_none_or(|max| graph.vertices_length() + needed <= max)
+        };
+
+        let mut next_report = options.progress_interval;
+        let mut stop = StreamStop::EndOfInput;
+        loop {
+            if options.max_edges.is_some_and(|max| graph.edges_length() >= max) {
+                stop = StreamStop::EdgeLimit;
+                break;
+            }
+            let Some(line_number) = next_line(&mut reader, &mut buffer, &mut state)? else {
+                break;
+            };
+            let line = buffer.as_str();
+            let parse_error = || GraphError::parse(line_number, line);
+            let key = |token: &str| token.parse::<i32>().map_err(|_| parse_error());
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            let (v, w, weight) = match tokens.as_slice() {
+                [v] => (key(v)?, None, None),
+                [v, w] => (key(v)?, Some(key(w)?), None),
+                [v, w, weight] => {
+                    let weight = weight.parse::<W>().map_err(|_| parse_error())?;
+                    (key(v)?, Some(key(w)?), Some(weight))
+                }
+                _ => return Err(parse_error()),
+            };
+
+            let mut needed = usize::from(!graph.vertice_exists(v));
+            if let Some(w) = w.filter(|&w| w != v) {
+                needed += usize::from(!graph.vertice_exists(w));
+            }
+            if !vertex_room(&graph, needed) {
+                stop = StreamStop::VertexLimit;
+                break;
+            }
+            match (w, weight) {
+                (None, _) => {
+                    graph.add_vertice(v);
+                }
+                (Some(w), None) => {
+                    graph.add_edge(Edge::new(v, w));
+                }
+                (Some(w), Some(weight)) => {
+                    graph.add_edge(Edge::new_weighted(v, w, weight));
+                }
+            }
+
+            if options.progress_interval > 0 && state.lines >= next_report {
+                next_report = state.lines + options.progress_interval;
+                state.vertices = graph.vertices_length();
+                state.edges = graph.edges_length();
+                progress(&state);
+            }
+        }
+
+        // Os vértices isolados só são criados se as arestas baterem com o cabeçalho, para que
+        // um cabeçalho inválido não aloque os vértices que ele declara antes do erro
+        if stop == StreamStop::EndOfInput && graph.edges_length() == edge_num {
+            let missing = vert_num.saturating_sub(graph.vertices_length());
+            let room = options
+                .max_vertices
+                .map_or(missing, |max| max.saturating_sub(graph.vertices_length()));
+            graph.try_reserve_vertices(missing.min(room))?;
+            let base = if graph.vertice_exists(0) { 0 } else { 1 };
+            for key in base..base + vert_count {
+                if graph.vertices_length() >= vert_num {
+                    break;
+                }
+                if !vertex_room(&graph, 1) {
+                    stop = StreamStop::VertexLimit;
+                    break;
+                }
+                graph.add_vertice(key);
+            }
+        }
+
+        state.vertices = graph.vertices_length();
+        state.edges = graph.edges_length();
+        progress(&state);
+
+        let found = (state.vertices, state.edges);
+        if stop == StreamStop::EndOfInput && found != (vert_num, edge_num) {
+            return Err(GraphError::HeaderMismatch {
+                declared: (vert_num, edge_num),
+                found,
+            });
+        }
+        Ok((graph, StreamSummary { progress: state, stop }))
+    }
+
+    /// Cria um grafo lendo um arquivo de lista de arestas incrementalmente
+    /// (ver [`DiGraph::from_edge_list_reader`])
+    ///
+    /// Arquivos comprimidos com gzip (reconhecidos pelo conteúdo, não pela extensão) são
+    /// descomprimidos durante a leitura se a feature `gzip` estiver ativa.
+    ///
+    /// # Errors
+    /// `GraphError::Io` se o arquivo não puder ser lido ou for gzip sem a feature `gzip`,
+    /// além dos erros de `from_edge_list_reader`.
+    pub fn from_file_with(
+        file_path: &str,
+        options: &StreamOptions,
+        progress: impl FnMut(&StreamProgress),
+    ) -> Result<StreamedGraph<W>, GraphError> {
+        DiGraph::from_edge_list_reader(open_maybe_gzip(file_path)?, options, progress)
+    }
+}
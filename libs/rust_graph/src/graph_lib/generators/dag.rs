@@ -0,0 +1,169 @@
+use rand::{distributions::uniform::SampleUniform, seq::SliceRandom, Rng};
+
+use super::{assert_probability, bernoulli_indices, pair_at, RandomBuilder, RandomGraphConfig};
+use crate::{tools::weight::Weight, DiGraph};
+
+/// Rede de fluxo e seu par `(s, t)`, pronta para `max_flux`
+pub type FlowNetwork<W> = (DiGraph<i32, W>, (i32, i32));
+
+/// # DagOptions
+/// Formato do DAG criado por [`DiGraph::from_random_dag`]
+/// ```
+/// # use rust_graph::graph_lib::generators::dag::DagOptions;
+/// // 4 camadas, chaves sem relação com a ordem topológica
+/// let options = DagOptions {
+///     shuffle_keys: true,
+///     ..DagOptions::layered(4, 0.3)
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DagOptions {
+    /// Probabilidade de cada arco candidato
+    pub edge_probability: f64,
+    /// `None`: qualquer par `i < j` da ordem topológica é candidato.
+    /// `Some(k)`: os vértices são divididos em `k` camadas consecutivas de tamanhos
+    /// parecidos e só há arcos de uma camada para a seguinte
+    pub layers: Option<usize>,
+    /// Se `false` a chave de cada vértice é sua posição na ordem topológica (todo arco
+    /// `v -> w` tem `v < w`); se `true` as chaves são embaralhadas
+    pub shuffle_keys: bool,
+}
+
+impl DagOptions {
+    pub fn topological(edge_probability: f64) -> DagOptions {
+        DagOptions {
+            edge_probability,
+            layers: None,
+            shuffle_keys: false,
+        }
+    }
+
+    pub fn layered(layers: usize, edge_probability: f64) -> DagOptions {
+        DagOptions {
+            edge_probability,
+            layers: Some(layers),
+            shuffle_keys: false,
+        }
+    }
+}
+
+#[allow(unused)]
+impl<W: Weight + SampleUniform> DiGraph<i32, W> {
+    /// Cria um DAG aleatório com `n` vértices
+    ///
+    /// Os arcos candidatos (ver [`DagOptions`]) existem independentemente com probabilidade
+    /// `options.edge_probability`. Laços e arestas paralelas nunca são gerados, qualquer que
+    /// seja `config`.
+    /// ```
+    /// # use rust_graph::{DiGraph, graph_lib::generators::{RandomGraphConfig, dag::DagOptions}};
+    /// let dag: DiGraph = DiGraph::from_random_dag(30, &DagOptions::topological(0.2), &RandomGraphConfig::new(3));
+    /// assert!(dag.all_edges().iter().all(|e| e.v_w().0 < e.v_w().1));
+    /// ```
+    ///
+    /// # Panics
+    /// Se a probabilidade não estiver em `[0, 1]`, se `options.layers` for `Some(0)` com
+    /// `n > 0` ou se o intervalo de pesos de `config` for vazio
+    pub fn from_random_dag(n: usize, options: &DagOptions, config: &RandomGraphConfig<W>) -> DiGraph<i32, W> {
+        assert_probability(options.edge_probability);
+        let config = RandomGraphConfig {
+            self_loops: false,
+            parallel_edges: false,
+            ..config.clone()
+        };
+        let mut builder = RandomBuilder::new(&config, n, true);
+        let p = options.edge_probability;
+
+        // Arcos entre posições da ordem topológica
+        let mut arcs = Vec::new();
+        match options.layers {
+            None => {
+                let len = n * n.saturating_sub(1) / 2;
+                bernoulli_indices(&mut builder.rng, len, p, |i| {
+                    let (later, earlier) = pair_at(i, n, false, false);
+                    arcs.push((earlier, later));
+                });
+            }
+            Some(layers) => {
+                assert!(layers > 0 || n == 0, "a layered DAG needs at least one layer");
+                let start = |layer: usize| layer * n / layers.max(1);
+                for layer in 1..layers {
+                    let (first, middle, last) = (start(layer - 1), start(layer), start(layer + 1));
+                    let width = last - middle;
+                    bernoulli_indices(&mut builder.rng, (middle - first) * width, p, |i| {
+                        arcs.push((first + i / width, middle + i % width));
+                    });
+                }
+            }
+        }
+
+        let mut keys: Vec<i32> = (0..n as i32).collect();
+        if options.shuffle_keys {
+            keys.shuffle(&mut builder.rng);
+        }
+        for (v, w) in arcs {
+            builder.add_edge(keys[v], keys[w]);
+        }
+        builder.into_digraph()
+    }
+
+    /// Cria uma rede de fluxo aleatória com `n` vértices, fonte `s = 0` e terminal `t = n - 1`
+    ///
+    /// Primeiro é criado um caminho de `s` a `t` passando por vértices internos sorteados,
+    /// com capacidades positivas; depois cada arco `v -> w` que não entra em `s` nem sai de
+    /// `t` é adicionado com probabilidade `edge_probability`. As capacidades são os pesos,
+    /// sorteados em `config.weights` (1 se não houver intervalo). Laços nunca são gerados.
+    /// ```
+    /// # use rust_graph::{DiGraph, graph_lib::{flux::ford_fulkerson::max_flux, generators::RandomGraphConfig}};
+    /// let config = RandomGraphConfig {
+    ///     weights: Some(0..=20),
+    ///     ..RandomGraphConfig::new(9)
+    /// };
+    /// let (network, (s, t)): (DiGraph, _) = DiGraph::from_random_flow_network(20, 0.2, &config);
+    /// let (flux, _) = max_flux(&network, s, t).unwrap();
+    /// assert!(flux.get_max_flux() > 0);
+    /// ```
+    ///
+    /// # Panics
+    /// Se `n < 2`, se `edge_probability` não estiver em `[0, 1]` ou se o intervalo de pesos
+    /// de `config` tiver valores negativos ou nenhum valor positivo
+    pub fn from_random_flow_network(n: usize, edge_probability: f64, config: &RandomGraphConfig<W>) -> FlowNetwork<W> {
+        assert!(n >= 2, "a flow network needs at least 2 vertices");
+        assert_probability(edge_probability);
+        if let Some(weights) = &config.weights {
+            assert!(
+                *weights.start() >= W::zero() && *weights.end() > W::zero(),
+                "capacities must be non-negative and not all zero"
+            );
+        }
+        let config = RandomGraphConfig {
+            self_loops: false,
+            ..config.clone()
+        };
+        let mut builder = RandomBuilder::new(&config, n, true);
+        let (s, t) = (0, n as i32 - 1);
+
+        // Caminho s -> ... -> t por uma quantidade sorteada de vértices internos
+        let mut inner: Vec<i32> = (1..t).collect();
+        inner.shuffle(&mut builder.rng);
+        let length = builder.rng.gen_range(0..=inner.len());
+        let path: Vec<i32> = std::iter::once(s)
+            .chain(inner[..length].iter().copied())
+            .chain(std::iter::once(t))
+            .collect();
+        for arc in path.windows(2) {
+            builder.add_positive_edge(arc[0], arc[1]);
+        }
+
+        let mut arcs = Vec::new();
+        bernoulli_indices(&mut builder.rng, n * (n - 1), edge_probability, |i| {
+            let (v, w) = pair_at(i, n, true, false);
+            arcs.push((v as i32, w as i32));
+        });
+        for (v, w) in arcs {
+            if v != t && w != s {
+                builder.add_edge(v, w);
+            }
+        }
+        (builder.into_digraph(), (s, t))
+    }
+}
@@ -0,0 +1,267 @@
+use std::cmp::Reverse;
+
+use rand::{distributions::uniform::SampleUniform, seq::SliceRandom};
+
+use super::{RandomBuilder, RandomGraphConfig};
+use crate::{graph_lib::edge::Edge, tools::weight::Weight, DiGraph, GraphError, UnGraph};
+
+fn not_graphical(reason: String) -> GraphError {
+    GraphError::NotGraphical(reason)
+}
+
+/// Arestas de um grafo simples com os graus `degrees`, pelo algoritmo de Havel–Hakimi
+///
+/// A cada passo o vértice de maior grau restante é ligado aos vértices seguintes de maior
+/// grau restante. Os vértices ficam em baldes por grau restante, então o custo é
+/// `O(n + m + n * grau máximo)`.
+fn havel_hakimi(degrees: &[usize]) -> Result<Vec<(i32, i32)>, GraphError> {
+    let n = degrees.len();
+    if let Some(v) = degrees.iter().position(|&d| d >= n) {
+        return Err(not_graphical(format!(
+            "vertex {v} has degree {} with only {n} vertices",
+            degrees[v]
+        )));
+    }
+    let sum: usize = degrees.iter().sum();
+    if sum % 2 == 1 {
+        return Err(not_graphical(format!("the degree sum {sum} is odd")));
+    }
+
+    let mut top = degrees.iter().copied().max().unwrap_or(0);
+    let mut buckets: Vec<Vec<usize>> = vec![Vec::new(); top + 1];
+    // Ordem decrescente de chave, para que `pop` comece pelas menores chaves
+    for v in (0..n).rev().filter(|&v| degrees[v] > 0) {
+        buckets[degrees[v]].push(v);
+    }
+
+    let mut edges = Vec::with_capacity(sum / 2);
+    let mut chosen = Vec::new();
+    loop {
+        while top > 0 && buckets[top].is_empty() {
+            top -= 1;
+        }
+        if top == 0 {
+            return Ok(edges);
+        }
+        let (v, d) = (buckets[top].pop().unwrap(), top);
+        chosen.clear();
+        for bucket in (1..=top).rev() {
+            while chosen.len() < d {
+                match buckets[bucket].pop() {
+                    Some(u) => chosen.push((u, bucket)),
+                    None => break,
+                }
+            }
+        }
+        if chosen.len() < d {
+            return Err(not_graphical(format!(
+                "vertex {v} needs {d} neighbours but only {} are left",
+                chosen.len()
+            )));
+        }
+        for &(u, bucket) in chosen.iter() {
+            edges.push((v as i32, u as i32));
+            if bucket > 1 {
+                buckets[bucket - 1].push(u);
+            }
+        }
+    }
+}
+
+fn check_directed_sums(out_degrees: &[usize], in_degrees: &[usize]) -> Result<(), GraphError> {
+    if out_degrees.len() != in_degrees.len() {
+        return Err(not_graphical(format!(
+            "{} out-degrees and {} in-degrees",
+            out_degrees.len(),
+            in_degrees.len()
+        )));
+    }
+    let (out_sum, in_sum) = (out_degrees.iter().sum::<usize>(), in_degrees.iter().sum::<usize>());
+    if out_sum != in_sum {
+        return Err(not_graphical(format!(
+            "out-degrees sum to {out_sum} and in-degrees sum to {in_sum}"
+        )));
+    }
+    Ok(())
+}
+
+/// Arcos de um grafo direcionado simples (sem laços) com os graus de saída e entrada dados,
+/// pelo algoritmo de Kleitman–Wang
+///
+/// Cada vértice, em ordem de chave, é ligado aos vértices de maior grau de entrada restante
+/// (empates pelo maior grau de saída restante e depois pela menor chave).
+fn kleitman_wang(out_degrees: &[usize], in_degrees: &[usize]) -> Result<Vec<(i32, i32)>, GraphError> {
+    check_directed_sums(out_degrees, in_degrees)?;
+    let n = out_degrees.len();
+    let mut out_left = out_degrees.to_vec();
+    let mut in_left = in_degrees.to_vec();
+    let mut edges = Vec::new();
+    for v in 0..n {
+        let d = out_left[v];
+        if d == 0 {
+            continue;
+        }
+        let mut candidates: Vec<usize> = (0..n).filter(|&u| u != v && in_left[u] > 0).collect();
+        if candidates.len() < d {
+            return Err(not_graphical(format!(
+                "vertex {v} needs {d} out-neighbours but only {} are left",
+                candidates.len()
+            )));
+        }
+        candidates.sort_by_key(|&u| (Reverse(in_left[u]), Reverse(out_left[u]), u));
+        for &u in candidates[..d].iter() {
+            edges.push((v as i32, u as i32));
+            in_left[u] -= 1;
+        }
+        out_left[v] = 0;
+    }
+    Ok(edges)
+}
+
+/// Se `degrees` é a sequência de graus de algum grafo simples não direcionado
+/// (teste de Havel–Hakimi)
+/// ```
+/// # use rust_graph::graph_lib::generators::degree::is_graphical;
+/// assert!(is_graphical(&[3, 3, 2, 2, 2]));
+/// assert!(!is_graphical(&[3, 3, 1, 1]));
+/// ```
+pub fn is_graphical(degrees: &[usize]) -> bool {
+    havel_hakimi(degrees).is_ok()
+}
+
+/// Se existe um grafo direcionado simples (sem laços nem arcos paralelos) em que o vértice
+/// `v` tem grau de saída `out_degrees[v]` e de entrada `in_degrees[v]` (teste de Kleitman–Wang)
+pub fn is_digraphical(out_degrees: &[usize], in_degrees: &[usize]) -> bool {
+    kleitman_wang(out_degrees, in_degrees).is_ok()
+}
+
+#[allow(unused)]
+impl<W: Weight> UnGraph<i32, W> {
+    /// Cria um grafo simples com vértices `0..n` em que o vértice `v` tem grau `degrees[v]`
+    ///
+    /// A construção (Havel–Hakimi) é determinística e as arestas têm peso 1.
+    /// ```
+    /// # use rust_graph::UnGraph;
+    /// let graph: UnGraph = UnGraph::from_degree_sequence(&[3, 3, 2, 2, 2]).unwrap();
+    /// assert_eq!(graph.degree(0), Some(3));
+    /// assert!(UnGraph::<i32, i32>::from_degree_sequence(&[3, 3, 1, 1]).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// `GraphError::NotGraphical` se nenhum grafo simples tiver esses graus.
+    pub fn from_degree_sequence(degrees: &[usize]) -> Result<UnGraph<i32, W>, GraphError> {
+        let edges = havel_hakimi(degrees)?;
+        let mut graph = UnGraph::new_sized(degrees.len() as u32);
+        for v in 0..degrees.len() {
+            graph.add_vertice(v as i32);
+        }
+        for (v, w) in edges {
+            graph.add_edge(Edge::new(v, w));
+        }
+        Ok(graph)
+    }
+}
+
+#[allow(unused)]
+impl<W: Weight> DiGraph<i32, W> {
+    /// Cria um grafo direcionado simples com vértices `0..n` em que o vértice `v` tem grau
+    /// de saída `out_degrees[v]` e de entrada `in_degrees[v]`
+    ///
+    /// A construção (Kleitman–Wang) é determinística e os arcos têm peso 1.
+    ///
+    /// # Errors
+    /// `GraphError::NotGraphical` se as sequências tiverem tamanhos ou somas diferentes,
+    /// ou se nenhum grafo direcionado simples tiver esses graus.
+    pub fn from_degree_sequences(out_degrees: &[usize], in_degrees: &[usize]) -> Result<DiGraph<i32, W>, GraphError> {
+        let edges = kleitman_wang(out_degrees, in_degrees)?;
+        let mut graph = DiGraph::new_sized(out_degrees.len() as u32);
+        for v in 0..out_degrees.len() {
+            graph.add_vertice(v as i32);
+        }
+        for (v, w) in edges {
+            graph.add_edge(Edge::new(v, w));
+        }
+        Ok(graph)
+    }
+}
+
+#[allow(unused)]
+impl<W: Weight + SampleUniform> UnGraph<i32, W> {
+    /// Modelo de configuração: cada vértice `v` recebe `degrees[v]` "pontas" e as pontas são
+    /// ligadas em pares sorteados
+    ///
+    /// Com `config.self_loops` e `config.parallel_edges` o resultado é um multigrafo com
+    /// exatamente esses graus (um laço usa duas pontas do vértice). Sem eles os laços e as
+    /// arestas repetidas são descartados (modelo de configuração "apagado"), e os graus podem
+    /// ficar menores.
+    ///
+    /// # Errors
+    /// `GraphError::NotGraphical` se a soma dos graus for ímpar.
+    ///
+    /// # Panics
+    /// Se o intervalo de pesos de `config` for vazio
+    pub fn from_configuration_model(
+        degrees: &[usize],
+        config: &RandomGraphConfig<W>,
+    ) -> Result<UnGraph<i32, W>, GraphError> {
+        let sum: usize = degrees.iter().sum();
+        if sum % 2 == 1 {
+            return Err(not_graphical(format!("the degree sum {sum} is odd")));
+        }
+        let mut builder = RandomBuilder::new(config, degrees.len(), false);
+        let mut stubs = stubs(degrees);
+        stubs.shuffle(&mut builder.rng);
+        for pair in stubs.chunks_exact(2) {
+            builder.add_edge(pair[0], pair[1]);
+        }
+        Ok(builder.into_ungraph())
+    }
+}
+
+#[allow(unused)]
+impl<W: Weight + SampleUniform> DiGraph<i32, W> {
+    /// Modelo de configuração direcionado: as pontas de saída (`out_degrees[v]` por vértice)
+    /// são ligadas a pontas de entrada sorteadas
+    ///
+    /// Laços e arcos repetidos seguem as políticas de `config` como em
+    /// [`UnGraph::from_configuration_model`].
+    /// ```
+    /// # use rust_graph::{DiGraph, graph_lib::generators::RandomGraphConfig};
+    /// let config = RandomGraphConfig {
+    ///     self_loops: true,
+    ///     parallel_edges: true,
+    ///     ..RandomGraphConfig::new(5)
+    /// };
+    /// let graph: DiGraph = DiGraph::from_configuration_model(&[2, 0, 1], &[1, 1, 1], &config).unwrap();
+    /// assert_eq!(graph.edges_of(0).unwrap().len(), 2);
+    /// ```
+    ///
+    /// # Errors
+    /// `GraphError::NotGraphical` se as sequências tiverem tamanhos ou somas diferentes.
+    ///
+    /// # Panics
+    /// Se o intervalo de pesos de `config` for vazio
+    pub fn from_configuration_model(
+        out_degrees: &[usize],
+        in_degrees: &[usize],
+        config: &RandomGraphConfig<W>,
+    ) -> Result<DiGraph<i32, W>, GraphError> {
+        check_directed_sums(out_degrees, in_degrees)?;
+        let mut builder = RandomBuilder::new(config, out_degrees.len(), true);
+        let mut in_stubs = stubs(in_degrees);
+        in_stubs.shuffle(&mut builder.rng);
+        for (v, w) in stubs(out_degrees).into_iter().zip(in_stubs) {
+            builder.add_edge(v, w);
+        }
+        Ok(builder.into_digraph())
+    }
+}
+
+/// Cada vértice `v` repetido `degrees[v]` vezes
+fn stubs(degrees: &[usize]) -> Vec<i32> {
+    degrees
+        .iter()
+        .enumerate()
+        .flat_map(|(v, &d)| std::iter::repeat_n(v as i32, d))
+        .collect()
+}
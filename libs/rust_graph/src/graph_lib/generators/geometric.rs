@@ -0,0 +1,288 @@
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+};
+
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::{
+    graph_lib::{attributes::VerticeMap, edge::Edge},
+    DiGraph, UnGraph,
+};
+
+/// # GeometricGraph
+/// Grafo geométrico junto com as coordenadas de cada vértice
+///
+/// As posições ficam com o grafo: [`GeometricGraph::heuristic`] dá a estimativa de A* e
+/// [`GraphPainter::from_geometric`](crate::graph_lib::view::GraphPainter::from_geometric)
+/// desenha os vértices nas suas coordenadas.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GeometricGraph<G> {
+    pub graph: G,
+    pub positions: VerticeMap<i32, Point>,
+}
+
+impl<G> GeometricGraph<G> {
+    /// Coordenadas do vértice, se ele existir
+    pub fn position(&self, vertice_key: i32) -> Option<Point> {
+        self.positions.get(&vertice_key).copied()
+    }
+
+    /// Heurística de A* para chegar em `target` (ver [`euclidean_heuristic`])
+    pub fn heuristic(&self, target: i32) -> impl Fn(i32) -> f64 + '_ {
+        euclidean_heuristic(&self.positions, target)
+    }
+}
+
+/// # Point
+/// Coordenadas de um vértice no plano
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Point {
+    pub fn new(x: f64, y: f64) -> Point {
+        Point { x, y }
+    }
+
+    /// Distância euclidiana até `other`
+    pub fn distance(&self, other: &Point) -> f64 {
+        (self.x - other.x).hypot(self.y - other.y)
+    }
+}
+
+/// Heurística de A* para chegar em `target`: a distância em linha reta até ele
+///
+/// É admissível quando os pesos não são menores que a distância entre os extremos, como
+/// nos grafos de [`UnGraph::from_random_geometric`]. Vértices sem coordenadas têm
+/// estimativa 0.
+pub fn euclidean_heuristic(positions: &VerticeMap<i32, Point>, target: i32) -> impl Fn(i32) -> f64 + '_ {
+    let target = positions.get(&target).copied();
+    move |v| match (positions.get(&v), target) {
+        (Some(p), Some(t)) => p.distance(&t),
+        _ => 0.0,
+    }
+}
+
+/// Como os pares de vértices são ligados em [`GeometricOptions`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GeometricConnection {
+    /// Pares a distância no máximo `r` (grafo de disco unitário quando `r = 1`)
+    Radius(f64),
+    /// Cada vértice se liga aos seus `k` vizinhos mais próximos (empates pela menor chave)
+    Nearest(usize),
+}
+
+/// # GeometricOptions
+/// Formato do grafo criado por [`UnGraph::from_random_geometric`] e
+/// [`DiGraph::from_random_geometric`]
+/// ```
+/// # use rust_graph::graph_lib::generators::geometric::GeometricOptions;
+/// // 6 vizinhos mais próximos em um retângulo 100 x 50
+/// let options = GeometricOptions {
+///     width: 100.0,
+///     height: 50.0,
+///     ..GeometricOptions::nearest(6)
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeometricOptions {
+    /// Os vértices são sorteados uniformemente em `[0, width) x [0, height)`
+    pub width: f64,
+    pub height: f64,
+    pub connection: GeometricConnection,
+}
+
+impl GeometricOptions {
+    /// Quadrado unitário, pares a distância no máximo `radius`
+    pub fn radius(radius: f64) -> GeometricOptions {
+        GeometricOptions {
+            width: 1.0,
+            height: 1.0,
+            connection: GeometricConnection::Radius(radius),
+        }
+    }
+
+    /// Quadrado unitário, `k` vizinhos mais próximos
+    pub fn nearest(k: usize) -> GeometricOptions {
+        GeometricOptions {
+            width: 1.0,
+            height: 1.0,
+            connection: GeometricConnection::Nearest(k),
+        }
+    }
+}
+
+fn random_points(n: usize, options: &GeometricOptions, seed: u64) -> Vec<Point> {
+    assert!(
+        options.width.is_finite() && options.width >= 0.0 && options.height.is_finite() && options.height >= 0.0,
+        "the box must have finite, non-negative sides"
+    );
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    (0..n)
+        .map(|_| {
+            let x = rng.gen::<f64>() * options.width;
+            Point::new(x, rng.gen::<f64>() * options.height)
+        })
+        .collect()
+}
+
+/// Pares `(v, w)` com `w < v` a distância no máximo `radius`, em ordem
+///
+/// Os pontos ficam em células de lado `radius`, então só as 9 células em volta de cada
+/// ponto são examinadas. Para raios muito pequenos o lado é no mínimo `extent / n`, o que
+/// limita os índices das células a `n`.
+fn radius_pairs(points: &[Point], radius: f64, extent: f64) -> Vec<(usize, usize)> {
+    assert!(
+        radius.is_finite() && radius >= 0.0,
+        "radius {radius} must be finite and non-negative"
+    );
+    let cell_side = radius.max(extent / points.len().max(1) as f64);
+    let cell_side = if cell_side > 0.0 { cell_side } else { 1.0 };
+    let cell = |p: &Point| ((p.x / cell_side).floor() as i64, (p.y / cell_side).floor() as i64);
+    let mut cells: HashMap<(i64, i64), Vec<usize>> = HashMap::new();
+    let mut pairs = Vec::new();
+    let mut near = Vec::new();
+    for (v, p) in points.iter().enumerate() {
+        let (row, col) = cell(p);
+        near.clear();
+        for d_row in -1..=1 {
+            for d_col in -1..=1 {
+                if let Some(bucket) = cells.get(&(row + d_row, col + d_col)) {
+                    near.extend(bucket.iter().copied().filter(|&w| p.distance(&points[w]) <= radius));
+                }
+            }
+        }
+        near.sort_unstable();
+        pairs.extend(near.iter().map(|&w| (v, w)));
+        cells.entry((row, col)).or_default().push(v);
+    }
+    pairs
+}
+
+/// Para cada vértice `v` em ordem, os pares `(v, w)` com os `k` vértices mais próximos de `v`
+/// (do mais próximo ao mais distante); custo `O(n² log n)`
+fn nearest_pairs(points: &[Point], k: usize) -> Vec<(usize, usize)> {
+    let mut pairs = Vec::new();
+    let mut others = Vec::new();
+    for (v, p) in points.iter().enumerate() {
+        others.clear();
+        others.extend((0..points.len()).filter(|&w| w != v));
+        let by_distance = |a: &usize, b: &usize| {
+            p.distance(&points[*a])
+                .partial_cmp(&p.distance(&points[*b]))
+                .unwrap_or(Ordering::Equal)
+                .then(a.cmp(b))
+        };
+        if k < others.len() {
+            others.select_nth_unstable_by(k, by_distance);
+            others.truncate(k);
+        }
+        others.sort_unstable_by(by_distance);
+        pairs.extend(others.iter().map(|&w| (v, w)));
+    }
+    pairs
+}
+
+fn positions(points: &[Point]) -> VerticeMap<i32, Point> {
+    let mut positions = VerticeMap::new();
+    for (v, p) in points.iter().enumerate() {
+        positions.insert(v as i32, *p);
+    }
+    positions
+}
+
+fn distance_edge(points: &[Point], v: usize, w: usize) -> Edge<i32, f64> {
+    Edge::new_weighted(v as i32, w as i32, points[v].distance(&points[w]))
+}
+
+#[allow(unused)]
+impl UnGraph<i32, f64> {
+    /// Cria um grafo geométrico aleatório com `n` vértices sorteados a partir de `seed`
+    ///
+    /// Com [`GeometricConnection::Radius`] os vértices a distância no máximo `r` são ligados;
+    /// com [`GeometricConnection::Nearest`] cada vértice é ligado aos seus `k` vizinhos mais
+    /// próximos (a aresta aparece uma vez só se a relação for mútua, então há vértices com
+    /// grau maior que `k`). O peso de cada aresta é a distância euclidiana entre os extremos,
+    /// e as coordenadas ficam no [`GeometricGraph`] retornado.
+    /// ```
+    /// # use rust_graph::{UnGraph, graph_lib::generators::geometric::GeometricOptions};
+    /// let geometric = UnGraph::from_random_geometric(200, &GeometricOptions::radius(0.1), 4);
+    /// for e in geometric.graph.all_edges() {
+    ///     let (v, w) = e.v_w();
+    ///     let distance = geometric.position(v).unwrap().distance(&geometric.position(w).unwrap());
+    ///     assert!(e.weight() <= 0.1 && e.weight() == distance);
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    /// Se os lados da caixa ou o raio forem negativos ou não finitos
+    pub fn from_random_geometric(n: usize, options: &GeometricOptions, seed: u64) -> GeometricGraph<UnGraph<i32, f64>> {
+        let points = random_points(n, options, seed);
+        let mut graph = UnGraph::new_sized(n as u32);
+        for v in 0..n {
+            graph.add_vertice(v as i32);
+        }
+        match options.connection {
+            GeometricConnection::Radius(radius) => {
+                for (v, w) in radius_pairs(&points, radius, options.width.max(options.height)) {
+                    graph.add_edge(distance_edge(&points, v, w));
+                }
+            }
+            GeometricConnection::Nearest(k) => {
+                let mut seen = HashSet::new();
+                for (v, w) in nearest_pairs(&points, k) {
+                    if seen.insert((v.min(w), v.max(w))) {
+                        graph.add_edge(distance_edge(&points, v, w));
+                    }
+                }
+            }
+        }
+        GeometricGraph {
+            graph,
+            positions: positions(&points),
+        }
+    }
+}
+
+#[allow(unused)]
+impl DiGraph<i32, f64> {
+    /// Versão direcionada de [`UnGraph::from_random_geometric`], com os mesmos pontos para a
+    /// mesma `seed`
+    ///
+    /// Com [`GeometricConnection::Radius`] cada par próximo vira os arcos `v -> w` e `w -> v`;
+    /// com [`GeometricConnection::Nearest`] só há o arco de cada vértice para cada um dos seus
+    /// `k` vizinhos mais próximos, então todo vértice tem grau de saída `min(k, n - 1)`.
+    ///
+    /// # Panics
+    /// Se os lados da caixa ou o raio forem negativos ou não finitos
+    pub fn from_random_geometric(n: usize, options: &GeometricOptions, seed: u64) -> GeometricGraph<DiGraph<i32, f64>> {
+        let points = random_points(n, options, seed);
+        let mut graph = DiGraph::new_sized(n as u32);
+        for v in 0..n {
+            graph.add_vertice(v as i32);
+        }
+        match options.connection {
+            GeometricConnection::Radius(radius) => {
+                for (v, w) in radius_pairs(&points, radius, options.width.max(options.height)) {
+                    graph.add_edge(distance_edge(&points, v, w));
+                    graph.add_edge(distance_edge(&points, w, v));
+                }
+            }
+            GeometricConnection::Nearest(k) => {
+                for (v, w) in nearest_pairs(&points, k) {
+                    graph.add_edge(distance_edge(&points, v, w));
+                }
+            }
+        }
+        GeometricGraph {
+            graph,
+            positions: positions(&points),
+        }
+    }
+}
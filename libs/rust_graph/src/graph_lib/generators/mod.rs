@@ -0,0 +1,241 @@
+pub mod dag;
+pub mod degree;
+pub mod geometric;
+pub mod models;
+pub mod named;
+pub mod uniform;
+
+use std::{collections::HashSet, ops::RangeInclusive};
+
+use rand::{distributions::uniform::SampleUniform, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::{graph_lib::edge::Edge, tools::weight::Weight, DiGraph, UnGraph};
+
+/// # RandomGraphConfig
+/// Configuração comum dos geradores de grafos aleatórios
+///
+/// A mesma configuração (inclusive a `seed`) gera sempre o mesmo grafo, com os mesmos
+/// ids de arestas, o que permite reproduzir um caso de teste que falhou.
+/// ```
+/// # use rust_graph::graph_lib::generators::RandomGraphConfig;
+/// let config = RandomGraphConfig {
+///     weights: Some(-10.0..=10.0),
+///     self_loops: true,
+///     ..RandomGraphConfig::new(42)
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RandomGraphConfig<W = i32> {
+    /// Semente do gerador pseudoaleatório (ChaCha8, estável entre plataformas)
+    pub seed: u64,
+    /// Intervalo dos pesos, sorteados uniformemente; `None` gera arestas com peso 1
+    pub weights: Option<RangeInclusive<W>>,
+    /// Se uma aresta pode ligar um vértice a ele mesmo
+    pub self_loops: bool,
+    /// Se pode haver mais de uma aresta `v -> w`
+    pub parallel_edges: bool,
+}
+
+impl<W> RandomGraphConfig<W> {
+    /// Configuração com a semente `seed`, sem pesos, sem laços e sem arestas paralelas
+    pub fn new(seed: u64) -> RandomGraphConfig<W> {
+        RandomGraphConfig {
+            seed,
+            weights: None,
+            self_loops: false,
+            parallel_edges: false,
+        }
+    }
+}
+
+pub(crate) fn assert_probability(p: f64) {
+    assert!((0.0..=1.0).contains(&p), "probability {p} is not in [0, 1]");
+}
+
+/// Quantidade de pares `(v, w)` distintos entre `n` vértices
+pub(crate) fn pair_count(n: usize, directed: bool, self_loops: bool) -> usize {
+    let loops = if self_loops { n } else { 0 };
+    if directed {
+        n * n.saturating_sub(1) + loops
+    } else {
+        n * n.saturating_sub(1) / 2 + loops
+    }
+}
+
+/// Par de índice `index` (em `0..pair_count(n, ..)`) na ordem linha a linha
+///
+/// Sem direção os pares são `(v, w)` com `w < v` (ou `w <= v` com laços).
+pub(crate) fn pair_at(index: usize, n: usize, directed: bool, self_loops: bool) -> (usize, usize) {
+    if directed {
+        if self_loops {
+            return (index / n, index % n);
+        }
+        let (v, column) = (index / (n - 1), index % (n - 1));
+        return (v, column + usize::from(column >= v));
+    }
+    // A linha `v` tem `v` colunas (ou `v + 1` com laços) e começa em `start(v)`
+    let start = |v: usize| {
+        if self_loops {
+            v * (v + 1) / 2
+        } else {
+            v * v.saturating_sub(1) / 2
+        }
+    };
+    let mut v = (((8 * index + 1) as f64).sqrt() as usize).div_ceil(2);
+    while v > 0 && start(v) > index {
+        v -= 1;
+    }
+    while start(v + 1) <= index {
+        v += 1;
+    }
+    (v, index - start(v))
+}
+
+/// Chama `visit` para cada índice de `0..len` sorteado independentemente com probabilidade `p`
+///
+/// Os saltos entre índices sorteados seguem a distribuição geométrica, então o custo é
+/// proporcional à quantidade sorteada e não a `len`.
+pub(crate) fn bernoulli_indices<R: Rng>(rng: &mut R, len: usize, p: f64, mut visit: impl FnMut(usize)) {
+    if p <= 0.0 {
+        return;
+    }
+    let log_q = (1.0 - p).ln();
+    let mut index = 0;
+    while index < len {
+        if p < 1.0 {
+            let skip = ((1.0 - rng.gen::<f64>()).ln() / log_q).floor();
+            if skip >= (len - index) as f64 {
+                return;
+            }
+            index += skip as usize;
+        }
+        visit(index);
+        index += 1;
+    }
+}
+
+/// Monta um grafo aleatório com vértices `0..n` respeitando as políticas da configuração
+///
+/// As arestas são guardadas na ordem em que foram sorteadas e só viram um `DiGraph` ou
+/// `UnGraph` no final, para que os ids sejam reprodutíveis.
+pub(crate) struct RandomBuilder<'c, W> {
+    pub rng: ChaCha8Rng,
+    config: &'c RandomGraphConfig<W>,
+    vertices_len: usize,
+    directed: bool,
+    edges: Vec<Edge<i32, W>>,
+    /// Pares já adicionados (`(v, w)`, ou `(max, min)` sem direção), se não houver arestas paralelas
+    seen: HashSet<(i32, i32)>,
+}
+
+impl<'c, W: Weight + SampleUniform> RandomBuilder<'c, W> {
+    /// # Panics
+    /// Se o intervalo de pesos da configuração for vazio
+    pub fn new(config: &'c RandomGraphConfig<W>, vertices_len: usize, directed: bool) -> RandomBuilder<'c, W> {
+        if let Some(weights) = &config.weights {
+            assert!(weights.start() <= weights.end(), "empty weight range");
+        }
+        RandomBuilder {
+            rng: ChaCha8Rng::seed_from_u64(config.seed),
+            config,
+            vertices_len,
+            directed,
+            edges: Vec::new(),
+            seen: HashSet::new(),
+        }
+    }
+
+    pub fn edges_length(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Quantidade de pares distintos permitidos pela política de laços
+    pub fn pair_count(&self) -> usize {
+        pair_count(self.vertices_len, self.directed, self.config.self_loops)
+    }
+
+    /// Maior quantidade de arestas possível com as políticas da configuração
+    /// (`usize::MAX` se forem permitidas arestas paralelas)
+    pub fn max_edges(&self) -> usize {
+        let simple = self.pair_count();
+        if self.config.parallel_edges && simple > 0 {
+            usize::MAX
+        } else {
+            simple
+        }
+    }
+
+    fn key(&self, v: i32, w: i32) -> (i32, i32) {
+        if self.directed {
+            (v, w)
+        } else {
+            (v.max(w), v.min(w))
+        }
+    }
+
+    /// Se a aresta `v -> w` (ou `{v, w}`) já foi adicionada
+    pub fn contains(&self, v: i32, w: i32) -> bool {
+        self.seen.contains(&self.key(v, w))
+    }
+
+    /// Se a aresta `v -> w` (ou `{v, w}`) pode ser adicionada
+    pub fn allows(&self, v: i32, w: i32) -> bool {
+        (self.config.self_loops || v != w) && (self.config.parallel_edges || !self.contains(v, w))
+    }
+
+    /// Adiciona `v -> w` (ou `{v, w}`) com um peso sorteado, se for permitida;
+    /// retorna se foi adicionada
+    pub fn add_edge(&mut self, v: i32, w: i32) -> bool {
+        self.add_edge_where(v, w, |_| true)
+    }
+
+    /// Como `add_edge`, mas sorteia o peso de novo até que seja positivo
+    ///
+    /// O intervalo de pesos deve ter algum valor positivo.
+    pub fn add_positive_edge(&mut self, v: i32, w: i32) -> bool {
+        self.add_edge_where(v, w, |weight| weight > W::zero())
+    }
+
+    fn add_edge_where(&mut self, v: i32, w: i32, accept: impl Fn(W) -> bool) -> bool {
+        if !self.allows(v, w) {
+            return false;
+        }
+        let edge = match &self.config.weights {
+            Some(weights) => loop {
+                let weight = self.rng.gen_range(weights.clone());
+                if accept(weight) {
+                    break Edge::new_weighted(v, w, weight);
+                }
+            },
+            None => Edge::new(v, w),
+        };
+        self.edges.push(edge);
+        if !self.config.parallel_edges {
+            self.seen.insert(self.key(v, w));
+        }
+        true
+    }
+
+    pub fn into_digraph(self) -> DiGraph<i32, W> {
+        let mut graph = DiGraph::new_sized(self.vertices_len as u32);
+        for v in 0..self.vertices_len {
+            graph.add_vertice(v as i32);
+        }
+        for edge in self.edges {
+            graph.add_edge(edge);
+        }
+        graph
+    }
+
+    pub fn into_ungraph(self) -> UnGraph<i32, W> {
+        let mut graph = UnGraph::new_sized(self.vertices_len as u32);
+        for v in 0..self.vertices_len {
+            graph.add_vertice(v as i32);
+        }
+        for edge in self.edges {
+            graph.add_edge(edge);
+        }
+        graph
+    }
+}
@@ -0,0 +1,313 @@
+use std::collections::HashSet;
+
+use rand::{distributions::uniform::SampleUniform, seq::index, Rng};
+
+use super::{assert_probability, bernoulli_indices, pair_at, RandomBuilder, RandomGraphConfig};
+use crate::{tools::weight::Weight, DiGraph, UnGraph};
+
+fn gnp<W: Weight + SampleUniform>(
+    n: usize,
+    p: f64,
+    directed: bool,
+    config: &RandomGraphConfig<W>,
+) -> RandomBuilder<'_, W> {
+    assert_probability(p);
+    let mut builder = RandomBuilder::new(config, n, directed);
+    let len = builder.pair_count();
+    let mut pairs = Vec::new();
+    bernoulli_indices(&mut builder.rng, len, p, |i| {
+        pairs.push(pair_at(i, n, directed, config.self_loops))
+    });
+    for (v, w) in pairs {
+        builder.add_edge(v as i32, w as i32);
+    }
+    builder
+}
+
+fn gnm<W: Weight + SampleUniform>(
+    n: usize,
+    m: usize,
+    directed: bool,
+    config: &RandomGraphConfig<W>,
+) -> RandomBuilder<'_, W> {
+    let mut builder = RandomBuilder::new(config, n, directed);
+    let len = builder.pair_count();
+    let m = m.min(builder.max_edges());
+    let indices = if config.parallel_edges {
+        (0..m).map(|_| builder.rng.gen_range(0..len)).collect()
+    } else {
+        index::sample(&mut builder.rng, len, m).into_vec()
+    };
+    for i in indices {
+        let (v, w) = pair_at(i, n, directed, config.self_loops);
+        builder.add_edge(v as i32, w as i32);
+    }
+    builder
+}
+
+fn barabasi_albert<W: Weight + SampleUniform>(
+    n: usize,
+    m: usize,
+    directed: bool,
+    config: &RandomGraphConfig<W>,
+) -> RandomBuilder<'_, W> {
+    assert!(m >= 1 && m < n, "Barabási–Albert needs 1 <= m < n (m = {m}, n = {n})");
+    let mut builder = RandomBuilder::new(config, n, directed);
+    // Cada vértice aparece uma vez para cada aresta que toca
+    let mut repeated: Vec<i32> = Vec::with_capacity(2 * m * n);
+    let mut targets: Vec<i32> = (0..m as i32).collect();
+    for source in m as i32..n as i32 {
+        for &target in targets.iter() {
+            builder.add_edge(source, target);
+        }
+        repeated.extend(targets.iter());
+        repeated.extend(std::iter::repeat_n(source, m));
+
+        let mut chosen = HashSet::with_capacity(m);
+        targets.clear();
+        while targets.len() < m {
+            let target = repeated[builder.rng.gen_range(0..repeated.len())];
+            if chosen.insert(target) {
+                targets.push(target);
+            }
+        }
+    }
+    builder
+}
+
+fn watts_strogatz<W: Weight + SampleUniform>(
+    n: usize,
+    k: usize,
+    beta: f64,
+    directed: bool,
+    config: &RandomGraphConfig<W>,
+) -> RandomBuilder<'_, W> {
+    assert!(k < n, "Watts–Strogatz needs k < n (k = {k}, n = {n})");
+    assert_probability(beta);
+    let mut builder = RandomBuilder::new(config, n, directed);
+    let key = |v: i32, w: i32| if directed { (v, w) } else { (v.max(w), v.min(w)) };
+
+    // Anel em que cada vértice se liga aos k / 2 vizinhos de cada lado
+    let n = n as i32;
+    let mut edges = Vec::new();
+    for j in 1..=(k / 2) as i32 {
+        for v in 0..n {
+            edges.push((v, (v + j) % n));
+            if directed {
+                edges.push((v, (v - j).rem_euclid(n)));
+            }
+        }
+    }
+    let mut present: HashSet<(i32, i32)> = edges.iter().map(|&(v, w)| key(v, w)).collect();
+    // Grau de saída (ou grau, sem direção): um vértice ligado a todos não pode religar
+    let mut degree = vec![k / 2 * 2; n as usize];
+
+    for edge in edges.iter_mut() {
+        let (v, u) = *edge;
+        if !builder.rng.gen_bool(beta) || degree[v as usize] >= n as usize - 1 {
+            continue;
+        }
+        let w = loop {
+            let w = builder.rng.gen_range(0..n);
+            if w != v && !present.contains(&key(v, w)) {
+                break w;
+            }
+        };
+        present.remove(&key(v, u));
+        present.insert(key(v, w));
+        if !directed {
+            degree[u as usize] -= 1;
+            degree[w as usize] += 1;
+        }
+        *edge = (v, w);
+    }
+
+    for (v, w) in edges {
+        builder.add_edge(v, w);
+    }
+    builder
+}
+
+fn stochastic_block<'c, W: Weight + SampleUniform>(
+    sizes: &[usize],
+    probabilities: &[Vec<f64>],
+    directed: bool,
+    config: &'c RandomGraphConfig<W>,
+) -> RandomBuilder<'c, W> {
+    assert!(
+        probabilities.len() == sizes.len() && probabilities.iter().all(|row| row.len() == sizes.len()),
+        "the probability matrix must be {0}x{0}",
+        sizes.len()
+    );
+    let starts: Vec<usize> = sizes
+        .iter()
+        .scan(0, |start, size| {
+            let block = *start;
+            *start += size;
+            Some(block)
+        })
+        .collect();
+    let n = sizes.iter().sum();
+    let mut builder = RandomBuilder::new(config, n, directed);
+
+    let mut pairs = Vec::new();
+    for a in 0..sizes.len() {
+        let first = if directed { 0 } else { a };
+        for b in first..sizes.len() {
+            let p = probabilities[a][b];
+            assert_probability(p);
+            let (size_a, size_b) = (sizes[a], sizes[b]);
+            let (start_a, start_b) = (starts[a], starts[b]);
+            if a == b {
+                let len = super::pair_count(size_a, directed, config.self_loops);
+                bernoulli_indices(&mut builder.rng, len, p, |i| {
+                    let (v, w) = pair_at(i, size_a, directed, config.self_loops);
+                    pairs.push((start_a + v, start_a + w));
+                });
+            } else {
+                bernoulli_indices(&mut builder.rng, size_a * size_b, p, |i| {
+                    pairs.push((start_a + i / size_b, start_b + i % size_b));
+                });
+            }
+        }
+    }
+    for (v, w) in pairs {
+        builder.add_edge(v as i32, w as i32);
+    }
+    builder
+}
+
+/// Modelos clássicos de grafos aleatórios
+///
+/// Os vértices são `0..n` e os pesos seguem `config.weights`. As políticas de laços e
+/// arestas paralelas de `config` valem para G(n, p), G(n, m) e o modelo de blocos; os
+/// modelos de Barabási–Albert e Watts–Strogatz nunca geram laços nem arestas paralelas.
+#[allow(unused)]
+impl<W: Weight + SampleUniform> DiGraph<i32, W> {
+    /// Erdős–Rényi G(n, p): cada arco `v -> w` existe independentemente com probabilidade `p`
+    /// ```
+    /// # use rust_graph::{DiGraph, graph_lib::generators::RandomGraphConfig};
+    /// let graph: DiGraph = DiGraph::from_gnp(100, 0.05, &RandomGraphConfig::new(1));
+    /// assert_eq!(graph.vertices_length(), 100);
+    /// ```
+    ///
+    /// # Panics
+    /// Se `p` não estiver em `[0, 1]` ou se o intervalo de pesos de `config` for vazio
+    pub fn from_gnp(n: usize, p: f64, config: &RandomGraphConfig<W>) -> DiGraph<i32, W> {
+        gnp(n, p, true, config).into_digraph()
+    }
+
+    /// Erdős–Rényi G(n, m): `m` arcos sorteados uniformemente entre os pares permitidos
+    ///
+    /// `m` é limitado à quantidade de pares se não houver arestas paralelas.
+    ///
+    /// # Panics
+    /// Se o intervalo de pesos de `config` for vazio
+    pub fn from_gnm(n: usize, m: usize, config: &RandomGraphConfig<W>) -> DiGraph<i32, W> {
+        gnm(n, m, true, config).into_digraph()
+    }
+
+    /// Barabási–Albert: cada vértice novo (a partir de `m`) cria `m` arcos para vértices
+    /// antigos distintos, escolhidos com probabilidade proporcional ao grau (entrada + saída)
+    ///
+    /// O primeiro vértice novo se liga aos vértices `0..m`.
+    ///
+    /// # Panics
+    /// Se não valer `1 <= m < n` ou se o intervalo de pesos de `config` for vazio
+    pub fn from_barabasi_albert(n: usize, m: usize, config: &RandomGraphConfig<W>) -> DiGraph<i32, W> {
+        barabasi_albert(n, m, true, config).into_digraph()
+    }
+
+    /// Watts–Strogatz: anel em que cada vértice tem arcos para os `k / 2` vizinhos de cada
+    /// lado, e o destino de cada arco é trocado por um vértice sorteado com probabilidade `beta`
+    /// ```
+    /// # use rust_graph::{DiGraph, graph_lib::generators::RandomGraphConfig};
+    /// let graph: DiGraph = DiGraph::from_watts_strogatz(20, 4, 0.1, &RandomGraphConfig::new(1));
+    /// assert_eq!(graph.edges_length(), 80);
+    /// ```
+    ///
+    /// # Panics
+    /// Se `k >= n`, se `beta` não estiver em `[0, 1]` ou se o intervalo de pesos de `config`
+    /// for vazio
+    pub fn from_watts_strogatz(n: usize, k: usize, beta: f64, config: &RandomGraphConfig<W>) -> DiGraph<i32, W> {
+        watts_strogatz(n, k, beta, true, config).into_digraph()
+    }
+
+    /// Modelo de blocos estocástico: os vértices são divididos em blocos consecutivos de
+    /// tamanhos `sizes` (o bloco `0` é `0..sizes[0]`, e assim por diante) e cada arco de um
+    /// vértice do bloco `a` para um do bloco `b` existe com probabilidade `probabilities[a][b]`
+    /// ```
+    /// # use rust_graph::{DiGraph, graph_lib::generators::RandomGraphConfig};
+    /// let probabilities = vec![vec![0.5, 0.01], vec![0.01, 0.5]];
+    /// let graph: DiGraph = DiGraph::from_stochastic_block(&[30, 20], &probabilities, &RandomGraphConfig::new(1));
+    /// assert_eq!(graph.vertices_length(), 50);
+    /// ```
+    ///
+    /// # Panics
+    /// Se `probabilities` não for uma matriz `sizes.len() x sizes.len()` de probabilidades
+    /// ou se o intervalo de pesos de `config` for vazio
+    pub fn from_stochastic_block(
+        sizes: &[usize],
+        probabilities: &[Vec<f64>],
+        config: &RandomGraphConfig<W>,
+    ) -> DiGraph<i32, W> {
+        stochastic_block(sizes, probabilities, true, config).into_digraph()
+    }
+}
+
+/// Versões não direcionadas dos modelos de `DiGraph` (ver [`DiGraph::from_gnp`] e seguintes)
+#[allow(unused)]
+impl<W: Weight + SampleUniform> UnGraph<i32, W> {
+    /// Erdős–Rényi G(n, p): cada aresta `{v, w}` existe independentemente com probabilidade `p`
+    ///
+    /// # Panics
+    /// Ver [`DiGraph::from_gnp`]
+    pub fn from_gnp(n: usize, p: f64, config: &RandomGraphConfig<W>) -> UnGraph<i32, W> {
+        gnp(n, p, false, config).into_ungraph()
+    }
+
+    /// Erdős–Rényi G(n, m): `m` arestas sorteadas uniformemente entre os pares permitidos
+    ///
+    /// # Panics
+    /// Ver [`DiGraph::from_gnm`]
+    pub fn from_gnm(n: usize, m: usize, config: &RandomGraphConfig<W>) -> UnGraph<i32, W> {
+        gnm(n, m, false, config).into_ungraph()
+    }
+
+    /// Barabási–Albert: cada vértice novo se liga a `m` vértices antigos distintos,
+    /// escolhidos com probabilidade proporcional ao grau
+    /// ```
+    /// # use rust_graph::{UnGraph, graph_lib::generators::RandomGraphConfig};
+    /// let graph: UnGraph = UnGraph::from_barabasi_albert(100, 3, &RandomGraphConfig::new(1));
+    /// assert_eq!(graph.edges_length(), 97 * 3);
+    /// ```
+    ///
+    /// # Panics
+    /// Ver [`DiGraph::from_barabasi_albert`]
+    pub fn from_barabasi_albert(n: usize, m: usize, config: &RandomGraphConfig<W>) -> UnGraph<i32, W> {
+        barabasi_albert(n, m, false, config).into_ungraph()
+    }
+
+    /// Watts–Strogatz: anel em que cada vértice se liga aos `k / 2` vizinhos de cada lado,
+    /// com cada aresta religada a um vértice sorteado com probabilidade `beta`
+    ///
+    /// # Panics
+    /// Ver [`DiGraph::from_watts_strogatz`]
+    pub fn from_watts_strogatz(n: usize, k: usize, beta: f64, config: &RandomGraphConfig<W>) -> UnGraph<i32, W> {
+        watts_strogatz(n, k, beta, false, config).into_ungraph()
+    }
+
+    /// Modelo de blocos estocástico não direcionado
+    ///
+    /// `probabilities` deve ser simétrica: só `probabilities[a][b]` com `a <= b` é lida.
+    ///
+    /// # Panics
+    /// Ver [`DiGraph::from_stochastic_block`]
+    pub fn from_stochastic_block(
+        sizes: &[usize],
+        probabilities: &[Vec<f64>],
+        config: &RandomGraphConfig<W>,
+    ) -> UnGraph<i32, W> {
+        stochastic_block(sizes, probabilities, false, config).into_ungraph()
+    }
+}
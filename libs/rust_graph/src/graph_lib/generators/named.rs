@@ -0,0 +1,315 @@
+use std::collections::HashSet;
+
+use crate::{graph_lib::edge::Edge, tools::weight::Weight, DiGraph, UnGraph};
+
+/// Vizinhança de cada célula em [`DiGraph::new_grid_with`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridNeighbourhood {
+    /// Direita, esquerda, cima e baixo
+    Four,
+    /// As quatro de `Four` mais as diagonais
+    Eight,
+}
+
+/// # GridOptions
+/// Formato da grade criada por [`DiGraph::new_grid_with`] e [`UnGraph::new_grid_with`]
+///
+/// O padrão é a grade de [`DiGraph::new_grid`]: 4 vizinhos, arcos só para a direita e
+/// para baixo e sem bordas ligadas.
+/// ```
+/// # use rust_graph::graph_lib::generators::named::{GridNeighbourhood, GridOptions};
+/// let torus = GridOptions {
+///     neighbourhood: GridNeighbourhood::Eight,
+///     torus: true,
+///     ..GridOptions::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GridOptions {
+    pub neighbourhood: GridNeighbourhood,
+    /// Se cada ligação vira dois arcos (ignorado em `UnGraph`)
+    pub bidirectional: bool,
+    /// Se a última linha se liga à primeira e a última coluna à primeira
+    pub torus: bool,
+}
+
+impl Default for GridOptions {
+    fn default() -> Self {
+        GridOptions {
+            neighbourhood: GridNeighbourhood::Four,
+            bidirectional: false,
+            torus: false,
+        }
+    }
+}
+
+fn complete_edges(n: i32) -> Vec<(i32, i32)> {
+    (0..n).flat_map(|v| (v + 1..n).map(move |w| (v, w))).collect()
+}
+
+fn path_edges(n: i32) -> Vec<(i32, i32)> {
+    (1..n).map(|v| (v - 1, v)).collect()
+}
+
+/// Ciclo com os vértices `first..first + n` (sem arestas se `n < 3`)
+fn cycle_edges(first: i32, n: i32) -> Vec<(i32, i32)> {
+    if n < 3 {
+        return Vec::new();
+    }
+    (0..n).map(|i| (first + i, first + (i + 1) % n)).collect()
+}
+
+/// Quantidade de vértices do hipercubo Q(d), cujas chaves precisam caber em um `i32`
+fn hypercube_len(d: u32) -> usize {
+    assert!(
+        d <= 30,
+        "hypercube dimension {d} is above 30, the keys would not fit in i32"
+    );
+    1 << d
+}
+
+fn hypercube_edges(d: u32) -> Vec<(i32, i32)> {
+    (0..hypercube_len(d) as i32)
+        .flat_map(|v| (0..d).map(move |bit| (v, v ^ (1 << bit))).filter(|(v, w)| v < w))
+        .collect()
+}
+
+/// Petersen generalizado GP(n, k): ciclo externo `0..n`, raios `i - (n + i)` e
+/// o polígono estrelado interno `n + i - n + (i + k) % n`
+fn generalized_petersen_edges(n: i32, k: i32) -> Vec<(i32, i32)> {
+    let mut edges = cycle_edges(0, n);
+    edges.extend((0..n).map(|i| (i, n + i)));
+    edges.extend((0..n).map(|i| (n + i, n + (i + k) % n)));
+    edges
+}
+
+/// Quantidade de células da grade, cujas chaves precisam caber em um `i32`
+fn grid_len(height: u32, width: u32) -> usize {
+    height
+        .checked_mul(width)
+        .filter(|&n| n <= i32::MAX as u32)
+        .unwrap_or_else(|| panic!("grid {height} x {width} has too many cells for i32 keys")) as usize
+}
+
+/// Ligações da grade na ordem das células, cada uma orientada "para a frente"
+/// (direita, baixo, diagonal direita e diagonal esquerda); ligações repetidas e
+/// laços de grades pequenas com `torus` são descartados
+fn grid_edges(height: u32, width: u32, options: &GridOptions) -> Vec<(i32, i32)> {
+    let (height, width) = (height as i64, width as i64);
+    let mut offsets = vec![(0, 1), (1, 0)];
+    if options.neighbourhood == GridNeighbourhood::Eight {
+        offsets.extend([(1, 1), (1, -1)]);
+    }
+    let mut seen = HashSet::new();
+    let mut edges = Vec::new();
+    for row in 0..height {
+        for col in 0..width {
+            for &(d_row, d_col) in offsets.iter() {
+                let (mut r, mut c) = (row + d_row, col + d_col);
+                if options.torus {
+                    (r, c) = (r.rem_euclid(height), c.rem_euclid(width));
+                } else if r >= height || c < 0 || c >= width {
+                    continue;
+                }
+                let (v, w) = ((row * width + col) as i32, (r * width + c) as i32);
+                if v != w && seen.insert((v.min(w), v.max(w))) {
+                    edges.push((v, w));
+                }
+            }
+        }
+    }
+    edges
+}
+
+fn symmetric_digraph<W: Weight>(vertices_len: usize, edges: Vec<(i32, i32)>) -> DiGraph<i32, W> {
+    let mut graph = DiGraph::new_sized(vertices_len as u32);
+    for v in 0..vertices_len {
+        graph.add_vertice(v as i32);
+    }
+    for (v, w) in edges {
+        graph.add_edge(Edge::new(v, w));
+        graph.add_edge(Edge::new(w, v));
+    }
+    graph
+}
+
+fn ungraph<W: Weight>(vertices_len: usize, edges: Vec<(i32, i32)>) -> UnGraph<i32, W> {
+    let mut graph = UnGraph::new_sized(vertices_len as u32);
+    for v in 0..vertices_len {
+        graph.add_vertice(v as i32);
+    }
+    for (v, w) in edges {
+        graph.add_edge(Edge::new(v, w));
+    }
+    graph
+}
+
+/// Grafos clássicos com vértices `0..n` e arestas de peso 1
+///
+/// Os mesmos construtores existem em `DiGraph`, onde cada aresta vira os arcos `v -> w`
+/// e `w -> v` (como em [`UnGraph::to_digraph`]).
+#[allow(unused)]
+impl<W: Weight> UnGraph<i32, W> {
+    /// Grafo completo K(n)
+    pub fn new_complete(n: usize) -> UnGraph<i32, W> {
+        ungraph(n, complete_edges(n as i32))
+    }
+
+    /// Caminho P(n): `0 - 1 - ... - (n - 1)`
+    pub fn new_path(n: usize) -> UnGraph<i32, W> {
+        ungraph(n, path_edges(n as i32))
+    }
+
+    /// Ciclo C(n): o caminho P(n) mais a aresta `(n - 1) - 0` (sem arestas se `n < 3`)
+    pub fn new_cycle(n: usize) -> UnGraph<i32, W> {
+        ungraph(n, cycle_edges(0, n as i32))
+    }
+
+    /// Estrela com centro `0` e as `leaves` folhas `1..=leaves`
+    pub fn new_star(leaves: usize) -> UnGraph<i32, W> {
+        ungraph(leaves + 1, (1..=leaves as i32).map(|v| (0, v)).collect())
+    }
+
+    /// Roda com centro `0` ligado a todos os vértices do ciclo `1..=rim`
+    ///
+    /// O ciclo só existe se `rim >= 3`.
+    pub fn new_wheel(rim: usize) -> UnGraph<i32, W> {
+        let mut edges: Vec<(i32, i32)> = (1..=rim as i32).map(|v| (0, v)).collect();
+        edges.extend(cycle_edges(1, rim as i32));
+        ungraph(rim + 1, edges)
+    }
+
+    /// Bipartido completo K(m, n): cada vértice de `0..m` ligado a cada vértice de `m..m + n`
+    pub fn new_complete_bipartite(m: usize, n: usize) -> UnGraph<i32, W> {
+        let (m_, n_) = (m as i32, n as i32);
+        ungraph(
+            m + n,
+            (0..m_).flat_map(|v| (m_..m_ + n_).map(move |w| (v, w))).collect(),
+        )
+    }
+
+    /// Hipercubo Q(d): `2^d` vértices, ligados quando suas chaves diferem em um único bit
+    ///
+    /// # Panics
+    /// Se `d > 30` (as chaves não caberiam em um `i32`)
+    pub fn new_hypercube(d: u32) -> UnGraph<i32, W> {
+        ungraph(hypercube_len(d), hypercube_edges(d))
+    }
+
+    /// Grafo de Petersen: ciclo externo `0..5`, raios `i - (i + 5)` e pentagrama interno
+    /// ```
+    /// # use rust_graph::UnGraph;
+    /// let petersen: UnGraph = UnGraph::new_petersen();
+    /// assert_eq!((petersen.vertices_length(), petersen.edges_length()), (10, 15));
+    /// assert!((0..10).all(|v| petersen.degree(v) == Some(3)));
+    /// ```
+    pub fn new_petersen() -> UnGraph<i32, W> {
+        ungraph(10, generalized_petersen_edges(5, 2))
+    }
+
+    /// Árvore binária completa com `n` vértices numerados como em um heap:
+    /// os filhos de `v` são `2v + 1` e `2v + 2`
+    pub fn new_binary_tree(n: usize) -> UnGraph<i32, W> {
+        ungraph(n, (1..n as i32).map(|v| ((v - 1) / 2, v)).collect())
+    }
+
+    /// Grafo de Möbius–Kantor, o Petersen generalizado GP(8, 3)
+    pub fn new_mobius_kantor() -> UnGraph<i32, W> {
+        ungraph(16, generalized_petersen_edges(8, 3))
+    }
+
+    /// Grade `height x width` com as células numeradas linha a linha (`row * width + col`)
+    ///
+    /// # Panics
+    /// Se `height * width` passar de `i32::MAX`
+    pub fn new_grid_with(height: u32, width: u32, options: &GridOptions) -> UnGraph<i32, W> {
+        ungraph(grid_len(height, width), grid_edges(height, width, options))
+    }
+}
+
+/// Grafos clássicos simétricos (ver os construtores de mesmo nome em `UnGraph`)
+#[allow(unused)]
+impl<W: Weight> DiGraph<i32, W> {
+    pub fn new_complete(n: usize) -> DiGraph<i32, W> {
+        symmetric_digraph(n, complete_edges(n as i32))
+    }
+
+    pub fn new_path(n: usize) -> DiGraph<i32, W> {
+        symmetric_digraph(n, path_edges(n as i32))
+    }
+
+    pub fn new_cycle(n: usize) -> DiGraph<i32, W> {
+        symmetric_digraph(n, cycle_edges(0, n as i32))
+    }
+
+    pub fn new_star(leaves: usize) -> DiGraph<i32, W> {
+        symmetric_digraph(leaves + 1, (1..=leaves as i32).map(|v| (0, v)).collect())
+    }
+
+    pub fn new_wheel(rim: usize) -> DiGraph<i32, W> {
+        let mut edges: Vec<(i32, i32)> = (1..=rim as i32).map(|v| (0, v)).collect();
+        edges.extend(cycle_edges(1, rim as i32));
+        symmetric_digraph(rim + 1, edges)
+    }
+
+    pub fn new_complete_bipartite(m: usize, n: usize) -> DiGraph<i32, W> {
+        let (m_, n_) = (m as i32, n as i32);
+        symmetric_digraph(
+            m + n,
+            (0..m_).flat_map(|v| (m_..m_ + n_).map(move |w| (v, w))).collect(),
+        )
+    }
+
+    /// # Panics
+    /// Se `d > 30` (as chaves não caberiam em um `i32`)
+    pub fn new_hypercube(d: u32) -> DiGraph<i32, W> {
+        symmetric_digraph(hypercube_len(d), hypercube_edges(d))
+    }
+
+    pub fn new_petersen() -> DiGraph<i32, W> {
+        symmetric_digraph(10, generalized_petersen_edges(5, 2))
+    }
+
+    pub fn new_binary_tree(n: usize) -> DiGraph<i32, W> {
+        symmetric_digraph(n, (1..n as i32).map(|v| ((v - 1) / 2, v)).collect())
+    }
+
+    pub fn new_mobius_kantor() -> DiGraph<i32, W> {
+        symmetric_digraph(16, generalized_petersen_edges(8, 3))
+    }
+
+    /// Grade `height x width` com as células numeradas linha a linha (`row * width + col`)
+    ///
+    /// Cada ligação é um arco para a direita, para baixo ou para as diagonais de baixo, e
+    /// com `options.bidirectional` também o arco contrário. Com `options.torus` as bordas
+    /// opostas são ligadas (ligações repetidas em grades com menos de 3 linhas ou colunas
+    /// aparecem uma vez só).
+    /// ```
+    /// # use rust_graph::{DiGraph, graph_lib::generators::named::{GridNeighbourhood, GridOptions}};
+    /// let options = GridOptions {
+    ///     neighbourhood: GridNeighbourhood::Eight,
+    ///     bidirectional: true,
+    ///     torus: true,
+    /// };
+    /// let grid: DiGraph = DiGraph::new_grid_with(4, 4, &options);
+    /// assert_eq!(grid.edges_length(), 16 * 8);
+    /// ```
+    ///
+    /// # Panics
+    /// Se `height * width` passar de `i32::MAX`
+    pub fn new_grid_with(height: u32, width: u32, options: &GridOptions) -> DiGraph<i32, W> {
+        let cells = grid_len(height, width);
+        let edges = grid_edges(height, width, options);
+        if options.bidirectional {
+            return symmetric_digraph(cells, edges);
+        }
+        let mut graph = DiGraph::new_sized(cells as u32);
+        for v in 0..cells as i32 {
+            graph.add_vertice(v);
+        }
+        for (v, w) in edges {
+            graph.add_edge(Edge::new(v, w));
+        }
+        graph
+    }
+}
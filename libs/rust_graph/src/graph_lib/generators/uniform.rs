@@ -0,0 +1,59 @@
+use rand::{distributions::uniform::SampleUniform, Rng};
+
+use super::{RandomBuilder, RandomGraphConfig};
+use crate::{tools::weight::Weight, DiGraph};
+
+/// Quantidade máxima de arestas por vértice quando a quantidade não é informada
+const MAX_EDGES_MULTIPLIER: usize = 20;
+
+#[allow(unused)]
+impl<W: Weight + SampleUniform> DiGraph<i32, W> {
+    /// Cria um grafo direcionado aleatório e reprodutível com vértices `0..vertices_len`
+    ///
+    /// Cada vértice `i > 0` recebe primeiro uma aresta para um vértice sorteado em `0..i`,
+    /// o que deixa o grafo fracamente conexo; as demais arestas têm extremos sorteados
+    /// uniformemente. `edges_len` é ajustado para ficar entre `vertices_len - 1` e o máximo
+    /// permitido pelas políticas de `config`; se for `None` a quantidade é sorteada.
+    /// ```
+    /// # use rust_graph::{DiGraph, graph_lib::generators::RandomGraphConfig};
+    /// let config = RandomGraphConfig {
+    ///     weights: Some(-5..=5),
+    ///     ..RandomGraphConfig::new(7)
+    /// };
+    /// let a: DiGraph = DiGraph::from_random_with(50, Some(200), &config);
+    /// let b: DiGraph = DiGraph::from_random_with(50, Some(200), &config);
+    /// assert_eq!(a.edges_length(), 200);
+    /// assert_eq!(a.to_edge_list(), b.to_edge_list());
+    /// ```
+    ///
+    /// # Panics
+    /// Se o intervalo de pesos de `config` for vazio
+    pub fn from_random_with(
+        vertices_len: usize,
+        edges_len: Option<usize>,
+        config: &RandomGraphConfig<W>,
+    ) -> DiGraph<i32, W> {
+        let mut builder = RandomBuilder::new(config, vertices_len, true);
+        if vertices_len == 0 {
+            return builder.into_digraph();
+        }
+        let edges_len = edges_len
+            .unwrap_or_else(|| builder.rng.gen_range(0..vertices_len * MAX_EDGES_MULTIPLIER))
+            .max(vertices_len - 1)
+            .min(builder.max_edges());
+
+        // Arestas que garantem a conectividade fraca
+        for i in 1..vertices_len as i32 {
+            let destiny = builder.rng.gen_range(0..i);
+            builder.add_edge(i, destiny);
+        }
+
+        let n = vertices_len as i32;
+        while builder.edges_length() < edges_len {
+            let origin = builder.rng.gen_range(0..n);
+            let destiny = builder.rng.gen_range(0..n);
+            builder.add_edge(origin, destiny);
+        }
+        builder.into_digraph()
+    }
+}
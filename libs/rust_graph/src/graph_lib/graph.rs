@@ -1,121 +1,89 @@
 use super::{
-    edge::Edge,
-    search::busca::{DeepFirstSearch, DfsStruct, EdgeClassification},
-    vertice::{self, Vertice},
+    csr::csr_graph::CsrGraph,
+    formats::stream::StreamOptions,
+    generators::{named::GridOptions, RandomGraphConfig},
+    edge::{Edge, EdgeId},
+    error::GraphError,
+    search::busca::{DeepFirstSearch, DfsStruct},
+    vertice::{Vertice, VerticeKey},
 };
-use core::panic;
-use rand::{random, Rng};
-use scan_fmt::scan_fmt;
+use crate::tools::weight::Weight;
+use rand::random;
 use std::{
     collections::{HashMap, HashSet},
-    fmt::Debug,
+    fmt::Display,
+    str::FromStr,
     fs,
-    io::ErrorKind,
-    rc::Rc,
-    sync::{atomic::AtomicI32, Arc, RwLock},
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 /// # DiGraph
 /// Grafo direcionado representado em lista de Adjacência
 ///
+/// `K`: tipo da chave dos vértices (qualquer `Hash + Eq + Clone`)
+///
+/// `W`: tipo numérico do peso das arestas
+///
 /// `vertices_len`: quantidade de vértices em um grafo
 ///
 /// `edges_len`: quantidade de arestas em um grafo
 ///
 /// `vertices`: HashMap para encontrar vértices usando sua key em O(1)
-pub struct DiGraph {
+///
+/// `edge_index`: extremos `(v, w)` de cada aresta, indexados pelo `EdgeId`
+/// (`None` para arestas removidas)
+pub struct DiGraph<K = i32, W = i32> {
     vertices_len: u32,
     edges_len: usize,
-    vertices: HashMap<i32, Vertice>, // thread safety
+    vertices: HashMap<K, Vertice<K, W>>,
+    edge_index: Vec<Option<(K, K)>>,
+}
+
+impl<K: VerticeKey, W: Weight> Default for DiGraph<K, W> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 #[allow(unused)]
-impl DiGraph {
+impl<K: VerticeKey, W: Weight> DiGraph<K, W> {
     /// Cria um novo grafo direcionado vazio.
-    pub fn new() -> DiGraph {
+    pub fn new() -> DiGraph<K, W> {
         DiGraph {
             vertices_len: 0,
             edges_len: 0,
             vertices: HashMap::new(),
+            edge_index: Vec::new(),
         }
     }
 
     /// Cria um novo grafo direcionado com uma capacidade inicial para os vértices.
-    pub fn new_sized(vertice_num: u32) -> DiGraph {
+    pub fn new_sized(vertice_num: u32) -> DiGraph<K, W> {
         DiGraph {
             vertices_len: 0,
             edges_len: 0,
             vertices: HashMap::with_capacity(vertice_num as usize),
+            edge_index: Vec::new(),
         }
     }
 
-    /// Cria um grafo direcionado a partir de um arquivo.
+    /// Reserva espaço para mais `additional` vértices sem abortar o processo se a memória
+    /// não for suficiente
     ///
-    /// O arquivo deve estar no formato:
-    /// ```
-    /// let v = 2;
-    /// println!("v: {v}");
-    /// 
-    /// ```
-    pub fn from_file(file_path: &str) -> Option<DiGraph> {
-        let file = fs::read_to_string(file_path);
-        let Ok(file_content) = file else {
-            let error = file.err().unwrap();
-            match error.kind() {
-                ErrorKind::PermissionDenied => println!("Acesso ao arquivo foi negado!"),
-                ErrorKind::NotFound => println!("Arquivo não encontrado!"),
-                _ => {
-                    println!("Um erro inesperado aconteceu!");
-                }
-            }
-            return None;
-        };
-
-        let mut lines = file_content.lines(); // Iterador do arquivo
-
-        let (vert_num, edge_num) = match scan_fmt!(lines.next().unwrap_or(""), "{} {}", u32, u32) {
-            Ok((v, e)) => (v, e),
-            Err(err) => {
-                eprintln!("Erro de leitura: {err}\nO arquivo pode não estar no formato requerido.");
-                return None;
-            }
-        };
-
-        let mut graph = DiGraph::new();
-
-        for (index, line) in lines.enumerate() {
-            let (orig, dest) = match scan_fmt!(line, "{} {}", i32, i32) {
-                Ok(tuple) => tuple,
-                Err(err) => {
-                    println!(
-                        "Erro {{{err}}} durante a leitura de aresta na linha {}\nconteúdo: {line}",
-                        index + 2
-                    );
-                    return None;
-                }
-            };
-            graph.add_edge(Edge::new(orig, dest));
-        }
-        Some(graph)
+    /// # Errors
+    /// `GraphError::Io` com `ErrorKind::OutOfMemory` se a reserva falhar.
+    pub(crate) fn try_reserve_vertices(&mut self, additional: usize) -> Result<(), GraphError> {
+        self.vertices.try_reserve(additional).map_err(|err| {
+            GraphError::Io(std::io::Error::new(std::io::ErrorKind::OutOfMemory, err.to_string()))
+        })
     }
 
     /// Cria um grafo direcionado a partir de um vetor de arestas ponderadas ou não.
-    pub fn from_edges(edge_array: Vec<Edge>) -> DiGraph {
+    pub fn from_edges(edge_array: Vec<Edge<K, W>>) -> DiGraph<K, W> {
         let mut graph = DiGraph::new();
-
-        edge_array.iter().for_each(|edge| {
-            let (v, w) = (edge.origin_key(), edge.destiny_key());
-            if !graph.vertice_exists(v) {
-                graph.add_vertice(v);
-            }
-            if !graph.vertice_exists(w) {
-                graph.add_vertice(w);
-            }
-            let vertice = graph.get_vertice_arc_mut(v).unwrap();
-            vertice.add_edge(edge.clone());
-            graph.edges_len += 1;
-        });
+        for edge in edge_array {
+            graph.add_edge(edge);
+        }
         graph
     }
 
@@ -131,24 +99,24 @@ impl DiGraph {
 
     /// Retorna um vetor com as chaves dos vértices.
     ///
-    /// `Vec<i32>` contendo as chaves dos vértices.
-    pub fn get_vertice_key_array(&self) -> Vec<i32> {
+    /// `Vec<K>` contendo as chaves dos vértices.
+    pub fn get_vertice_key_array(&self) -> Vec<K> {
         self.vertices.keys().cloned().collect()
     }
 
-    /// Retorna a referência do Arc<RwLock<Vertice>>, se existir.
-    pub fn get_vertice_arc(&self, vertice_key: i32) -> Option<&Vertice> {
+    /// Retorna a referência do vértice, se existir.
+    pub fn get_vertice_arc(&self, vertice_key: K) -> Option<&Vertice<K, W>> {
         self.vertices.get(&vertice_key)
     }
 
-    pub fn get_vertice_arc_mut(&mut self, vertice_key: i32) -> Option<&mut Vertice> {
+    pub fn get_vertice_arc_mut(&mut self, vertice_key: K) -> Option<&mut Vertice<K, W>> {
         self.vertices.get_mut(&vertice_key)
     }
 
     /// Retorna todas as arestas do grafo como um vetor.
-    pub fn all_edges(&self) -> Vec<Edge> {
+    pub fn all_edges(&self) -> Vec<Edge<K, W>> {
         let len: usize = self.edges_length();
-        let mut edges = Vec::with_capacity(len as usize);
+        let mut edges = Vec::with_capacity(len);
         for v in self.vertices.values() {
             let v_edges = v.edges_vec_ref();
             for e in v_edges {
@@ -158,17 +126,118 @@ impl DiGraph {
         edges
     }
 
-    pub fn remove_edge(&mut self, e: Edge) {
-        let mut vertice = self.get_vertice_arc_mut(e.origin_key()).unwrap();
-        vertice.remove_edge(e);
+    /// Remove uma aresta do grafo, identificada pelo `id`.
+    ///
+    /// A aresta é retirada da lista de sucessores da origem e da lista
+    /// de predecessores (`back_edges`) do destino.
+    ///
+    /// Retorna a aresta removida, ou `None` se ela não pertence ao grafo.
+    pub fn remove_edge(&mut self, e: &Edge<K, W>) -> Option<Edge<K, W>> {
+        let removed = self.get_vertice_arc_mut(e.origin_key())?.remove_edge(e)?;
+        if let Some(vertice_destino) = self.get_vertice_arc_mut(e.destiny_key()) {
+            vertice_destino.remove_back_edge_from(e.origin_key(), e.id());
+        }
+        self.edge_index[e.id().index()] = None;
+        self.edges_len -= 1;
+        Some(removed)
+    }
+
+    /// Remove a aresta de id `id`.
+    ///
+    /// Retorna a aresta removida, ou `None` se o id não pertence ao grafo.
+    pub fn remove_edge_by_id(&mut self, id: EdgeId) -> Option<Edge<K, W>> {
+        let edge = self.edge(id)?.clone();
+        self.remove_edge(&edge)
+    }
+
+    /// Retorna a aresta de id `id`, se existir.
+    pub fn edge(&self, id: EdgeId) -> Option<&Edge<K, W>> {
+        let (v, w) = self.edge_index.get(id.index())?.as_ref()?;
+        self.vertices
+            .get(v)?
+            .get_edges_to(w.clone())?
+            .iter()
+            .find(|e| e.id() == id)
+    }
+
+    /// Altera a aresta de id `id` com `f`, retornando o resultado de `f`, ou `None` se o id
+    /// não pertence ao grafo.
+    ///
+    /// `f` altera a aresta guardada na origem, que depois é copiada para `back_edges` do
+    /// destino, então sucessores e predecessores continuam vendo o mesmo peso e atributos.
+    /// ```
+    /// # use rust_graph::{DiGraph, graph_lib::edge::{Edge, EdgeAttribute}};
+    /// let mut graph: DiGraph = DiGraph::new();
+    /// let id = graph.add_edge(Edge::new_weighted(1, 2, 5));
+    /// graph.update_edge(id, |e| e.set_attribute(EdgeAttribute::Capacity, 8));
+    /// let back = graph.get_vertice_arc(2).unwrap().back_edges_hashmap();
+    /// assert_eq!(back[0].attribute(EdgeAttribute::Capacity), Some(8));
+    /// ```
+    pub fn update_edge<R>(&mut self, id: EdgeId, f: impl FnOnce(&mut Edge<K, W>) -> R) -> Option<R> {
+        let (v, w) = self.edge_index.get(id.index())?.clone()?;
+        let edge = self
+            .vertices
+            .get_mut(&v)?
+            .get_edges_to_mut(w.clone())?
+            .iter_mut()
+            .find(|e| e.id() == id)?;
+        let result = f(edge);
+        let updated = edge.clone();
+        if let Some(back) = self.vertices.get_mut(&w).and_then(|d| d.get_back_edge_mut(v, id)) {
+            *back = updated;
+        }
+        Some(result)
+    }
+
+    /// Troca o peso da aresta de id `id` (nas duas cópias, ver `update_edge`).
+    ///
+    /// Retorna o peso anterior, ou `None` se o id não pertence ao grafo.
+    pub fn set_edge_weight(&mut self, id: EdgeId, weight: W) -> Option<W> {
+        self.update_edge(id, |e| {
+            let old = e.weight();
+            e.set_weight(weight);
+            old
+        })
+    }
+
+    /// Remove um vértice e todas as arestas que saem ou chegam nele.
+    ///
+    /// Retorna as arestas removidas, ou `None` se o vértice não existe.
+    pub fn remove_vertice(&mut self, vertice_key: K) -> Option<Vec<Edge<K, W>>> {
+        let vertice = self.vertices.remove(&vertice_key)?;
+        self.vertices_len -= 1;
+
+        let (mut removed, back_edges) = vertice.get_all_edges_tuple();
+        for e in removed.iter() {
+            if let Some(vertice_destino) = self.vertices.get_mut(e.destiny_key_ref()) {
+                vertice_destino.remove_back_edge_from(vertice_key.clone(), e.id());
+            }
+        }
+        for e in back_edges {
+            // Laços já foram contados entre as arestas de saída
+            if *e.origin_key_ref() == vertice_key {
+                continue;
+            }
+            if let Some(vertice_origem) = self.vertices.get_mut(e.origin_key_ref()) {
+                vertice_origem.remove_edge_to(vertice_key.clone(), e.id());
+            }
+            removed.push(e);
+        }
+
+        for e in removed.iter() {
+            self.edge_index[e.id().index()] = None;
+        }
+        self.edges_len -= removed.len();
+        Some(removed)
     }
 
     /// Aplica uma função a todas as arestas do grafo.
     ///
-    /// `F`: Função que recebe uma referência mutável para `Edge`.
+    /// `F`: Função que recebe uma referência mutável para `Edge`. As cópias em `back_edges`
+    /// são atualizadas em seguida, como em `update_edge`.
     pub fn mut_edges<F>(&mut self, f: F)
     where
-        F: Fn(&mut Edge) -> (),
+        F: Fn(&mut Edge<K, W>),
     {
         for v in self.vertices.values_mut() {
             let mut_edges = v.edges_hashmap_mut();
@@ -178,48 +247,71 @@ impl DiGraph {
                 }
             }
         }
+        for edge in self.all_edges() {
+            let (v, w) = edge.v_w();
+            if let Some(back) = self.vertices.get_mut(&w).and_then(|d| d.get_back_edge_mut(v, edge.id())) {
+                *back = edge;
+            }
+        }
     }
 
     /// Verifica a existência de um vértice no grafo.
     ///
     /// `true` se existe, `false` caso contrário.
-    pub fn vertice_exists(&self, vert_key: i32) -> bool {
+    pub fn vertice_exists(&self, vert_key: K) -> bool {
         self.vertices.contains_key(&vert_key)
     }
 
     /// Adiciona um vértice ao grafo.
-    pub fn add_vertice(&mut self, vertice_key: i32) -> bool {
-        if self.vertice_exists(vertice_key) {
+    pub fn add_vertice(&mut self, vertice_key: K) -> bool {
+        if self.vertice_exists(vertice_key.clone()) {
             return false;
         }
-        let vertice = Vertice::new(vertice_key);
+        let vertice = Vertice::new(vertice_key.clone());
         self.vertices.insert(vertice_key, vertice);
         self.vertices_len += 1;
         true
     }
 
     /// Adiciona uma aresta ao grafo.
-    pub fn add_edge(&mut self, edge: Edge) {
+    ///
+    /// A aresta recebe o próximo `EdgeId` livre do grafo, que é retornado.
+    pub fn add_edge(&mut self, mut edge: Edge<K, W>) -> EdgeId {
+        let id = EdgeId::new(self.edge_index.len());
+        edge.set_id(id);
         let (v, w) = edge.v_w();
-        if !self.vertice_exists(v) {
-            self.add_vertice(v);
+        self.edge_index.push(Some((v.clone(), w.clone())));
+        if !self.vertice_exists(v.clone()) {
+            self.add_vertice(v.clone());
         }
-        if !self.vertice_exists(w) {
-            self.add_vertice(w);
+        if !self.vertice_exists(w.clone()) {
+            self.add_vertice(w.clone());
         }
 
-        // Obtém o Arc<RwLock<Vertice>> referente ao vértice de origem
         let vertice_origem = self.get_vertice_arc_mut(v).unwrap();
         vertice_origem.add_edge(edge.clone());
 
-        let mut vertice_destino = self.get_vertice_arc_mut(w).unwrap();
+        let vertice_destino = self.get_vertice_arc_mut(w).unwrap();
         vertice_destino.add_back_edge(edge);
 
         self.edges_len += 1;
+        id
+    }
+
+    /// Adiciona a aresta com o id `id`, deixando vagos os ids entre a última aresta e `id`
+    ///
+    /// Usado para restaurar grafos salvos sem mudar os ids. Retorna `None` se `id` não
+    /// for maior que todos os ids já distribuídos.
+    pub(crate) fn add_edge_at(&mut self, edge: Edge<K, W>, id: usize) -> Option<EdgeId> {
+        if id == EdgeId::UNASSIGNED.index() || id < self.edge_index.len() {
+            return None;
+        }
+        self.edge_index.resize(id, None);
+        Some(self.add_edge(edge))
     }
 
     /// Verifica se existe pelo menos uma aresta entre dois vértices.
-    pub fn has_edge(&self, origin_key: i32, destiny_key: i32) -> bool {
+    pub fn has_edge(&self, origin_key: K, destiny_key: K) -> bool {
         if let Some(vertice) = self.vertices.get(&origin_key) {
             return vertice.has_edge_to(destiny_key);
         }
@@ -229,31 +321,23 @@ impl DiGraph {
     /// Retorna todas as arestas entre dois vértices.
     ///
     /// Retorna `Some(Vec<Edge>)` se houver arestas, ou `None` caso contrário.
-    pub fn get_edges(&self, origin_key: i32, destiny_key: i32) -> Option<Vec<Edge>> {
+    pub fn get_edges(&self, origin_key: K, destiny_key: K) -> Option<Vec<Edge<K, W>>> {
         if let Some(vertice) = self.vertices.get(&origin_key) {
             return vertice.get_edges_to(destiny_key).cloned();
         }
         None
     }
 
-    pub fn unused_v_key_from(&self, origin: i32) -> i32 {
-        let mut key = origin;
-        while self.vertice_exists(key) {
-            key += 1;
-        }
-        key
-    }
-
     /// Retorna as chaves dos sucessores de um vértice.
-    pub fn get_sucessor(&self, vertice_key: i32) -> Option<Vec<i32>> {
-        let vertice = self.get_vertice_arc(vertice_key)?;
+    pub fn get_sucessor(&self, vertice_key: K) -> Option<Vec<K>> {
+        let vertice = self.get_vertice_arc(vertice_key.clone())?;
         Some(
             vertice
                 .edges_hashmap()
                 .keys()
-                .filter_map(|&(orig, dest)| {
-                    if orig == vertice_key {
-                        Some(dest)
+                .filter_map(|(orig, dest)| {
+                    if *orig == vertice_key {
+                        Some(dest.clone())
                     } else {
                         None
                     }
@@ -263,17 +347,17 @@ impl DiGraph {
     }
 
     /// Retorna um vetor clonado de todas as arestas de um vértice.
-    pub fn edges_of(&self, vertice_key: i32) -> Option<Vec<Edge>> {
+    pub fn edges_of(&self, vertice_key: K) -> Option<Vec<Edge<K, W>>> {
         let vertice = self.get_vertice_arc(vertice_key)?;
         Some(vertice.edges_vec())
     }
 
     /// Retorna as chaves dos predecessores de um vértice.
-    pub fn predecessor(&self, vertice_key: i32) -> Option<Vec<i32>> {
-        let mut list: Vec<i32> = Vec::new();
-        for (vert_key, vertice_ref) in &self.vertices {
+    pub fn predecessor(&self, vertice_key: K) -> Option<Vec<K>> {
+        let mut list: Vec<K> = Vec::new();
+        for vertice_ref in self.vertices.values() {
             for aresta in vertice_ref.edges_vec_ref() {
-                if aresta.destiny_key() == vertice_key {
+                if *aresta.destiny_key_ref() == vertice_key {
                     list.push(aresta.origin_key());
                 }
             }
@@ -282,11 +366,11 @@ impl DiGraph {
     }
 
     /// Retorna arestas predecessoras de um vertice
-    pub fn predecessor_edges(&self, vertice_key: i32) -> Option<Vec<Edge>> {
-        let mut list: Vec<Edge> = Vec::new();
-        for (vert_key, vertice_ref) in &self.vertices {
+    pub fn predecessor_edges(&self, vertice_key: K) -> Option<Vec<Edge<K, W>>> {
+        let mut list: Vec<Edge<K, W>> = Vec::new();
+        for vertice_ref in self.vertices.values() {
             for edge in vertice_ref.edges_vec_ref() {
-                if edge.destiny_key() == vertice_key {
+                if *edge.destiny_key_ref() == vertice_key {
                     list.push(edge.clone());
                 }
             }
@@ -295,27 +379,37 @@ impl DiGraph {
     }
 
     /// Executa uma busca em profundidade a partir de uma chave de vértice.
-    pub fn dfs_search(&self, mut search_key: i32) -> DfsStruct {
+    ///
+    /// Ao terminar a partir de `search_key`, recomeça em algum vértice ainda
+    /// não explorado até que todos tenham sido visitados.
+    pub fn dfs_search(&self, search_key: K) -> DfsStruct<K, W> {
         let mut dfs_data = DfsStruct::new(self);
         let key_array = self.get_vertice_key_array();
-        while search_key != -1 {
-            self.DeepFirstSearch(search_key, &mut dfs_data);
+        let mut search_key = Some(search_key);
+        while let Some(key) = search_key {
+            self.DeepFirstSearch(key, &mut dfs_data);
             search_key = dfs_data.get_unexplored_vertice(&key_array);
         }
         dfs_data
     }
 
+    /// Congela o grafo em um `CsrGraph` imutável, com vértices remapeados para índices densos.
+    ///
+    /// Útil para grafos grandes: os algoritmos de `CsrWorkspace` percorrem vetores
+    /// contíguos em vez de HashMaps.
+    pub fn freeze(&self) -> CsrGraph<K, W> {
+        CsrGraph::from_digraph(self)
+    }
+
     /// Cria um novo grafo com todas as arestas transpostas.
     ///
-    /// Na prática, apenas inverte as arestas direcionadas.
-    pub fn transpose(&self) -> DiGraph {
+    /// Na prática, apenas inverte as arestas direcionadas, mantendo seus pesos e atributos.
+    pub fn transpose(&self) -> DiGraph<K, W> {
         let mut t_graph = DiGraph::new_sized(self.vertices_len);
-        let vertices = self.get_vertice_key_array();
-        for vertice in vertices {
-            if let Some(edges) = self.edges_of(vertice) {
-                for edge in edges {
-                    t_graph.add_edge(Edge::new(edge.destiny_key(), edge.origin_key()));
-                }
+        for vertice in self.iter_vertices() {
+            t_graph.add_vertice(vertice.key());
+            for edge in vertice.edges_vec_ref() {
+                t_graph.add_edge(edge.reversed());
             }
         }
         t_graph
@@ -326,9 +420,9 @@ impl DiGraph {
     /// `from_key` -> chave do vértice de origem
     ///
     /// `destiny_key` -> chave do vértice de destino
-    pub fn reaches(&self, from_key: i32, destiny_key: i32) -> bool {
-        let mut stack: Vec<i32> = vec![from_key];
-        let mut visited: HashSet<i32> = HashSet::new();
+    pub fn reaches(&self, from_key: K, destiny_key: K) -> bool {
+        let mut stack: Vec<K> = vec![from_key];
+        let mut visited: HashSet<K> = HashSet::new();
 
         while let Some(vertice_key) = stack.pop() {
             if vertice_key == destiny_key {
@@ -336,7 +430,7 @@ impl DiGraph {
             }
 
             // Se já visitou este vértice, pule
-            if !visited.insert(vertice_key) {
+            if !visited.insert(vertice_key.clone()) {
                 continue;
             }
 
@@ -350,224 +444,259 @@ impl DiGraph {
     }
 
     /// Retorna o caminho entre dois vértices, se existir.
-    pub fn path_between(&self, v: i32, w: i32) -> Option<Vec<Edge>> {
-        let dfs_struct = self.dfs_search(v);
+    pub fn path_between(&self, v: K, w: K) -> Option<Vec<Edge<K, W>>> {
+        let dfs_struct = self.dfs_search(v.clone());
         let mut path = vec![];
 
         let mut current = w;
         while current != v {
             let father = dfs_struct.fathers.get(&current)?;
-            let vertice = self.get_vertice_arc(*father).unwrap();
+            let vertice = self.get_vertice_arc(father.clone()).unwrap();
 
             // Coletar todas as arestas para `current` e encontrar uma que parta de `father`
-            let edges_to_current = vertice.get_edges_to(current).unwrap();
+            let edges_to_current = vertice.get_edges_to(current.clone()).unwrap();
 
             let edge = edges_to_current
                 .iter()
-                .find(|e| e.destiny_key() == current)?;
+                .find(|e| *e.destiny_key_ref() == current)?;
 
             path.push(edge.clone());
-            current = *father;
+            current = father.clone();
         }
         path.reverse();
         Some(path)
     }
 }
 
-// Gerador aleatório de grafo
-impl DiGraph {
-    const MAX_EDGES_MULTIPLIER: u32 = 20;
+#[allow(unused)]
+impl<W: Weight + FromStr> DiGraph<i32, W> {
+    /// Cria um grafo direcionado a partir de um arquivo de lista de arestas.
+    ///
+    /// Ver [`DiGraph::from_edge_list`] para o formato. O arquivo é lido incrementalmente
+    /// (ver [`DiGraph::from_file_with`] para limites, progresso e arquivos gzip).
+    ///
+    /// # Errors
+    /// `GraphError::Io` se o arquivo não puder ser lido, além dos erros de `from_edge_list`.
+    pub fn from_file(file_path: &str) -> Result<DiGraph<i32, W>, GraphError> {
+        let (graph, _) = DiGraph::from_file_with(file_path, &StreamOptions::default(), |_| {})?;
+        Ok(graph)
+    }
 
-    //é const mas tem mutabilidade interna kakakak
-    const MAX_EDGE_WEIGHT: AtomicI32 = AtomicI32::new(40);
+    /// Cria um grafo direcionado a partir do conteúdo de uma lista de arestas.
+    ///
+    /// O formato é:
+    /// ```text
+    /// # comentários começam com '#' e linhas vazias são ignoradas
+    /// V E          <- quantidade de vértices e de arestas
+    /// 1 2          <- aresta 1 -> 2 com peso 1
+    /// 2 3 7        <- aresta 2 -> 3 com peso 7
+    /// 5            <- vértice 5, sem arestas
+    /// ```
+    ///
+    /// Vértices declarados no cabeçalho que não aparecem nas linhas são adicionados
+    /// isolados, numerados a partir de 0 se o vértice 0 aparece no arquivo, ou de 1 caso contrário.
+    ///
+    /// # Errors
+    /// `GraphError::Parse` com a linha inválida se o conteúdo não estiver no formato (ou se o
+    /// cabeçalho declarar mais vértices do que cabem nas chaves `i32`) e
+    /// `GraphError::HeaderMismatch` se as quantidades do cabeçalho não baterem com o conteúdo.
+    /// ```
+    /// # use rust_graph::DiGraph;
+    /// let graph: DiGraph = DiGraph::from_edge_list("4 2\n0 1\n1 2 5 # peso 5\n").unwrap();
+    /// assert_eq!(graph.vertices_length(), 4);
+    /// assert!(graph.vertice_exists(3));
+    /// // Cabeçalho com mais vértices do que cabem nas chaves
+    /// assert!(DiGraph::<i32>::from_edge_list("3000000000 1\n0 1\n").is_err());
+    /// ```
+    pub fn from_edge_list(content: &str) -> Result<DiGraph<i32, W>, GraphError> {
+        let (graph, _) = DiGraph::from_edge_list_reader(content.as_bytes(), &StreamOptions::default(), |_| {})?;
+        Ok(graph)
+    }
+}
+
+#[allow(unused)]
+impl<W: Weight> DiGraph<i32, W> {
+
+    pub fn unused_v_key_from(&self, origin: i32) -> i32 {
+        let mut key = origin;
+        while self.vertice_exists(key) {
+            key += 1;
+        }
+        key
+    }
 
+    /// Grade `height x width` com arcos para a direita e para baixo
+    ///
+    /// Ver [`DiGraph::new_grid_with`] para 8 vizinhos, arcos nos dois sentidos e toro.
+    pub fn new_grid(height: u32, width: u32) -> DiGraph<i32, W> {
+        DiGraph::new_grid_with(height, width, &GridOptions::default())
+    }
+}
+
+// Gerador aleatório de grafo
+impl DiGraph {
     /// Cria um grafo direcionado aleatório.
     ///
     /// `vertices_len`: número de vértices
     ///
     /// `edges_len`: número de arestas (opcional)
     ///
-    /// `weighted`: se as arestas devem ter pesos
+    /// `weighted`: se as arestas devem ter pesos (entre -39 e 39, ou 0 e 39)
     ///
     /// `negative_weight`: se os pesos podem ser negativos
     ///
+    /// A semente é sorteada a cada chamada; para um grafo reprodutível use
+    /// [`DiGraph::from_random_with`].
     pub fn from_random(
         vertices_len: u32,
         edges_len: Option<u32>,
         weighted: bool,
         negative_weight: bool,
     ) -> DiGraph {
-        let min_edges = vertices_len.saturating_sub(1);
-        let mut rng = rand::thread_rng();
-        let random_edges_len = rng.gen_range(0..vertices_len * Self::MAX_EDGES_MULTIPLIER);
-        let mut edges_len = edges_len.unwrap_or(random_edges_len).max(min_edges);
-        let mut edges_added = HashSet::<(i32, i32)>::new();
-        let max_edge_weight = Self::MAX_EDGE_WEIGHT.load(std::sync::atomic::Ordering::Relaxed);
-        let max_edges: u64 = vertices_len as u64 * ((vertices_len as u64) - 1);
-        edges_len = edges_len.min(max_edges as u32);
-
-        let mut graph = DiGraph::new_sized(vertices_len);
-        for i in 0..vertices_len {
-            graph.add_vertice(i as i32);
-        }
-
-        // Adiciona arestas para garantir conectividade mínima
-        for i in 1..vertices_len {
-            let i_key = i as i32;
-            let dest_key: i32 = rng.gen_range(0..i_key);
-
-            let weight = if weighted {
-                let mut w = random::<i32>() % max_edge_weight;
-                if !negative_weight && w < 0 {
-                    w = -w;
-                }
-                Some(w)
-            } else {
-                None
-            };
-
-            if let Some(w) = weight {
-                graph.add_edge(Edge::new_weighted(i_key, dest_key, w));
-            } else {
-                graph.add_edge(Edge::new(i_key, dest_key));
-            }
-            edges_added.insert((i_key, dest_key));
-        }
-
-        let mut count = vertices_len.saturating_sub(1);
-        while count < edges_len {
-            let origin = rng.gen_range(0..vertices_len) as i32;
-            let destiny = rng.gen_range(0..vertices_len) as i32;
-            if origin == destiny || edges_added.contains(&(origin, destiny)) {
-                continue;
-            }
-
-            let weight = if weighted {
-                let mut w = random::<i32>() % max_edge_weight;
-                if !negative_weight && w < 0 {
-                    w = -w;
-                }
-                Some(w)
-            } else {
-                None
-            };
-
-            if let Some(w) = weight {
-                graph.add_edge(Edge::new_weighted(origin, destiny, w));
-            } else {
-                graph.add_edge(Edge::new(origin, destiny));
-            }
-            edges_added.insert((origin, destiny));
-            count += 1;
-        }
-
-        println!(
-            "Grafo gerado com {} vértices e {} arestas",
-            vertices_len, edges_len
-        );
-        graph
-    }
-
-    /// Define o peso máximo das arestas para o gerador aleatório.
-    pub fn set_edge_max(coeficient: i32) {
-        Self::MAX_EDGE_WEIGHT.store(coeficient, std::sync::atomic::Ordering::Relaxed);
-    }
-
-    pub fn new_grid(height: u32, width: u32) -> DiGraph {
-        let mut digraph = DiGraph::new_sized((height * width));
-
-        for row in 0..height {
-            for col in 0..width {
-                let v = row * width + col;
-
-                // Conectar com o vizinho da direita
-                if col + 1 < width {
-                    let right_neighbor = v + 1;
-                    digraph.add_edge(Edge::new(v as i32, right_neighbor as i32));
-                }
-
-                // Conectar com o vizinho de baixo
-                if row + 1 < height {
-                    let bottom_neighbor = v + width;
-                    digraph.add_edge(Edge::new(v as i32, bottom_neighbor as i32));
-                }
-            }
-        }
-
-        digraph
+        let min_weight = if negative_weight { -39 } else { 0 };
+        let config = RandomGraphConfig {
+            weights: weighted.then_some(min_weight..=39),
+            ..RandomGraphConfig::new(random())
+        };
+        DiGraph::from_random_with(vertices_len as usize, edges_len.map(|e| e as usize), &config)
     }
 }
 
 // Iteradores
-impl DiGraph {
+impl<K: VerticeKey, W: Weight> DiGraph<K, W> {
     /// Retorna um iterador sobre os vértices do grafo.
-    pub fn iter_vertices(&self) -> impl Iterator<Item = &Vertice> {
+    pub fn iter_vertices(&self) -> impl Iterator<Item = &Vertice<K, W>> {
         self.vertices.values()
     }
 }
 
-impl Clone for DiGraph {
-    fn clone(&self) -> Self {
-        Self {
-            vertices_len: self.vertices_len.clone(),
-            edges_len: self.edges_len.clone(),
-            vertices: self.vertices.clone(),
-        }
-    }
-}
-
-impl DiGraph {
+impl<K: VerticeKey, W: Weight> DiGraph<K, W> {
     /// Encontra um par de vértices (base, antibase) tal que:
     /// - `base` não tem predecessores.
     /// - `base` alcança `antibase`.
     /// - `antibase` não tem sucessores.
-    pub fn find_base_antibase(&self) -> Option<(i32, i32)> {
+    pub fn find_base_antibase(&self) -> Option<(K, K)> {
         // 1. Encontra a base: um vértice sem predecessores
         let base = self
             .get_vertice_key_array()
             .into_iter()
-            .find(|&v| self.predecessor(v).map_or(true, |p| p.is_empty()))?;
+            .find(|v| self.predecessor(v.clone()).is_none_or(|p| p.is_empty()))?;
 
         // 2. Encontra a antibase: alcançável pela base e sem sucessores
-        let antibase = self.get_vertice_key_array().into_iter().find(|&v| {
-            v != base // Diferente da base
-                    && self.reaches(base, v) // A base alcança esse vértice
-                    && self.get_sucessor(v).map_or(true, |s| s.is_empty()) // Sem sucessores
+        let antibase = self.get_vertice_key_array().into_iter().find(|v| {
+            *v != base // Diferente da base
+                    && self.reaches(base.clone(), v.clone()) // A base alcança esse vértice
+                    && self.get_sucessor(v.clone()).is_none_or(|s| s.is_empty()) // Sem sucessores
         })?;
         Some((base, antibase))
     }
 }
 
 // to csv
-impl DiGraph{
-    pub fn to_csv(&self)  {
-        self.vertices_to_csv("vertices.csv");
-        self.edges_to_csv("edges.csv");
+impl<K: VerticeKey + Display, W: Weight> DiGraph<K, W> {
+    /// Escreve o grafo no formato de lista de arestas lido por [`DiGraph::from_file`].
+    ///
+    /// Os pesos só são escritos se alguma aresta tiver peso diferente de 1, e os
+    /// vértices isolados são escritos sozinhos na linha.
+    pub fn to_edge_list(&self) -> String {
+        let weighted = self.all_edges().iter().any(|e| e.weight() != W::one());
+        let mut content = format!("{} {}\n", self.vertices_length(), self.edges_length());
+
+        let mut edges = self.all_edges();
+        edges.sort_by_key(|e| e.id());
+        for e in edges {
+            let (v, w) = e.v_w();
+            if weighted {
+                content.push_str(&format!("{} {} {}\n", v, w, e.weight()));
+            } else {
+                content.push_str(&format!("{} {}\n", v, w));
+            }
+        }
+        for vertice in self.iter_vertices() {
+            if vertice.edges_vec_ref().is_empty() && vertice.back_edges_hashmap().is_empty() {
+                content.push_str(&format!("{}\n", vertice.key()));
+            }
+        }
+        content
+    }
+
+    /// Salva o grafo em um arquivo de lista de arestas (ver [`DiGraph::to_edge_list`]).
+    pub fn to_file(&self, file_path: &str) -> Result<(), GraphError> {
+        fs::write(file_path, self.to_edge_list())?;
+        Ok(())
+    }
+
+    pub fn to_csv(&self) -> Result<(), GraphError> {
+        self.vertices_to_csv("vertices.csv")?;
+        self.edges_to_csv("edges.csv")
     }
 
-    pub fn vertices_to_csv(&self, file_path: &str) {
+    pub fn vertices_to_csv(&self, file_path: &str) -> Result<(), GraphError> {
         let mut csv = String::new();
         csv.push_str("id,label\n");
         for vertice in self.iter_vertices() {
             let vertice_key = vertice.key();
-            let vertice_str = format!("{},{}\n", vertice_key,vertice_key);
+            let vertice_str = format!("{},{}\n", vertice_key, vertice_key);
             csv.push_str(&vertice_str);
         }
-        fs::write(file_path, csv).expect("Erro ao escrever arquivo");
+        fs::write(file_path, csv)?;
+        Ok(())
     }
 
-    pub fn edges_to_csv(&self, file_path: &str) {
+    pub fn edges_to_csv(&self, file_path: &str) -> Result<(), GraphError> {
         let mut csv = String::new();
         csv.push_str("source,target,weight\n");
         for vertice in self.iter_vertices() {
-
-            let vertice_key = vertice.key();
             for edge in vertice.edges_vec_ref() {
-                let (v,w) = edge.v_w();
+                let (v, w) = edge.v_w();
                 let edge_str = format!("{},{},{}\n", v, w, edge.weight());
                 csv.push_str(&edge_str);
             }
         }
-        fs::write(file_path, csv).expect("Erro ao escrever arquivo");
+        fs::write(file_path, csv)?;
+        Ok(())
+    }
+}
+
+/// Forma serializada de um grafo: as chaves dos vértices e as arestas em ordem de `EdgeId`
+///
+/// As arestas guardam seus ids, e os ids de arestas removidas ficam vagos ao desserializar,
+/// para que mapas indexados por `EdgeId` (`EdgeMap`, `FluxMap`) continuem valendo.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub(crate) struct GraphRepr<K, W> {
+    pub vertices: Vec<K>,
+    pub edges: Vec<Edge<K, W>>,
+}
+
+#[cfg(feature = "serde")]
+impl<K: VerticeKey + serde::Serialize, W: Weight + serde::Serialize> serde::Serialize for DiGraph<K, W> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut edges = self.all_edges();
+        edges.sort_by_key(|e| e.id());
+        let vertices = self.get_vertice_key_array();
+        serde::Serialize::serialize(&GraphRepr { vertices, edges }, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, K, W> serde::Deserialize<'de> for DiGraph<K, W>
+where
+    K: VerticeKey + serde::Deserialize<'de>,
+    W: Weight + serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr: GraphRepr<K, W> = serde::Deserialize::deserialize(deserializer)?;
+        let mut graph = DiGraph::new_sized(repr.vertices.len() as u32);
+        for v in repr.vertices {
+            graph.add_vertice(v);
+        }
+        for edge in repr.edges {
+            let id = edge.id().index();
+            if graph.add_edge_at(edge, id).is_none() {
+                return Err(serde::de::Error::custom(format!("edge id {} is out of order", id)));
+            }
+        }
+        Ok(graph)
     }
 }
@@ -0,0 +1,352 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::{
+    graph_lib::{edge::Edge, vertice::VerticeKey},
+    tools::weight::Weight,
+    UnGraph,
+};
+
+const NONE: usize = usize::MAX;
+
+/// Estado do algoritmo de Edmonds sobre os índices dos vértices
+struct Blossom {
+    adjacency: Vec<Vec<usize>>,
+    mate: Vec<usize>,
+    /// Pai de cada vértice ímpar na árvore alternante
+    parent: Vec<usize>,
+    /// Base da flor que contém o vértice (ele mesmo se não estiver em uma flor)
+    base: Vec<usize>,
+    /// Vértices pares (já na fila) da árvore alternante
+    used: Vec<bool>,
+    in_blossom: Vec<bool>,
+    queue: VecDeque<usize>,
+}
+
+impl Blossom {
+    fn new(adjacency: Vec<Vec<usize>>) -> Blossom {
+        let n = adjacency.len();
+        Blossom {
+            adjacency,
+            mate: vec![NONE; n],
+            parent: vec![NONE; n],
+            base: (0..n).collect(),
+            used: vec![false; n],
+            in_blossom: vec![false; n],
+            queue: VecDeque::new(),
+        }
+    }
+
+    /// Ancestral comum de `a` e `b` mais próximo na árvore alternante (uma base de flor)
+    fn lowest_common_ancestor(&self, mut a: usize, mut b: usize) -> usize {
+        let mut on_path = vec![false; self.mate.len()];
+        loop {
+            a = self.base[a];
+            on_path[a] = true;
+            if self.mate[a] == NONE {
+                break;
+            }
+            a = self.parent[self.mate[a]];
+        }
+        loop {
+            b = self.base[b];
+            if on_path[b] {
+                return b;
+            }
+            b = self.parent[self.mate[b]];
+        }
+    }
+
+    /// Marca as flores no caminho de `v` até a base `blossom_base`, apontando os pais para `child`
+    fn mark_path(&mut self, mut v: usize, blossom_base: usize, mut child: usize) {
+        while self.base[v] != blossom_base {
+            self.in_blossom[self.base[v]] = true;
+            self.in_blossom[self.base[self.mate[v]]] = true;
+            self.parent[v] = child;
+            child = self.mate[v];
+            v = self.parent[self.mate[v]];
+        }
+    }
+
+    /// Busca em largura de um caminho aumentante a partir do vértice livre `root`,
+    /// contraindo as flores encontradas
+    ///
+    /// ## Retorna
+    /// O vértice livre no fim do caminho, se houver
+    fn find_augmenting_path(&mut self, root: usize) -> Option<usize> {
+        let n = self.mate.len();
+        self.used.fill(false);
+        self.parent.fill(NONE);
+        for (i, base) in self.base.iter_mut().enumerate() {
+            *base = i;
+        }
+        self.used[root] = true;
+        self.queue.clear();
+        self.queue.push_back(root);
+
+        while let Some(v) = self.queue.pop_front() {
+            for i in 0..self.adjacency[v].len() {
+                let to = self.adjacency[v][i];
+                if self.base[v] == self.base[to] || self.mate[v] == to {
+                    continue;
+                }
+                if to == root || (self.mate[to] != NONE && self.parent[self.mate[to]] != NONE) {
+                    // Ciclo ímpar: contrai a flor na sua base
+                    let blossom_base = self.lowest_common_ancestor(v, to);
+                    self.in_blossom.fill(false);
+                    self.mark_path(v, blossom_base, to);
+                    self.mark_path(to, blossom_base, v);
+                    for u in 0..n {
+                        if self.in_blossom[self.base[u]] {
+                            self.base[u] = blossom_base;
+                            if !self.used[u] {
+                                self.used[u] = true;
+                                self.queue.push_back(u);
+                            }
+                        }
+                    }
+                } else if self.parent[to] == NONE {
+                    self.parent[to] = v;
+                    if self.mate[to] == NONE {
+                        return Some(to);
+                    }
+                    let next = self.mate[to];
+                    self.used[next] = true;
+                    self.queue.push_back(next);
+                }
+            }
+        }
+        None
+    }
+
+    /// Inverte o caminho aumentante que termina em `v`
+    fn augment(&mut self, mut v: usize) {
+        while v != NONE {
+            let parent = self.parent[v];
+            let next = self.mate[parent];
+            self.mate[v] = parent;
+            self.mate[parent] = v;
+            v = next;
+        }
+    }
+}
+
+/// Emparelhamento máximo (em quantidade de arestas) de um grafo não direcionado,
+/// pelo algoritmo das flores de Edmonds
+///
+/// Funciona em qualquer grafo, bipartido ou não, em tempo `O(n³)`. Laços são ignorados;
+/// entre vértices com arestas paralelas é escolhida a de menor id.
+/// ```
+/// # use rust_graph::{UnGraph, graph_lib::matching::maximum_matching};
+/// // O grafo de Petersen tem um emparelhamento perfeito
+/// let petersen: UnGraph = UnGraph::new_petersen();
+/// assert_eq!(maximum_matching(&petersen).len(), 5);
+/// // Em um ciclo ímpar sobra um vértice
+/// assert_eq!(maximum_matching(&UnGraph::<i32, i32>::new_cycle(7)).len(), 3);
+/// ```
+///
+/// ## Retorna
+/// As arestas do emparelhamento, sem vértices em comum
+pub fn maximum_matching<K: VerticeKey, W: Weight>(graph: &UnGraph<K, W>) -> Vec<Edge<K, W>> {
+    let keys = graph.get_vertice_key_array();
+    let index: HashMap<&K, usize> = keys.iter().enumerate().map(|(i, k)| (k, i)).collect();
+
+    // Aresta de menor id entre cada par de vizinhos
+    let mut chosen: HashMap<(usize, usize), Edge<K, W>> = HashMap::new();
+    for e in graph.all_edges() {
+        let (v, w) = (index[e.origin_key_ref()], index[e.destiny_key_ref()]);
+        if v == w {
+            continue;
+        }
+        let pair = (v.min(w), v.max(w));
+        if chosen.get(&pair).is_none_or(|old| old.id() > e.id()) {
+            chosen.insert(pair, e);
+        }
+    }
+    let mut adjacency = vec![Vec::new(); keys.len()];
+    let mut pairs: Vec<&(usize, usize)> = chosen.keys().collect();
+    pairs.sort_by_key(|pair| chosen[pair].id());
+    for &&(v, w) in pairs.iter() {
+        adjacency[v].push(w);
+        adjacency[w].push(v);
+    }
+
+    let mut blossom = Blossom::new(adjacency);
+    // Emparelhamento guloso inicial, depois os caminhos aumentantes
+    for v in 0..keys.len() {
+        if blossom.mate[v] != NONE {
+            continue;
+        }
+        if let Some(&w) = blossom.adjacency[v].iter().find(|&&w| blossom.mate[w] == NONE) {
+            blossom.mate[v] = w;
+            blossom.mate[w] = v;
+        }
+    }
+    for root in 0..keys.len() {
+        if blossom.mate[root] == NONE {
+            if let Some(end) = blossom.find_augmenting_path(root) {
+                blossom.augment(end);
+            }
+        }
+    }
+
+    let mut matching: Vec<Edge<K, W>> = (0..keys.len())
+        .filter(|&v| blossom.mate[v] != NONE && v < blossom.mate[v])
+        .map(|v| chosen[&(v, blossom.mate[v])].clone())
+        .collect();
+    matching.sort_by_key(|e| e.id());
+    matching
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+
+    use super::*;
+
+    /// Tamanho do emparelhamento máximo por força bruta, com programação dinâmica sobre o
+    /// conjunto de vértices ainda livres (só para grafos pequenos)
+    fn brute_force(graph: &UnGraph) -> usize {
+        let mut keys = graph.get_vertice_key_array();
+        keys.sort();
+        let n = keys.len();
+        let index: HashMap<i32, usize> = keys.iter().enumerate().map(|(i, &k)| (k, i)).collect();
+        let mut neighbours = vec![0u32; n];
+        for e in graph.all_edges() {
+            let (v, w) = (index[e.origin_key_ref()], index[e.destiny_key_ref()]);
+            if v != w {
+                neighbours[v] |= 1 << w;
+                neighbours[w] |= 1 << v;
+            }
+        }
+        // best[mask]: emparelhamento máximo usando só os vértices de `mask`
+        let mut best = vec![0usize; 1 << n];
+        for mask in 1..1usize << n {
+            let v = mask.trailing_zeros() as usize;
+            let rest = mask & !(1 << v);
+            let mut value = best[rest];
+            let mut candidates = neighbours[v] as usize & rest;
+            while candidates != 0 {
+                let w = candidates.trailing_zeros() as usize;
+                candidates &= candidates - 1;
+                value = value.max(1 + best[rest & !(1 << w)]);
+            }
+            best[mask] = value;
+        }
+        best[(1 << n) - 1]
+    }
+
+    /// Confere que o emparelhamento usa arestas do grafo, sem laços e sem vértices em comum
+    fn assert_valid(graph: &UnGraph, matching: &[Edge<i32, i32>]) {
+        let mut covered = HashSet::new();
+        for e in matching {
+            let original = graph.edge(e.id()).expect("matching edge is not in the graph");
+            assert_eq!(
+                (original.origin_key_ref(), original.destiny_key_ref()),
+                (e.origin_key_ref(), e.destiny_key_ref())
+            );
+            assert_ne!(e.origin_key_ref(), e.destiny_key_ref(), "loop in matching");
+            assert!(covered.insert(*e.origin_key_ref()), "vertex matched twice");
+            assert!(covered.insert(*e.destiny_key_ref()), "vertex matched twice");
+        }
+    }
+
+    fn assert_maximum(graph: &UnGraph) {
+        let matching = maximum_matching(graph);
+        assert_valid(graph, &matching);
+        assert_eq!(matching.len(), brute_force(graph), "edges: {:?}", graph.all_edges());
+    }
+
+    fn from_pairs(pairs: &[(i32, i32)]) -> UnGraph {
+        UnGraph::from_edges(pairs.iter().map(|&(v, w)| Edge::new(v, w)).collect())
+    }
+
+    #[test]
+    fn odd_cycles_leave_one_vertex_free() {
+        for n in 3..=15 {
+            let cycle: UnGraph = UnGraph::new_cycle(n);
+            let matching = maximum_matching(&cycle);
+            assert_valid(&cycle, &matching);
+            assert_eq!(matching.len(), n / 2, "cycle of {n} vertices");
+        }
+    }
+
+    #[test]
+    fn named_graphs_with_blossoms() {
+        let petersen: UnGraph = UnGraph::new_petersen();
+        assert_maximum(&petersen);
+        for n in 1..=12 {
+            assert_maximum(&UnGraph::new_complete(n));
+            assert_maximum(&UnGraph::new_wheel(n));
+        }
+        let mobius_kantor: UnGraph = UnGraph::new_mobius_kantor();
+        let matching = maximum_matching(&mobius_kantor);
+        assert_valid(&mobius_kantor, &matching);
+        assert_eq!(matching.len(), 8);
+    }
+
+    #[test]
+    fn augmenting_paths_through_blossoms() {
+        // Flor 1-2-3-4-5 com haste 0-1: o caminho aumentante de 6 até 0 passa pela flor
+        assert_maximum(&from_pairs(&[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 1), (3, 6)]));
+        // Dois triângulos ligados por uma aresta
+        assert_maximum(&from_pairs(&[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3)]));
+        // Flores aninhadas: um triângulo dentro de um ciclo de 5, com hastes
+        assert_maximum(&from_pairs(&[
+            (0, 1),
+            (1, 2),
+            (2, 3),
+            (3, 1),
+            (3, 4),
+            (4, 5),
+            (5, 6),
+            (6, 1),
+            (5, 7),
+            (7, 8),
+        ]));
+        // Ordem de inserção que leva o emparelhamento guloso inicial ao ótimo local errado
+        assert_maximum(&from_pairs(&[(1, 2), (0, 1), (2, 3), (3, 4), (4, 0), (4, 5)]));
+    }
+
+    #[test]
+    fn loops_parallel_edges_and_isolated_vertices() {
+        let mut graph: UnGraph = UnGraph::new();
+        assert!(maximum_matching(&graph).is_empty());
+        graph.add_vertice(7);
+        graph.add_edge(Edge::new(1, 1));
+        assert!(maximum_matching(&graph).is_empty());
+        let first = graph.add_edge(Edge::new_weighted(1, 2, 5));
+        graph.add_edge(Edge::new_weighted(2, 1, 3));
+        let matching = maximum_matching(&graph);
+        assert_eq!(matching.len(), 1);
+        assert_eq!(matching[0].id(), first);
+    }
+
+    #[test]
+    fn random_graphs_match_brute_force() {
+        let mut rng = ChaCha8Rng::seed_from_u64(2);
+        for _ in 0..500 {
+            let n = rng.gen_range(1..=12);
+            let density = rng.gen_range(0.1..0.7);
+            let mut graph: UnGraph = UnGraph::new();
+            // Chaves espalhadas (inclusive negativas) e inseridas fora de ordem
+            let keys: Vec<i32> = (0..n).map(|v| v * 7 - 20).collect();
+            for _ in 0..n {
+                graph.add_vertice(keys[rng.gen_range(0..n as usize)]);
+            }
+            for &key in keys.iter() {
+                graph.add_vertice(key);
+            }
+            for v in 0..n as usize {
+                for w in v..n as usize {
+                    if rng.gen_bool(density) {
+                        graph.add_edge(Edge::new(keys[w], keys[v]));
+                    }
+                }
+            }
+            assert_maximum(&graph);
+        }
+    }
+}
@@ -0,0 +1,576 @@
+use std::{collections::HashMap, fmt::Display, fs, str::FromStr};
+
+use super::{edge::Edge, error::GraphError, vertice::VerticeKey};
+use crate::{
+    tools::{inifinity::Infinity, weight::Weight},
+    DiGraph,
+};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+#[derive(Debug, Clone)]
+/// # MatrixGraph
+/// Grafo direcionado representado em matriz de adjacência
+///
+/// A existência das arestas é guardada em uma matriz de bits (`n * n` bits, uma linha
+/// por vértice), o que torna `has_edge` O(1) e a representação compacta para grafos
+/// densos. Os pesos só são alocados (`n * n` valores) quando alguma aresta tem peso
+/// diferente de 1.
+///
+/// Arestas paralelas não existem nesta representação: ao converter de um `DiGraph`,
+/// fica o menor peso entre `v` e `w`.
+///
+/// `keys`: chave de cada vértice, na ordem das linhas/colunas
+///
+/// `index`: HashMap da chave para a linha/coluna do vértice
+///
+/// Com a feature `serde` a desserialização confere os tamanhos da matriz, o `index` e a
+/// quantidade de arestas.
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(
+        serialize = "K: VerticeKey + serde::Serialize, W: Weight + serde::Serialize",
+        deserialize = "K: VerticeKey + serde::Deserialize<'de>, W: Weight + serde::Deserialize<'de>"
+    )),
+    serde(try_from = "MatrixGraphRepr<K, W>")
+)]
+pub struct MatrixGraph<K = i32, W = i32> {
+    keys: Vec<K>,
+    index: HashMap<K, usize>,
+    row_words: usize,
+    bits: Vec<u64>,
+    weights: Option<Vec<W>>,
+    edges_len: usize,
+}
+
+#[allow(unused)]
+impl<K: VerticeKey, W: Weight> MatrixGraph<K, W> {
+    /// Cria um grafo sem arestas com os vértices dados, na ordem das linhas/colunas
+    ///
+    /// Chaves repetidas são ignoradas.
+    pub fn from_keys(keys: Vec<K>) -> MatrixGraph<K, W> {
+        let mut unique = Vec::with_capacity(keys.len());
+        let mut index = HashMap::with_capacity(keys.len());
+        for key in keys {
+            if !index.contains_key(&key) {
+                index.insert(key.clone(), unique.len());
+                unique.push(key);
+            }
+        }
+        let n = unique.len();
+        let row_words = n.div_ceil(WORD_BITS);
+        MatrixGraph {
+            keys: unique,
+            index,
+            row_words,
+            bits: vec![0; row_words * n],
+            weights: None,
+            edges_len: 0,
+        }
+    }
+
+    /// Cria a matriz a partir de um `DiGraph`
+    pub fn from_digraph(graph: &DiGraph<K, W>) -> MatrixGraph<K, W> {
+        let mut matrix = MatrixGraph::from_keys(graph.get_vertice_key_array());
+        for e in graph.all_edges() {
+            let (v, w) = (matrix.index[e.origin_key_ref()], matrix.index[e.destiny_key_ref()]);
+            match matrix.weight_at(v, w) {
+                Some(old) if old <= e.weight() => {}
+                _ => matrix.set_at(v, w, e.weight()),
+            }
+        }
+        matrix
+    }
+
+    /// Cria um `DiGraph` com uma aresta para cada posição marcada da matriz
+    pub fn to_digraph(&self) -> DiGraph<K, W> {
+        let mut graph = DiGraph::new_sized(self.keys.len() as u32);
+        for key in self.keys.iter() {
+            graph.add_vertice(key.clone());
+        }
+        for v in 0..self.keys.len() {
+            for w in self.successors_at(v) {
+                let weight = self.weight_at(v, w).unwrap();
+                graph.add_edge(Edge::new_weighted(
+                    self.keys[v].clone(),
+                    self.keys[w].clone(),
+                    weight,
+                ));
+            }
+        }
+        graph
+    }
+
+    /// Retorna a quantidade de vértices no grafo.
+    pub fn vertices_length(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Retorna a quantidade de arestas no grafo.
+    pub fn edges_length(&self) -> usize {
+        self.edges_len
+    }
+
+    /// Chaves dos vértices, na ordem das linhas/colunas
+    pub fn keys(&self) -> &[K] {
+        &self.keys
+    }
+
+    /// Linha/coluna do vértice de chave `key`, se existir
+    pub fn index_of(&self, key: &K) -> Option<usize> {
+        self.index.get(key).copied()
+    }
+
+    /// Indica se a matriz guarda pesos (alguma aresta tem peso diferente de 1)
+    pub fn is_weighted(&self) -> bool {
+        self.weights.is_some()
+    }
+
+    /// Verifica se existe a aresta `v -> w` em O(1)
+    pub fn has_edge(&self, v: K, w: K) -> bool {
+        match (self.index.get(&v), self.index.get(&w)) {
+            (Some(&v), Some(&w)) => self.has_edge_at(v, w),
+            _ => false,
+        }
+    }
+
+    /// Verifica se existe a aresta entre a linha `v` e a coluna `w`
+    pub fn has_edge_at(&self, v: usize, w: usize) -> bool {
+        self.bits[v * self.row_words + w / WORD_BITS] & (1 << (w % WORD_BITS)) != 0
+    }
+
+    /// Peso da aresta `v -> w`, se existir
+    pub fn weight(&self, v: K, w: K) -> Option<W> {
+        self.weight_at(*self.index.get(&v)?, *self.index.get(&w)?)
+    }
+
+    fn weight_at(&self, v: usize, w: usize) -> Option<W> {
+        if !self.has_edge_at(v, w) {
+            return None;
+        }
+        match &self.weights {
+            Some(weights) => Some(weights[v * self.keys.len() + w]),
+            None => Some(W::one()),
+        }
+    }
+
+    /// Adiciona a aresta `v -> w` com peso 1, criando os vértices se necessário
+    ///
+    /// Se a aresta já existir, apenas o peso é substituído.
+    pub fn add_edge(&mut self, v: K, w: K) {
+        self.add_weighted_edge(v, w, W::one());
+    }
+
+    /// Adiciona a aresta `v -> w` com peso `weight`, criando os vértices se necessário
+    ///
+    /// Se a aresta já existir, apenas o peso é substituído.
+    pub fn add_weighted_edge(&mut self, v: K, w: K, weight: W) {
+        let v = self.index_or_insert(v);
+        let w = self.index_or_insert(w);
+        self.set_at(v, w, weight);
+    }
+
+    /// Remove a aresta `v -> w`, retornando seu peso se existia
+    pub fn remove_edge(&mut self, v: K, w: K) -> Option<W> {
+        let (v, w) = (*self.index.get(&v)?, *self.index.get(&w)?);
+        let weight = self.weight_at(v, w)?;
+        self.bits[v * self.row_words + w / WORD_BITS] &= !(1 << (w % WORD_BITS));
+        self.edges_len -= 1;
+        Some(weight)
+    }
+
+    /// Adiciona um vértice sem arestas
+    ///
+    /// Como a matriz é densa, a operação é O(n²).
+    pub fn add_vertice(&mut self, vertice_key: K) -> bool {
+        if self.index.contains_key(&vertice_key) {
+            return false;
+        }
+        self.index_or_insert(vertice_key);
+        true
+    }
+
+    /// Retorna as chaves dos sucessores de um vértice
+    pub fn get_sucessor(&self, vertice_key: K) -> Option<Vec<K>> {
+        let v = *self.index.get(&vertice_key)?;
+        Some(self.successors_at(v).map(|w| self.keys[w].clone()).collect())
+    }
+
+    /// Retorna as chaves dos predecessores de um vértice
+    pub fn predecessor(&self, vertice_key: K) -> Option<Vec<K>> {
+        let w = *self.index.get(&vertice_key)?;
+        Some(
+            (0..self.keys.len())
+                .filter(|&v| self.has_edge_at(v, w))
+                .map(|v| self.keys[v].clone())
+                .collect(),
+        )
+    }
+
+    fn successors_at(&self, v: usize) -> impl Iterator<Item = usize> + '_ {
+        (0..self.keys.len()).filter(move |&w| self.has_edge_at(v, w))
+    }
+
+    fn set_at(&mut self, v: usize, w: usize, weight: W) {
+        let word = &mut self.bits[v * self.row_words + w / WORD_BITS];
+        let mask = 1 << (w % WORD_BITS);
+        if *word & mask == 0 {
+            *word |= mask;
+            self.edges_len += 1;
+        }
+        if self.weights.is_none() && weight != W::one() {
+            self.weights = Some(vec![W::one(); self.keys.len() * self.keys.len()]);
+        }
+        let n = self.keys.len();
+        if let Some(weights) = self.weights.as_mut() {
+            weights[v * n + w] = weight;
+        }
+    }
+
+    fn index_or_insert(&mut self, key: K) -> usize {
+        if let Some(&i) = self.index.get(&key) {
+            return i;
+        }
+        let old_n = self.keys.len();
+        let n = old_n + 1;
+        let row_words = n.div_ceil(WORD_BITS);
+
+        let mut bits = vec![0; row_words * n];
+        for v in 0..old_n {
+            let old_row = &self.bits[v * self.row_words..(v + 1) * self.row_words];
+            bits[v * row_words..v * row_words + self.row_words].copy_from_slice(old_row);
+        }
+        if let Some(old_weights) = self.weights.as_ref() {
+            let mut weights = vec![W::one(); n * n];
+            for v in 0..old_n {
+                weights[v * n..v * n + old_n].copy_from_slice(&old_weights[v * old_n..(v + 1) * old_n]);
+            }
+            self.weights = Some(weights);
+        }
+        self.bits = bits;
+        self.row_words = row_words;
+        self.index.insert(key.clone(), old_n);
+        self.keys.push(key);
+        old_n
+    }
+
+    /// Fecho transitivo: `v -> w` existe no resultado se `w` é alcançável a partir de `v`
+    /// por um caminho com pelo menos uma aresta
+    ///
+    /// Algoritmo de Warshall operando sobre linhas de bits, O(n³ / 64).
+    pub fn transitive_closure(&self) -> MatrixGraph<K, W> {
+        let n = self.keys.len();
+        let mut bits = self.bits.clone();
+        for k in 0..n {
+            let row_k = bits[k * self.row_words..(k + 1) * self.row_words].to_vec();
+            for v in 0..n {
+                if bits[v * self.row_words + k / WORD_BITS] & (1 << (k % WORD_BITS)) != 0 {
+                    let row_v = &mut bits[v * self.row_words..(v + 1) * self.row_words];
+                    for (a, b) in row_v.iter_mut().zip(row_k.iter()) {
+                        *a |= *b;
+                    }
+                }
+            }
+        }
+        let edges_len = bits.iter().map(|word| word.count_ones() as usize).sum();
+        MatrixGraph {
+            keys: self.keys.clone(),
+            index: self.index.clone(),
+            row_words: self.row_words,
+            bits,
+            weights: None,
+            edges_len,
+        }
+    }
+
+    /// Custo mínimo entre todos os pares de vértices (Floyd-Warshall)
+    ///
+    /// `result[v][w]` é o custo de `keys()[v]` até `keys()[w]`; grafos sem pesos
+    /// usam 1 em cada aresta.
+    ///
+    /// # Errors
+    /// `GraphError::NegativeCycle` se o grafo tiver um ciclo negativo (inclusive um laço
+    /// de peso negativo)
+    /// ```
+    /// # use rust_graph::MatrixGraph;
+    /// let mut graph: MatrixGraph = MatrixGraph::new(2);
+    /// graph.add_weighted_edge(0, 0, -3);
+    /// assert!(graph.floyd_warshall().is_err());
+    /// ```
+    pub fn floyd_warshall(&self) -> Result<Vec<Vec<Infinity<W>>>, GraphError> {
+        let n = self.keys.len();
+        let mut cost = vec![vec![Infinity::Infinite; n]; n];
+        for (v, row) in cost.iter_mut().enumerate() {
+            for w in self.successors_at(v) {
+                row[w] = Infinity::Number(self.weight_at(v, w).unwrap());
+            }
+            // Um laço negativo é mantido na diagonal para ser reportado como ciclo negativo
+            if row[v] >= Infinity::Number(W::zero()) {
+                row[v] = Infinity::Number(W::zero());
+            }
+        }
+        for k in 0..n {
+            for v in 0..n {
+                if cost[v][k].is_infinite() {
+                    continue;
+                }
+                for w in 0..n {
+                    let through_k = cost[v][k] + cost[k][w];
+                    if cost[v][w] > through_k {
+                        cost[v][w] = through_k;
+                    }
+                }
+            }
+            if (0..n).any(|v| cost[v][v] < Infinity::Number(W::zero())) {
+                return Err(GraphError::NegativeCycle);
+            }
+        }
+        Ok(cost)
+    }
+}
+
+impl<K: VerticeKey + Display, W: Weight> MatrixGraph<K, W> {
+    /// Escreve a matriz em texto: uma linha por vértice, com o peso da aresta
+    /// ou 0 quando ela não existe
+    ///
+    /// As chaves não são escritas; a ordem é a de `keys()`.
+    ///
+    /// Uma aresta de peso 0 é escrita como 0, igual à ausência de aresta, e por isso se
+    /// perde ao ler o texto de volta com [`MatrixGraph::from_matrix_str`]:
+    /// ```
+    /// # use rust_graph::MatrixGraph;
+    /// let mut graph: MatrixGraph = MatrixGraph::new(2);
+    /// graph.add_weighted_edge(0, 1, 0);
+    /// assert!(graph.has_edge(0, 1));
+    /// let read: MatrixGraph = MatrixGraph::from_matrix_str(&graph.to_matrix_string()).unwrap();
+    /// assert!(!read.has_edge(0, 1));
+    /// ```
+    pub fn to_matrix_string(&self) -> String {
+        let n = self.keys.len();
+        let mut out = String::with_capacity(n * n * 2);
+        for v in 0..n {
+            let row: Vec<String> = (0..n)
+                .map(|w| match self.weight_at(v, w) {
+                    Some(weight) => weight.to_string(),
+                    None => W::zero().to_string(),
+                })
+                .collect();
+            out.push_str(&row.join(" "));
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Salva a matriz de adjacência em um arquivo (ver `to_matrix_string`, inclusive
+    /// sobre arestas de peso 0)
+    pub fn to_file(&self, file_path: &str) -> Result<(), GraphError> {
+        fs::write(file_path, self.to_matrix_string())?;
+        Ok(())
+    }
+}
+
+impl<W: Weight + FromStr> MatrixGraph<i32, W> {
+    /// Cria um grafo sem arestas com os vértices `0..n`
+    pub fn new(n: usize) -> MatrixGraph<i32, W> {
+        MatrixGraph::from_keys((0..n as i32).collect())
+    }
+
+    /// Lê uma matriz de adjacência em texto
+    ///
+    /// Cada linha não vazia é uma linha da matriz, com valores separados por espaços;
+    /// 0 indica ausência de aresta e qualquer outro valor é o peso. Os vértices
+    /// recebem as chaves `0..n`. Arestas de peso 0 não podem ser representadas neste
+    /// formato (ver [`MatrixGraph::to_matrix_string`]).
+    ///
+    /// Um texto sem linhas é a matriz vazia, sem vértices.
+    ///
+    /// # Errors
+    /// `GraphError::Parse` com a linha do arquivo (contando as vazias) se a matriz não for
+    /// quadrada ou algum valor for inválido
+    /// ```
+    /// # use rust_graph::MatrixGraph;
+    /// let graph: MatrixGraph = MatrixGraph::from_matrix_str("0 1 0\n0 0 1\n1 0 0").unwrap();
+    /// assert!(graph.has_edge(2, 0));
+    /// assert_eq!(graph.edges_length(), 3);
+    /// ```
+    pub fn from_matrix_str(content: &str) -> Result<MatrixGraph<i32, W>, GraphError> {
+        let rows: Vec<(usize, &str)> = content
+            .lines()
+            .enumerate()
+            .filter(|(_, l)| !l.trim().is_empty())
+            .map(|(i, l)| (i + 1, l))
+            .collect();
+        let n = rows.len();
+        // A forma é conferida antes de alocar os n² bits, que poderiam ser muito maiores
+        // que o texto se houver muitas linhas curtas
+        if let Some(&(line_number, line)) = rows.iter().find(|(_, l)| l.split_whitespace().count() != n) {
+            return Err(GraphError::parse(line_number, line));
+        }
+        let mut graph = MatrixGraph::new(n);
+        for (v, &(line_number, line)) in rows.iter().enumerate() {
+            for (w, value) in line.split_whitespace().enumerate() {
+                let Ok(weight) = value.parse::<W>() else {
+                    return Err(GraphError::parse(line_number, line));
+                };
+                if weight != W::zero() {
+                    graph.set_at(v, w, weight);
+                }
+            }
+        }
+        Ok(graph)
+    }
+
+    /// Lê uma matriz de adjacência de um arquivo (ver `from_matrix_str`)
+    pub fn from_file(file_path: &str) -> Result<MatrixGraph<i32, W>, GraphError> {
+        let file_content = fs::read_to_string(file_path)?;
+        MatrixGraph::from_matrix_str(&file_content)
+    }
+}
+
+impl<K: VerticeKey, W: Weight> From<&DiGraph<K, W>> for MatrixGraph<K, W> {
+    fn from(graph: &DiGraph<K, W>) -> Self {
+        MatrixGraph::from_digraph(graph)
+    }
+}
+
+impl<K: VerticeKey, W: Weight> From<&MatrixGraph<K, W>> for DiGraph<K, W> {
+    fn from(graph: &MatrixGraph<K, W>) -> Self {
+        graph.to_digraph()
+    }
+}
+
+impl<K: VerticeKey, W: Weight> From<MatrixGraph<K, W>> for DiGraph<K, W> {
+    fn from(graph: MatrixGraph<K, W>) -> Self {
+        graph.to_digraph()
+    }
+}
+
+/// Forma serializada de um `MatrixGraph`, validada antes de virar o grafo
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct MatrixGraphRepr<K: VerticeKey, W> {
+    keys: Vec<K>,
+    index: HashMap<K, usize>,
+    row_words: usize,
+    bits: Vec<u64>,
+    weights: Option<Vec<W>>,
+    edges_len: usize,
+}
+
+#[cfg(feature = "serde")]
+impl<K: VerticeKey, W: Weight> TryFrom<MatrixGraphRepr<K, W>> for MatrixGraph<K, W> {
+    type Error = String;
+
+    fn try_from(repr: MatrixGraphRepr<K, W>) -> Result<Self, Self::Error> {
+        let n = repr.keys.len();
+        let index_ok = repr.index.len() == n
+            && repr.keys.iter().enumerate().all(|(i, k)| repr.index.get(k) == Some(&i));
+        if !index_ok {
+            return Err("index does not match the keys".to_string());
+        }
+        let rows_ok = repr.row_words == n.div_ceil(WORD_BITS)
+            && repr.row_words.checked_mul(n) == Some(repr.bits.len());
+        if !rows_ok {
+            return Err(format!("bit matrix does not have {n} rows of {n} bits"));
+        }
+        if repr.weights.as_ref().is_some_and(|w| Some(w.len()) != n.checked_mul(n)) {
+            return Err(format!("weight matrix does not have {n} x {n} values"));
+        }
+        // Os bits depois da coluna `n - 1` de cada linha são sempre zero
+        let padding = n % WORD_BITS;
+        let outside = |row: &[u64]| row[row.len() - 1] >> padding != 0;
+        if padding != 0 && repr.bits.chunks(repr.row_words).any(outside) {
+            return Err("bit matrix has edges outside the columns".to_string());
+        }
+        let found: usize = repr.bits.iter().map(|word| word.count_ones() as usize).sum();
+        if found != repr.edges_len {
+            return Err(format!("matrix declares {} edges, found {}", repr.edges_len, found));
+        }
+        Ok(MatrixGraph {
+            keys: repr.keys,
+            index: repr.index,
+            row_words: repr.row_words,
+            bits: repr.bits,
+            weights: repr.weights,
+            edges_len: repr.edges_len,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Linha do erro de leitura, falhando o teste se a matriz for aceita ou o erro for outro
+    fn error_line(content: &str) -> usize {
+        match MatrixGraph::<i32, i32>::from_matrix_str(content) {
+            Err(GraphError::Parse { line, .. }) => line,
+            Err(other) => panic!("expected a parse error for {content:?}, got {other}"),
+            Ok(_) => panic!("malformed matrix was accepted: {content:?}"),
+        }
+    }
+
+    #[test]
+    fn non_square_matrices_are_rejected() {
+        assert_eq!(error_line("0 1\n1 0\n0 0"), 1);
+        assert_eq!(error_line("0 1 0\n1 0\n0 0 0"), 2);
+        assert_eq!(error_line("0 1\n1 0 1"), 2);
+        assert_eq!(error_line("0 1 1"), 1);
+        assert_eq!(error_line("0\n0"), 1);
+    }
+
+    #[test]
+    fn invalid_values_are_rejected() {
+        assert_eq!(error_line("0 x\n1 0"), 1);
+        assert_eq!(error_line("0 1\n1.5 0"), 2);
+        assert_eq!(error_line("0 1\n1 99999999999"), 2);
+        assert_eq!(error_line("0,1\n1,0"), 1);
+        assert!(MatrixGraph::<i32, f64>::from_matrix_str("0 1.5\nNaN 0").is_ok());
+    }
+
+    #[test]
+    fn error_lines_count_blank_lines() {
+        assert_eq!(error_line("\n0 1\n\n1 x\n"), 4);
+        assert_eq!(error_line("0 1\n   \n1 0 0\n"), 3);
+    }
+
+    #[test]
+    fn many_short_rows_fail_before_allocating_the_matrix() {
+        // 1 milhão de linhas pediria 125 GB de bits se a forma não fosse conferida antes
+        let content = "1\n".repeat(1_000_000);
+        assert_eq!(error_line(&content), 1);
+    }
+
+    #[test]
+    fn empty_input_is_the_empty_matrix() {
+        for content in ["", "\n", "  \n\t\n"] {
+            let graph: MatrixGraph = MatrixGraph::from_matrix_str(content).unwrap();
+            assert_eq!(graph.vertices_length(), 0);
+            assert_eq!(graph.edges_length(), 0);
+            assert_eq!(graph.to_matrix_string(), "");
+        }
+    }
+
+    #[test]
+    fn weights_round_trip_through_text() {
+        let content = "0 -2 0\n0 1 7\n3 0 0\n";
+        let graph: MatrixGraph = MatrixGraph::from_matrix_str(content).unwrap();
+        assert_eq!(graph.edges_length(), 4);
+        assert!(graph.is_weighted());
+        assert_eq!(graph.weight(0, 1), Some(-2));
+        assert_eq!(graph.weight(1, 1), Some(1));
+        assert_eq!(graph.weight(0, 2), None);
+        assert_eq!(graph.to_matrix_string(), content);
+    }
+
+    #[test]
+    fn negative_self_loop_is_a_negative_cycle() {
+        let graph: MatrixGraph = MatrixGraph::from_matrix_str("0 1\n0 -1").unwrap();
+        assert!(matches!(graph.floyd_warshall(), Err(GraphError::NegativeCycle)));
+        let graph: MatrixGraph = MatrixGraph::from_matrix_str("0 1\n-1 0").unwrap();
+        assert!(graph.floyd_warshall().is_ok());
+        let graph: MatrixGraph = MatrixGraph::from_matrix_str("0 1\n-2 0").unwrap();
+        assert!(matches!(graph.floyd_warshall(), Err(GraphError::NegativeCycle)));
+    }
+}
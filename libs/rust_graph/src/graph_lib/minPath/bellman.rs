@@ -1,61 +1,102 @@
 use std::collections::HashMap;
 
-use crate::{tools::inifinity::Infinity, DiGraph};
+use crate::{
+    graph_lib::{edge::Edge, vertice::VerticeKey},
+    tools::{inifinity::Infinity, weight::Weight},
+    DiGraph, GraphError,
+};
 
-pub struct Bellman {
-    pred: HashMap<i32, i32>,
-    pot: HashMap<i32, Infinity>,
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(
+        serialize = "K: VerticeKey + serde::Serialize, W: Weight + serde::Serialize",
+        deserialize = "K: VerticeKey + serde::Deserialize<'de>, W: Weight + serde::Deserialize<'de>"
+    ))
+)]
+pub struct Bellman<K = i32, W = i32> {
+    pred: HashMap<K, K>,
+    pot: HashMap<K, Infinity<W>>,
+}
+
+impl<K: VerticeKey, W: Weight> Default for Bellman<K, W> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 #[allow(unused)]
-impl Bellman {
-    pub fn new() -> Bellman {
+impl<K: VerticeKey, W: Weight> Bellman<K, W> {
+    pub fn new() -> Bellman<K, W> {
         Bellman {
             pred: HashMap::new(),
             pot: HashMap::new(),
         }
     }
 
-    pub fn pred(&self) -> &HashMap<i32, i32> {
+    /// Predecessor de cada vértice no caminho mínimo
+    ///
+    /// Vértices inalcançáveis (e a origem) não possuem entrada.
+    pub fn pred(&self) -> &HashMap<K, K> {
         &self.pred
     }
-    pub fn pot(&self) -> &HashMap<i32, Infinity> {
+    pub fn pot(&self) -> &HashMap<K, Infinity<W>> {
         &self.pot
     }
 }
 use Infinity::*;
 
+/// Caminho mínimo de Bellman-Ford a partir de `start`, aceitando pesos negativos
+///
+/// # Errors
+/// `GraphError::MissingVertex` se `start` não existir e `GraphError::NegativeCycle`
+/// se houver um ciclo negativo alcançável a partir de `start`
+#[allow(unused)]
+pub fn find_shortest_path<K: VerticeKey, W: Weight>(
+    graph: &DiGraph<K, W>,
+    start: K,
+) -> Result<Bellman<K, W>, GraphError> {
+    find_shortest_path_by(graph, start, |e| e.weight())
+}
+
+/// Caminho mínimo usando `cost` para obter o custo de cada aresta
 #[allow(unused)]
-pub fn find_shortest_path(graph: &DiGraph, start: i32) -> Bellman {
+pub fn find_shortest_path_by<K: VerticeKey, E: Weight, W: Weight>(
+    graph: &DiGraph<K, E>,
+    start: K,
+    cost: impl Fn(&Edge<K, E>) -> W,
+) -> Result<Bellman<K, W>, GraphError> {
+    if !graph.vertice_exists(start.clone()) {
+        return Err(GraphError::missing_vertex(&start));
+    }
     let mut data = Bellman::new();
 
     for v in graph.iter_vertices() {
-     
         data.pot.insert(v.key(), Infinite);
-        data.pred.insert(v.key(), -1);
     }
-    
-    data.pot.insert(start, Number(0));
+
+    data.pot.insert(start, Number(W::zero()));
     for _ in 0..graph.vertices_length() {
         let mut change = false;
         for v in graph.iter_vertices() {
-         
             for e in v.edges_vec_ref() {
                 let w = e.destiny_key();
                 let v = v.key();
                 let v_d = *data.pot.get(&v).unwrap();
                 let w_d = *data.pot.get(&w).unwrap();
 
-                if w_d > (v_d + Number(e.weight())) {
-                    data.pot.insert(w, Number(v_d.unwrap() + e.weight()));
+                let e_cost = cost(e);
+                if w_d > (v_d + Number(e_cost)) {
+                    data.pot.insert(w.clone(), Number(v_d.unwrap() + e_cost));
                     data.pred.insert(w, v);
                     change = true;
                 }
             }
         }
         if !change {
-            break;
+            return Ok(data);
         }
     }
-    data
+    // Ainda houve relaxamento após |V| iterações
+    Err(GraphError::NegativeCycle)
 }
@@ -1,96 +1,119 @@
-use std::collections::HashMap;
+use std::{cmp::Ordering, collections::HashMap};
 
 use crate::{
-    graph_lib::graph,
-    graph_lib::vertice,
-    tools::{heap::HeapMin, inifinity::Infinity},
-    DiGraph,
+    graph_lib::{edge::Edge, vertice::VerticeKey},
+    tools::{heap::HeapMin, inifinity::Infinity, weight::Weight},
+    DiGraph, GraphError,
 };
 
-#[derive(Clone, Copy)]
-struct VerticeDist(i32, Infinity);
+#[derive(Clone)]
+struct VerticeDist<K, W>(K, Infinity<W>);
 
-impl VerticeDist {
-    pub fn vertice(&self) -> i32 {
-        self.0
+impl<K: VerticeKey, W: Weight> VerticeDist<K, W> {
+    pub fn vertice(&self) -> K {
+        self.0.clone()
     }
-    pub fn dist(&self) -> Infinity {
+    pub fn dist(&self) -> Infinity<W> {
         self.1
     }
-
-    pub fn set_dist(&mut self, dist: Infinity) {
-        self.1 = dist;
-    }
 }
 
-pub struct Dijkstra {
-    pred: HashMap<i32, i32>,
-    dist: HashMap<i32, Infinity>,
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(
+        serialize = "K: VerticeKey + serde::Serialize, W: Weight + serde::Serialize",
+        deserialize = "K: VerticeKey + serde::Deserialize<'de>, W: Weight + serde::Deserialize<'de>"
+    ))
+)]
+pub struct Dijkstra<K = i32, W = i32> {
+    pred: HashMap<K, K>,
+    dist: HashMap<K, Infinity<W>>,
 }
 use Infinity::{Infinite, Number};
-impl Dijkstra {
-    pub fn new() -> Dijkstra {
+
+impl<K: VerticeKey, W: Weight> Default for Dijkstra<K, W> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: VerticeKey, W: Weight> Dijkstra<K, W> {
+    pub fn new() -> Dijkstra<K, W> {
         Dijkstra {
             pred: HashMap::new(),
             dist: HashMap::new(),
         }
     }
 
-    pub fn new_sized(size: usize) -> Dijkstra {
-        let mut data = Dijkstra {
+    pub fn new_sized(size: usize) -> Dijkstra<K, W> {
+        Dijkstra {
             pred: HashMap::with_capacity(size),
             dist: HashMap::with_capacity(size),
-        };
-        data
+        }
     }
 
-    pub fn pred(&self) -> &HashMap<i32, i32> {
+    /// Predecessor de cada vértice no caminho mínimo
+    ///
+    /// Vértices inalcançáveis (e a origem) não possuem entrada.
+    pub fn pred(&self) -> &HashMap<K, K> {
         &self.pred
     }
-    pub fn dist(&self) -> &HashMap<i32, Infinity> {
+    pub fn dist(&self) -> &HashMap<K, Infinity<W>> {
         &self.dist
     }
 
-    pub fn shortest_path(g: &DiGraph, v_key: i32) -> Self {
-        let mut data = Dijkstra::new_sized(g.vertices_length() as usize);
-        let mut queue = HeapMin::new(|a: &VerticeDist, b: &VerticeDist| a.dist().cmp(&b.dist()));
-        data.dist.insert(v_key, Infinity::new(0));
-        queue.insert((VerticeDist(v_key, Infinity::new(0))));
+    /// Caminho mínimo a partir de `v_key` usando o peso das arestas
+    ///
+    /// # Errors
+    /// `GraphError::MissingVertex` se `v_key` não existir no grafo
+    pub fn shortest_path(g: &DiGraph<K, W>, v_key: K) -> Result<Self, GraphError> {
+        Self::shortest_path_by(g, v_key, |e| e.weight())
+    }
+
+    /// Caminho mínimo usando `cost` para obter o custo de cada aresta
+    ///
+    /// Permite usar um atributo guardado fora do grafo, por exemplo:
+    /// `Dijkstra::shortest_path_by(&g, v, |e| *costs.get(e.id()).unwrap())`
+    pub fn shortest_path_by<E: Weight>(
+        g: &DiGraph<K, E>,
+        v_key: K,
+        cost: impl Fn(&Edge<K, E>) -> W,
+    ) -> Result<Self, GraphError> {
+        if !g.vertice_exists(v_key.clone()) {
+            return Err(GraphError::missing_vertex(&v_key));
+        }
+        let mut data = Dijkstra::new_sized(g.vertices_length());
+        let mut queue = HeapMin::new(|a: &VerticeDist<K, W>, b: &VerticeDist<K, W>| {
+            a.dist().partial_cmp(&b.dist()).unwrap_or(Ordering::Equal)
+        });
+        data.dist.insert(v_key.clone(), Infinity::new(W::zero()));
+        queue.insert(VerticeDist(v_key.clone(), Infinity::new(W::zero())));
 
         for v in g.iter_vertices() {
-            if v.key() != v_key {
+            if *v.key_ref() != v_key {
                 data.dist.insert(v.key(), Infinite);
-                data.pred.insert(v.key(), -1);
             }
         }
 
         while !queue.empty() {
-            let v = queue.pop().unwrap().0;
-            let vertice = g.get_vertice_arc(v);
-            if vertice.is_none() {
-                panic!("Vertice does not exist in graph {v}");
-            }
-            let v = vertice.unwrap();
+            let v = queue.pop().unwrap().vertice();
+            let Some(v) = g.get_vertice_arc(v) else {
+                continue;
+            };
 
             for e in v.edges_vec_ref() {
                 let w = e.destiny_key();
-                let v_d = *data.dist.get(&v.key()).unwrap();
+                let v_d = *data.dist.get(v.key_ref()).unwrap();
                 let w_d = *data.dist.get(&w).unwrap();
-                if w_d > (v_d + Number(e.weight())) {
-                    println!(
-                        "Relaxando d[{}] = {} com d[{}] = {} + w(v,w) = {}",
-                        w,
-                        w_d,
-                        v.key(),
-                        v_d,
-                        e.weight()
-                    );
-                    data.dist.insert(w, Number(v_d.unwrap() + e.weight()));
-                    data.pred.insert(w, v.key());
-                    queue.insert(VerticeDist(w, Number(v_d.unwrap() + e.weight())));
+                let e_cost = cost(e);
+                if w_d > (v_d + Number(e_cost)) {
+                    data.dist.insert(w.clone(), Number(v_d.unwrap() + e_cost));
+                    data.pred.insert(w.clone(), v.key());
+                    queue.insert(VerticeDist(w, Number(v_d.unwrap() + e_cost)));
                 }
             }
         }
-        data
+        Ok(data)
     }
 }
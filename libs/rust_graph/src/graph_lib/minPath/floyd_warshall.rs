@@ -1,103 +1,138 @@
 use crate::{
-    graph_lib::edge::Edge,
-    graph_lib::graph,
-    tools::inifinity::Infinity,
-    graph_lib::vertice::{self, Vertice},
-    DiGraph,
+    graph_lib::{edge::Edge, vertice::VerticeKey},
+    tools::{inifinity::Infinity, weight::Weight},
+    DiGraph, GraphError,
 };
-use std::{collections::HashMap, fmt::Alignment, hash::Hash, rc::Rc, sync::Arc};
+use std::{collections::HashMap, fmt::Display};
 
-pub struct MinPathTable<'a> {
-    cost: HashMap<(i32, i32), Infinity>,   // maps (v, w) to cost
-    predecessor: HashMap<(i32, i32), i32>, //maps a pair of vertices to the predecessor
-    //example, (v,w) -> the predecessor of w in tha path from v to w is i32
-    g: &'a DiGraph,
+pub struct MinPathTable<'a, K = i32, W = i32> {
+    cost: HashMap<(K, K), Infinity<W>>, // maps (v, w) to cost
+    predecessor: HashMap<(K, K), K>,    //maps a pair of vertices to the predecessor
+    //example, (v,w) -> the predecessor of w in tha path from v to w is K
+    g: &'a DiGraph<K, W>,
 }
 
-use comfy_table::{Cell, CellAlignment, Color, ContentArrangement, Row, Table};
+use comfy_table::{Cell, Color, ContentArrangement, Row, Table};
 use Infinity::*;
-impl<'a> MinPathTable<'a> {
-    fn new(g: &'a DiGraph) -> Self {
+impl<'a, K: VerticeKey, W: Weight> MinPathTable<'a, K, W> {
+    fn new(g: &'a DiGraph<K, W>) -> Self {
         let vertices = g.get_vertice_key_array();
-        let mut cost_map: HashMap<(i32, i32), Infinity> = HashMap::new();
+        let mut cost_map: HashMap<(K, K), Infinity<W>> = HashMap::new();
         let mut predecessor = HashMap::new();
-        for &v in &vertices {
-            for &w in &vertices {
-                cost_map.insert((v, w), Infinite);
+        for v in &vertices {
+            for w in &vertices {
+                cost_map.insert((v.clone(), w.clone()), Infinite);
                 if v == w {
-                    cost_map.insert((v, w), Number(0));
+                    cost_map.insert((v.clone(), w.clone()), Number(W::zero()));
                 }
             }
         }
         for e in g.all_edges() { //colocar os custos das arestas correspondentes
             let (v, w) = e.v_w();
-            cost_map.insert((v, w), Infinity::Number(e.weight()));
-            predecessor.insert((v, w), v);
+            cost_map.insert((v.clone(), w.clone()), Infinity::Number(e.weight()));
+            predecessor.insert((v.clone(), w), v);
         }
         MinPathTable {
             cost: cost_map,
-            predecessor: predecessor,
+            predecessor,
             g,
         }
     }
 
-    fn set_cost(&mut self, edge: (i32, i32), cost: Infinity) {
+    fn set_cost(&mut self, edge: (K, K), cost: Infinity<W>) {
         self.cost.insert(edge, cost);
     }
 
-    pub fn get_cost(&self, edge: (i32, i32)) -> Option<&Infinity> {
+    pub fn get_cost(&self, edge: (K, K)) -> Option<&Infinity<W>> {
         self.cost.get(&edge)
     }
 
-    fn set_predecessor(&mut self, edge: (i32, i32), predecessor: i32) {
+    fn set_predecessor(&mut self, edge: (K, K), predecessor: K) {
         self.predecessor.insert(edge, predecessor);
     }
-    pub fn get_predecessor(&self, edge: (i32, i32)) -> Option<&i32> {
+    pub fn get_predecessor(&self, edge: (K, K)) -> Option<&K> {
         self.predecessor.get(&edge)
     }
 
     ///  Finds the minor cust to all vertices to each other
-    /// # Example
-    /// ````
-    /// let graph = DiGraph::from_random(5, Some(19), true, true);
     ///
-    /// let min_path = MinPath::from_digraph(&graph);
-    ///
-    ///let vertices = graph.get_vertice_key_array();
-    ///for v in vertices.iter() {
-    ///  for w in vertices.iter() {
-    ///    println!(
-    ///      "Menor custo de {v} -> {w} = {}",
-    ///     min_path.get_cost((*v, *w))
-    ///    );
-    ///  }
-    // }
+    /// # Errors
+    /// `GraphError::NegativeCycle` if the graph has a negative cycle
+    /// # Example
+    /// ```
+    /// # use rust_graph::{graph_lib::minPath::floyd_warshall::MinPathTable, DiGraph};
+    /// let graph = DiGraph::from_random(5, Some(19), true, false);
     ///
+    /// let min_path = MinPathTable::from_digraph(&graph).unwrap();
     ///
+    /// let vertices = graph.get_vertice_key_array();
+    /// for v in vertices.iter() {
+    ///     for w in vertices.iter() {
+    ///         println!(
+    ///             "Menor custo de {v} -> {w} = {}",
+    ///             min_path.get_cost((*v, *w)).unwrap()
+    ///         );
+    ///     }
+    /// }
     /// ```
-    pub fn from_digraph(g: &'a DiGraph) -> Self {
+    pub fn from_digraph(g: &'a DiGraph<K, W>) -> Result<Self, GraphError> {
         let mut cost_map = MinPathTable::new(g);
-        let mut vertices = g.get_vertice_key_array();
-        vertices.sort();
-        for &k in vertices.iter() {
-            for &v in vertices.iter() {
-                for &w in vertices.iter() {
-                    let v_w_cost = *cost_map.get_cost((v, w)).unwrap();
-
-                    let v_k_w_cost =
-                        *cost_map.get_cost((v, k)).unwrap() + *cost_map.get_cost((k, w)).unwrap();
-                        
+        let vertices = g.get_vertice_key_array();
+        for k in vertices.iter() {
+            for v in vertices.iter() {
+                for w in vertices.iter() {
+                    let v_w_cost = *cost_map.get_cost((v.clone(), w.clone())).unwrap();
+
+                    let v_k_w_cost = *cost_map.get_cost((v.clone(), k.clone())).unwrap()
+                        + *cost_map.get_cost((k.clone(), w.clone())).unwrap();
+
                     if v_w_cost > v_k_w_cost {
-                        cost_map.set_cost((v, w), v_k_w_cost);
-                        
-                        let predecessor = *cost_map.get_predecessor((k,w)).unwrap();
-                        cost_map.set_predecessor((v, w),predecessor);        
+                        cost_map.set_cost((v.clone(), w.clone()), v_k_w_cost);
+
+                        let predecessor = cost_map
+                            .get_predecessor((k.clone(), w.clone()))
+                            .unwrap()
+                            .clone();
+                        cost_map.set_predecessor((v.clone(), w.clone()), predecessor);
                     }
                 }
             }
+            // Custo negativo de um vértice para ele mesmo indica ciclo negativo
+            if vertices
+                .iter()
+                .any(|v| *cost_map.get_cost((v.clone(), v.clone())).unwrap() < Number(W::zero()))
+            {
+                return Err(GraphError::NegativeCycle);
+            }
+        }
+        Ok(cost_map)
+    }
+
+    pub fn min_paths_from_v(&self, v: K) -> Vec<Edge<K, W>> {
+        // Obtém os vértices acessíveis do grafo, excluindo 'v' e vértices inacessíveis.
+        let reachable_vertices: Vec<K> = self
+            .g
+            .get_vertice_key_array()
+            .into_iter()
+            .filter(|vertice| {
+                *vertice != v
+                    && !self
+                        .get_cost((v.clone(), vertice.clone()))
+                        .unwrap()
+                        .is_infinite()
+            })
+            .collect();
+
+        let mut paths: Vec<Edge<K, W>> = Vec::new();
+        for w in reachable_vertices {
+            let pred = self.get_predecessor((v.clone(), w.clone())).unwrap().clone();
+            paths.push(Edge::new(pred, w));
         }
-        cost_map
+        paths
     }
+}
+
+impl<K: VerticeKey + Ord + Display, W: Weight> MinPathTable<'_, K, W> {
     ///creates a table from comfy_table crate
     pub fn to_table(&self) -> Table {
         // Cria uma tabela vazia
@@ -105,7 +140,7 @@ impl<'a> MinPathTable<'a> {
         table.set_content_arrangement(ContentArrangement::Dynamic);
 
         // Obtém os vértices do grafo
-        let mut vertices: Vec<i32> = self.g.get_vertice_key_array();
+        let mut vertices: Vec<K> = self.g.get_vertice_key_array();
         vertices.sort();
 
         // Define o cabeçalho da tabela com os vértices
@@ -119,7 +154,7 @@ impl<'a> MinPathTable<'a> {
         for v in &vertices {
             let mut row = vec![Cell::new(v.to_string()).fg(Color::Cyan)]; // Primeira coluna como rótulo da linha
             for w in &vertices {
-                let pair = (*v, *w);
+                let pair = (v.clone(), w.clone());
                 let cost = self.get_cost(pair).unwrap();
                 row.push(Cell::new(format!("{}", cost)));
             }
@@ -127,25 +162,38 @@ impl<'a> MinPathTable<'a> {
         }
         table
     }
+}
 
-    pub fn min_paths_from_v(&self, v: i32) -> Vec<Edge> {
-        // Obtém os vértices acessíveis do grafo, excluindo 'v' e vértices inacessíveis.
-        let reachable_vertices: Vec<i32> = self
-            .g
-            .get_vertice_key_array()
-            .iter()
-            .copied()
-            .filter(|&vertice| vertice != v && !self.get_cost((v, vertice)).unwrap().is_infinite())
-            .collect();
+/// Serialized as a list of `{ origin, destiny, cost, predecessor }` entries, one per pair
+/// of vertices (JSON maps can't have tuple keys).
+///
+/// There is no `Deserialize`: the table borrows the graph it was computed from.
+#[cfg(feature = "serde")]
+impl<K: VerticeKey + serde::Serialize, W: Weight + serde::Serialize> serde::Serialize for MinPathTable<'_, K, W> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::{SerializeSeq, SerializeStruct};
 
-        let mut paths: Vec<Edge> = Vec::new();
-        for w in reachable_vertices {
-            let pred = self.get_predecessor((v, w));
-            let pred = *pred.unwrap();
-            println!("v: {}, w: {} pred : {pred}", v, w);
-            paths.push(Edge::new(pred, w));
+        struct Entry<'t, K, W> {
+            pair: &'t (K, K),
+            cost: &'t Infinity<W>,
+            predecessor: Option<&'t K>,
+        }
+        impl<K: serde::Serialize, W: serde::Serialize> serde::Serialize for Entry<'_, K, W> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let mut entry = serializer.serialize_struct("Entry", 4)?;
+                entry.serialize_field("origin", &self.pair.0)?;
+                entry.serialize_field("destiny", &self.pair.1)?;
+                entry.serialize_field("cost", self.cost)?;
+                entry.serialize_field("predecessor", &self.predecessor)?;
+                entry.end()
+            }
         }
-        paths
-    }
 
+        let mut seq = serializer.serialize_seq(Some(self.cost.len()))?;
+        for (pair, cost) in self.cost.iter() {
+            let predecessor = self.predecessor.get(pair);
+            seq.serialize_element(&Entry { pair, cost, predecessor })?;
+        }
+        seq.end()
+    }
 }
@@ -1,8 +1,17 @@
+pub mod attributes;
+pub mod csr;
 pub mod edge;
+pub mod error;
 pub mod flux;
+pub mod formats;
+pub mod generators;
 pub mod graph;
+pub mod matching;
+pub mod matrix_graph;
+#[allow(non_snake_case)]
 pub mod minPath;
 pub mod search;
+pub mod ungraph;
 pub mod vertice;
 pub mod view;
 pub mod mst;
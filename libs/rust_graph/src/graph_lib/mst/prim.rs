@@ -1,98 +1,99 @@
 // Prim's algorithm for finding the minimum spanning tree of a graph
 
-use std::collections::HashSet;
+use std::{cmp::Ordering, collections::HashSet};
 
 use crate::{
-    graph_lib::edge::{self, Edge},
-    DiGraph,
+    graph_lib::{edge::Edge, vertice::VerticeKey},
+    tools::{heap::HeapMin, weight::Weight},
+    DiGraph, GraphError, UnGraph,
 };
 /// # Algoritmo de Prim
 ///
 /// Gera uma MST a partir de um vértice de um grafo direcionado
 ///
-/// Começa com um conjunto de vértice S = {v} e adiciona arestas mínimas que definem o corte de aresta entre S e G aumentando assim o conjunto S.
+/// O grafo é tratado como não direcionado (ver [`mst_from_ungraph`]) e as arestas
+/// da MST mantêm a direção que tinham no grafo original.
 ///
 ///
 /// No final, a MST não leva em conta a direção das arestas, apenas seu peso.
-pub fn mst_from_graph(graph: &DiGraph, v: i32) -> DiGraph {
-    let mut mst = DiGraph::new();
+/// A árvore sempre contém `v`, mesmo que ele seja isolado.
+/// ```
+/// # use rust_graph::{DiGraph, graph_lib::mst::prim::mst_from_graph};
+/// let graph: DiGraph = DiGraph::from_edge_list("3 1\n0 1 4\n").unwrap();
+/// let mst = mst_from_graph(&graph, 2).unwrap();
+/// assert_eq!((mst.vertices_length(), mst.edges_length()), (1, 0));
+/// ```
+///
+/// # Errors
+/// `GraphError::MissingVertex` se `v` não existir no grafo
+pub fn mst_from_graph<K: VerticeKey, W: Weight>(
+    graph: &DiGraph<K, W>,
+    v: K,
+) -> Result<DiGraph<K, W>, GraphError> {
+    let mst = mst_from_ungraph(&UnGraph::from_digraph(graph), v.clone())?;
+    let mut tree = DiGraph::from_edges(mst.all_edges());
+    tree.add_vertice(v);
+    Ok(tree)
+}
 
-    // Holds the vertices that are in the minimum spanning tree
-    let mut v_in_mst: HashSet<i32> = HashSet::new();
-    v_in_mst.insert(v);
+/// # Algoritmo de Prim em grafo não direcionado
+///
+/// Começa com um conjunto de vértice S = {v} e adiciona arestas mínimas que definem o corte de aresta entre S e G aumentando assim o conjunto S.
+///
+/// Gera a árvore geradora mínima da componente conexa que contém `v`.
+///
+/// # Errors
+/// `GraphError::MissingVertex` se `v` não existir no grafo
+pub fn mst_from_ungraph<K: VerticeKey, W: Weight>(
+    graph: &UnGraph<K, W>,
+    v: K,
+) -> Result<UnGraph<K, W>, GraphError> {
+    let mut mst = UnGraph::new();
+    if !graph.vertice_exists(v.clone()) {
+        return Err(GraphError::missing_vertex(&v));
+    }
+    mst.add_vertice(v.clone());
 
-    // Holds the edges that are in the mst
-    let mut edges_in_mst: HashSet<Edge> = HashSet::new();
+    // Holds the vertices that are in the minimum spanning tree
+    let mut v_in_mst: HashSet<K> = HashSet::new();
+    v_in_mst.insert(v.clone());
 
-    let total_vertices = graph.vertices_length();
+    // Arestas do corte, ordenadas pelo peso
+    let mut queue = HeapMin::new(|a: &Edge<K, W>, b: &Edge<K, W>| {
+        a.weight().partial_cmp(&b.weight()).unwrap_or(Ordering::Equal)
+    });
+    for e in graph.edges_of(v).into_iter().flatten() {
+        queue.insert(e);
+    }
 
-    while v_in_mst.len() < total_vertices {
-        let mut edges_to_add = Vec::new();
-        for &v in v_in_mst.iter() {
-            // Odeio thread safety, nem sei por que inventei de fazer essa porra
-            let vertice = graph.get_vertice_arc(v).unwrap();
+    while let Some(min_edge) = queue.pop() {
+        let (origin, destiny) = min_edge.v_w();
 
-            let (forward_edges, back_edges) = vertice.get_all_edges_tuple();
+        // Adiciona o novo vértice à MST, descartando arestas que não cruzam mais o corte
+        let new_vertice = match (v_in_mst.contains(&origin), v_in_mst.contains(&destiny)) {
+            (true, false) => destiny,
+            (false, true) => origin,
+            _ => continue,
+        };
 
-            for e in forward_edges {
-                if !edges_in_mst.contains(&e) && !v_in_mst.contains(&e.destiny_key()) {
-                    edges_to_add.push(e.clone());
-                } else {
-                }
-            }
-            for e in back_edges {
-                if !edges_in_mst.contains(&e) && !v_in_mst.contains(&e.origin_key()) {
-                    edges_to_add.push(e.clone());
-                } else {
-                }
+        for e in graph.edges_of(new_vertice.clone()).into_iter().flatten() {
+            if !v_in_mst.contains(UnGraph::other_end(&e, &new_vertice)) {
+                queue.insert(e);
             }
         }
-
-        if edges_to_add.is_empty() {
-            break;
-        }
-
-        let min_edge = get_minimum_edge(&edges_to_add);
-
-        edges_in_mst.insert(min_edge.clone());
-        mst.add_edge(min_edge.clone());
-
-        // Adiciona o novo vértice à MST
-        if v_in_mst.contains(&min_edge.origin_key()) {
-            min_edge.destiny_key()
-        } else {
-            min_edge.origin_key()
-        };
+        v_in_mst.insert(new_vertice);
+        mst.add_edge(min_edge);
     }
 
-    println!("Construção da MST concluída.");
-    println!("Total de vértices na MST: {}", mst.vertices_length());
-    println!("Total de arestas na MST: {}", mst.edges_length());
-    mst
+    Ok(mst)
 }
 
-pub fn get_minimum_edge(edges: &Vec<Edge>) -> Edge {
-    println!(
-        "Buscando a aresta com o menor peso dentre {} arestas candidatas.",
-        edges.len()
-    );
+pub fn get_minimum_edge<K: VerticeKey, W: Weight>(edges: &[Edge<K, W>]) -> Edge<K, W> {
     let mut min = &edges[0];
     for e in edges.iter().skip(1) {
         if e.weight() < min.weight() {
-            println!(
-                "Aresta {:?} tem peso menor ({}) do que a aresta atual mínima {:?} (Peso: {}).",
-                e,
-                e.weight(),
-                min,
-                min.weight()
-            );
             min = e;
         }
     }
-    println!(
-        "Aresta mínima encontrada: {:?} (Peso: {})",
-        min,
-        min.weight()
-    );
     min.clone()
 }
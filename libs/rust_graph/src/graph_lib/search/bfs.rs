@@ -0,0 +1,209 @@
+use std::collections::HashMap;
+
+use crate::{
+    graph_lib::{edge::Edge, vertice::VerticeKey},
+    tools::weight::Weight,
+    DiGraph, UnGraph,
+};
+
+/// Estrutura destinada a armazenar o resultado da busca em largura
+///
+/// A distância de um vértice é a quantidade de arestas do menor caminho (sem pesos) entre
+/// alguma das origens e ele.
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(
+        serialize = "K: VerticeKey + serde::Serialize",
+        deserialize = "K: VerticeKey + serde::Deserialize<'de>"
+    ))
+)]
+pub struct BfsStruct<K = i32> {
+    pub distances: HashMap<K, usize>,
+    /// Pai de cada vértice descoberto na árvore da busca (as origens não têm pai)
+    pub fathers: HashMap<K, K>,
+    /// `layers[d]` são os vértices a distância `d`, na ordem em que foram descobertos
+    pub layers: Vec<Vec<K>>,
+    /// Alvo que interrompeu a busca, se ele foi alcançado
+    pub stopped_at: Option<K>,
+}
+
+impl<K: VerticeKey> Default for BfsStruct<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[allow(unused)]
+impl<K: VerticeKey> BfsStruct<K> {
+    pub fn new() -> BfsStruct<K> {
+        BfsStruct {
+            distances: HashMap::new(),
+            fathers: HashMap::new(),
+            layers: Vec::new(),
+            stopped_at: None,
+        }
+    }
+
+    /// Origens da busca (a camada 0)
+    pub fn sources(&self) -> &[K] {
+        self.layers.first().map_or(&[], |layer| layer.as_slice())
+    }
+
+    pub fn distance(&self, vertice_key: &K) -> Option<usize> {
+        self.distances.get(vertice_key).copied()
+    }
+
+    pub fn father(&self, vertice_key: &K) -> Option<&K> {
+        self.fathers.get(vertice_key)
+    }
+
+    /// Checa se um vértice foi alcançado a partir de alguma origem
+    pub fn already_visited(&self, vertice_key: &K) -> bool {
+        self.distances.contains_key(vertice_key)
+    }
+
+    /// Caminho com menos arestas de uma das origens até `target`, seguindo os pais
+    ///
+    /// ## Retorna
+    /// As chaves do caminho, começando na origem e terminando em `target`, ou `None` se
+    /// `target` não foi alcançado
+    pub fn path_to(&self, target: &K) -> Option<Vec<K>> {
+        if !self.already_visited(target) {
+            return None;
+        }
+        let mut path = vec![target.clone()];
+        let mut current = target;
+        while let Some(father) = self.fathers.get(current) {
+            path.push(father.clone());
+            current = father;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Árvore (floresta, com várias origens) da busca: um arco `pai -> filho` de peso 1 para
+    /// cada vértice descoberto
+    pub fn tree<W: Weight>(&self) -> DiGraph<K, W> {
+        let mut tree = DiGraph::new_sized(self.distances.len() as u32);
+        for vertice_key in self.layers.iter().flatten() {
+            tree.add_vertice(vertice_key.clone());
+            if let Some(father) = self.fathers.get(vertice_key) {
+                tree.add_edge(Edge::new(father.clone(), vertice_key.clone()));
+            }
+        }
+        tree
+    }
+
+    /// Busca em largura a partir de todas as `sources` ao mesmo tempo
+    ///
+    /// `neighbours` retorna os vizinhos de um vértice, na ordem em que devem ser visitados.
+    /// A busca para assim que `target` é descoberto.
+    fn search(sources: &[K], target: Option<&K>, neighbours: impl Fn(&K) -> Option<Vec<K>>) -> BfsStruct<K> {
+        let mut bfs = BfsStruct::new();
+        let mut layer = Vec::new();
+        for source in sources {
+            if neighbours(source).is_some() && !bfs.already_visited(source) {
+                bfs.distances.insert(source.clone(), 0);
+                layer.push(source.clone());
+            }
+        }
+        if let Some(target) = target.filter(|t| bfs.already_visited(t)) {
+            bfs.stopped_at = Some(target.clone());
+        }
+
+        while !layer.is_empty() && bfs.stopped_at.is_none() {
+            let distance = bfs.layers.len() + 1;
+            let mut next = Vec::new();
+            'layer: for vertice_key in layer.iter() {
+                for neighbour in neighbours(vertice_key).into_iter().flatten() {
+                    if bfs.already_visited(&neighbour) {
+                        continue;
+                    }
+                    bfs.distances.insert(neighbour.clone(), distance);
+                    bfs.fathers.insert(neighbour.clone(), vertice_key.clone());
+                    next.push(neighbour.clone());
+                    if target == Some(&neighbour) {
+                        bfs.stopped_at = Some(neighbour);
+                        break 'layer;
+                    }
+                }
+            }
+            bfs.layers.push(layer);
+            layer = next;
+        }
+        if !layer.is_empty() {
+            bfs.layers.push(layer);
+        }
+        bfs
+    }
+}
+
+#[allow(unused)]
+impl<K: VerticeKey, W: Weight> DiGraph<K, W> {
+    /// Busca em largura a partir de `search_key`, seguindo os arcos
+    /// ```
+    /// # use rust_graph::DiGraph;
+    /// let graph: DiGraph = DiGraph::from_edge_list("5 5\n0 1\n1 2\n0 3\n3 2\n2 4").unwrap();
+    /// let bfs = graph.bfs_search(0);
+    /// assert_eq!(bfs.distance(&4), Some(3));
+    /// assert_eq!(bfs.layers, vec![vec![0], vec![1, 3], vec![2], vec![4]]);
+    /// assert_eq!(bfs.path_to(&4), Some(vec![0, 1, 2, 4]));
+    /// ```
+    pub fn bfs_search(&self, search_key: K) -> BfsStruct<K> {
+        self.bfs_search_with(&[search_key], None)
+    }
+
+    /// Busca em largura com várias origens, parando assim que `target` for descoberto
+    ///
+    /// As distâncias são até a origem mais próxima. Os arcos de cada vértice são explorados
+    /// na ordem em que foram inseridos no grafo; origens que não estão no grafo são ignoradas.
+    /// Com `target` a última camada pode ficar incompleta.
+    pub fn bfs_search_with(&self, sources: &[K], target: Option<&K>) -> BfsStruct<K> {
+        BfsStruct::search(sources, target, |vertice_key| {
+            let mut arestas = self.edges_of(vertice_key.clone())?;
+            arestas.sort_by_key(|a| a.id());
+            Some(arestas.into_iter().map(|a| a.destiny_key()).collect())
+        })
+    }
+
+    /// Caminho com menos arcos de `origin` até `destiny`, ignorando os pesos
+    pub fn shortest_unweighted_path(&self, origin: K, destiny: K) -> Option<Vec<K>> {
+        self.bfs_search_with(&[origin], Some(&destiny)).path_to(&destiny)
+    }
+}
+
+#[allow(unused)]
+impl<K: VerticeKey, W: Weight> UnGraph<K, W> {
+    /// Busca em largura a partir de `search_key`
+    pub fn bfs_search(&self, search_key: K) -> BfsStruct<K> {
+        self.bfs_search_with(&[search_key], None)
+    }
+
+    /// Busca em largura com várias origens, parando assim que `target` for descoberto
+    ///
+    /// Mesmo comportamento de [`DiGraph::bfs_search_with`].
+    pub fn bfs_search_with(&self, sources: &[K], target: Option<&K>) -> BfsStruct<K> {
+        BfsStruct::search(sources, target, |vertice_key| {
+            let mut arestas = self.edges_of(vertice_key.clone())?;
+            arestas.sort_by_key(|a| a.id());
+            Some(
+                arestas
+                    .iter()
+                    .map(|a| UnGraph::other_end(a, vertice_key).clone())
+                    .collect(),
+            )
+        })
+    }
+
+    /// Caminho com menos arestas entre `origin` e `destiny`, ignorando os pesos
+    /// ```
+    /// # use rust_graph::UnGraph;
+    /// let cycle: UnGraph = UnGraph::new_cycle(6);
+    /// assert_eq!(cycle.shortest_unweighted_path(0, 4), Some(vec![0, 5, 4]));
+    /// ```
+    pub fn shortest_unweighted_path(&self, origin: K, destiny: K) -> Option<Vec<K>> {
+        self.bfs_search_with(&[origin], Some(&destiny)).path_to(&destiny)
+    }
+}
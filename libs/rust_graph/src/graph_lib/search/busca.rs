@@ -1,14 +1,17 @@
-use std::{
-    borrow::BorrowMut,
-    cell::{Ref, RefCell},
-    collections::HashMap,
-    rc::Rc,
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use crate::{
+    graph_lib::{
+        edge::{Edge, EdgeId},
+        graph::DiGraph,
+        vertice::VerticeKey,
+    },
+    tools::weight::Weight,
 };
 
-use crate::graph_lib::{edge::Edge, graph::DiGraph};
-
 // Busca em profundidade
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EdgeClassification {
     Arvore,
     Retorno,
@@ -32,23 +35,34 @@ impl EdgeClassification {
     }
 }
 
+/// Árvore de busca compartilhada entre o `DfsStruct` e seus consumidores
+pub type DfsTree<K = i32, W = i32> = Rc<RefCell<DiGraph<K, W>>>;
+
 /// Estrutura destinada a armazenar o resultado da busca em profundidade
 #[derive(Clone, Debug)]
-pub struct DfsStruct {
-    pub tempo_descoberta: HashMap<i32, i32>,
-    pub tempo_termino: HashMap<i32, i32>,
-    pub fathers: HashMap<i32, i32>,
-    pub class_arestas: HashMap<Edge, EdgeClassification>,
-    pub arestas_marked: HashMap<i32, bool>,
-    pub trees: Vec<Rc<RefCell<DiGraph>>>,
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(
+        serialize = "K: VerticeKey + serde::Serialize, W: Weight + serde::Serialize",
+        deserialize = "K: VerticeKey + serde::Deserialize<'de>, W: Weight + serde::Deserialize<'de>"
+    ))
+)]
+pub struct DfsStruct<K = i32, W = i32> {
+    pub tempo_descoberta: HashMap<K, i32>,
+    pub tempo_termino: HashMap<K, i32>,
+    pub fathers: HashMap<K, K>,
+    pub class_arestas: HashMap<EdgeId, EdgeClassification>,
+    pub arestas_marked: HashMap<EdgeId, bool>,
+    pub trees: Vec<DfsTree<K, W>>,
     clock: i32,
 }
 
 #[allow(unused)]
-impl DfsStruct {
-    pub fn new(g: &DiGraph) -> DfsStruct {
-        let v_len = g.vertices_length() as usize;
-        let e_len = g.edges_length() as usize;
+impl<K: VerticeKey, W: Weight> DfsStruct<K, W> {
+    pub fn new(g: &DiGraph<K, W>) -> DfsStruct<K, W> {
+        let v_len = g.vertices_length();
+        let e_len = g.edges_length();
         DfsStruct {
             tempo_descoberta: HashMap::with_capacity(v_len),
             tempo_termino: HashMap::with_capacity(v_len),
@@ -60,7 +74,7 @@ impl DfsStruct {
         }
     }
 
-    pub fn start_exploring(&mut self, vertice_key: i32) {
+    pub fn start_exploring(&mut self, vertice_key: K) {
         self.tempo_descoberta.insert(vertice_key, self.clock);
 
         self.clock += 1;
@@ -68,21 +82,19 @@ impl DfsStruct {
 
     /// Finaliza a exploração de um vértice marcando o tempo de término e incrementando o clock
     /// `vertice_k` - chave do vértice a ser finalizado
-    pub fn finish_exploring(&mut self, vertice_k: i32) {
+    pub fn finish_exploring(&mut self, vertice_k: K) {
         self.tempo_termino.insert(vertice_k, self.clock);
 
         self.clock += 1;
     }
 
-    /// Retorna a chave de um vértice ainda não explorado
+    /// Retorna a chave de um vértice ainda não explorado, se houver
     /// `key_array` - array de chaves de vértices a serem verificados
-    pub fn get_unexplored_vertice(&self, key_array: &Vec<i32>) -> i32 {
-        for key in key_array {
-            if self.tempo_descoberta.get(&key).is_none() {
-                return *key;
-            }
-        }
-        -1
+    pub fn get_unexplored_vertice(&self, key_array: &[K]) -> Option<K> {
+        key_array
+            .iter()
+            .find(|key| !self.tempo_descoberta.contains_key(key))
+            .cloned()
     }
 
     /// Checa se uma aresta já foi explorada
@@ -90,8 +102,8 @@ impl DfsStruct {
     ///
     /// ## Retorna
     /// `true` se a aresta já foi classificada, `false` caso contrário
-    pub fn is_aresta_marked(&self, aresta_id: i32) -> bool {
-        self.arestas_marked.get(&aresta_id).is_some()
+    pub fn is_aresta_marked(&self, aresta_id: EdgeId) -> bool {
+        self.arestas_marked.contains_key(&aresta_id)
     }
 
     /// Checa se um vértice já foi visitado
@@ -99,8 +111,8 @@ impl DfsStruct {
     /// Um vértice é considerado visitado se o tempo de descoberta já foi marcado
     ///
     /// `vertice_key` - chave do vértice a ser verificado
-    pub fn already_visited(&self, vertice_key: i32) -> bool {
-        self.tempo_descoberta.get(&vertice_key).is_some()
+    pub fn already_visited(&self, vertice_key: &K) -> bool {
+        self.tempo_descoberta.contains_key(vertice_key)
     }
 
     /// Checa se um vértice já foi explorado
@@ -108,12 +120,12 @@ impl DfsStruct {
     /// Um vértice é considerado explorado se o tempo de término já foi marcado
     ///
     /// `vertice_key` - chave do vértice a ser verificado
-    pub fn already_explored(&self, vertice_key: i32) -> bool {
-        self.tempo_termino.get(&vertice_key).is_some()
+    pub fn already_explored(&self, vertice_key: &K) -> bool {
+        self.tempo_termino.contains_key(vertice_key)
     }
 
-    pub fn classificate_aresta(&mut self, aresta: &Edge, class: EdgeClassification) {
-        self.class_arestas.insert(aresta.clone(), class.clone());
+    pub fn classificate_aresta(&mut self, aresta: &Edge<K, W>, class: EdgeClassification) {
+        self.class_arestas.insert(aresta.id(), class.clone());
 
         if class.is_arvore() {
             let (v, w) = aresta.v_w();
@@ -122,23 +134,23 @@ impl DfsStruct {
     }
 
     /// Obtém todas as raízes de uma busca em profundidade
-    pub fn get_roots(&self) -> Vec<i32> {
-        let mut roots: Vec<i32> = vec![];
-        for (vertice, _) in self.tempo_descoberta.iter() {
-            if self.fathers.get(vertice).is_none() {
-                roots.push(*vertice);
+    pub fn get_roots(&self) -> Vec<K> {
+        let mut roots: Vec<K> = vec![];
+        for vertice in self.tempo_descoberta.keys() {
+            if !self.fathers.contains_key(vertice) {
+                roots.push(vertice.clone());
             }
         }
         roots
     }
 
-    pub fn add_tree_edge(&mut self, origin_vert: i32, destiny_vert: i32) {
-        let trees: &mut Vec<Rc<RefCell<DiGraph>>> = self.trees.borrow_mut();
+    pub fn add_tree_edge(&mut self, origin_vert: K, destiny_vert: K) {
+        let trees = &mut self.trees;
 
         // Primeiro, tente encontrar uma árvore que já contenha o vértice de origem
         for tree in trees.iter_mut() {
             let mut tree_mut = tree.try_borrow_mut().unwrap();
-            if tree_mut.vertice_exists(origin_vert) {
+            if tree_mut.vertice_exists(origin_vert.clone()) {
                 tree_mut.add_edge(Edge::new(origin_vert, destiny_vert));
 
                 return;
@@ -152,81 +164,267 @@ impl DfsStruct {
         trees.push(Rc::new(RefCell::new(new_tree)));
     }
 
-    pub fn add_root(&mut self, root: i32) {
-        let trees: &mut Vec<Rc<RefCell<DiGraph>>> = self.trees.borrow_mut();
+    pub fn add_root(&mut self, root: K) {
+        let trees = &mut self.trees;
         let mut new_tree = DiGraph::new();
         new_tree.add_vertice(root);
         trees.push(Rc::new(RefCell::new(new_tree)));
     }
 }
 
-pub trait DeepFirstSearch {
-    fn DeepFirstSearch(&self, start_vertice: i32, data: &mut DfsStruct);
+/// O que a busca em profundidade deve fazer depois de um evento do `DfsVisitor`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DfsControl {
+    /// Segue normalmente
+    Continue,
+    /// Em `discover_vertex`, não explora as arestas do vértice (ele é finalizado em seguida);
+    /// em `tree_edge`, não desce pela aresta e o destino continua não descoberto.
+    /// Nos demais eventos equivale a `Continue`
+    Prune,
+    /// Interrompe a busca inteira imediatamente, sem finalizar os vértices da pilha
+    Stop,
 }
 
-/// Implementação de busca em profundidade
-impl DeepFirstSearch for DiGraph {
-    fn DeepFirstSearch(&self, search_key: i32, dfs_data: &mut DfsStruct) {
-        let mut stack: Vec<i32> = Vec::new();
-        stack.push(search_key);
-        dfs_data.add_root(search_key);
-
-        while let Some(&vertice_key) = stack.last() {
-            if !dfs_data.already_visited(vertice_key) {
-                dfs_data.start_exploring(vertice_key);
-            } else {
-            }
+/// Eventos da busca em profundidade de [`DiGraph::dfs_visit`]
+///
+/// Todos os métodos têm implementação padrão que retorna `DfsControl::Continue`, então
+/// basta implementar os eventos de interesse. Exemplo de detecção de ciclo:
+/// ```
+/// # use rust_graph::{DiGraph, graph_lib::{edge::Edge, search::busca::{DfsControl, DfsVisitor}}};
+/// struct CycleFinder(bool);
+///
+/// impl DfsVisitor for CycleFinder {
+///     fn back_edge(&mut self, _edge: &Edge) -> DfsControl {
+///         self.0 = true;
+///         DfsControl::Stop
+///     }
+/// }
+///
+/// let graph: DiGraph = DiGraph::from_edge_list("3 3\n0 1\n1 2\n2 0").unwrap();
+/// let mut finder = CycleFinder(false);
+/// graph.dfs_visit(&graph.get_vertice_key_array(), &mut finder);
+/// assert!(finder.0);
+/// ```
+#[allow(unused_variables)]
+pub trait DfsVisitor<K = i32, W = i32> {
+    /// O vértice foi alcançado pela primeira vez
+    fn discover_vertex(&mut self, vertice_key: &K) -> DfsControl {
+        DfsControl::Continue
+    }
 
-            let arestas = self.edges_of(vertice_key);
+    /// Todas as arestas do vértice foram exploradas
+    fn finish_vertex(&mut self, vertice_key: &K) -> DfsControl {
+        DfsControl::Continue
+    }
 
-            let Some(mut arestas) = arestas else {
-                dfs_data.finish_exploring(vertice_key);
-                stack.pop();
-                continue;
-            };
+    /// A aresta leva a um vértice ainda não descoberto (chamado antes de descobri-lo)
+    fn tree_edge(&mut self, edge: &Edge<K, W>) -> DfsControl {
+        DfsControl::Continue
+    }
 
-            arestas.sort_by(|a, b| a.destiny_key().cmp(&b.destiny_key()));
+    /// A aresta leva a um ancestral ainda na pilha (inclui laços)
+    fn back_edge(&mut self, edge: &Edge<K, W>) -> DfsControl {
+        DfsControl::Continue
+    }
 
-            let mut descobriu_vertice = false;
+    /// A aresta leva a um descendente já finalizado
+    fn forward_edge(&mut self, edge: &Edge<K, W>) -> DfsControl {
+        DfsControl::Continue
+    }
 
-            for aresta in arestas.iter() {
-                if dfs_data.is_aresta_marked(aresta.id() as i32) {
-                    continue; // Aresta já classificada
-                }
-                dfs_data.arestas_marked.insert(aresta.id() as i32, true); // Marca a aresta que está sendo explorada
-
-                if !dfs_data.already_visited(aresta.destiny_key()) {
-                    // Não foi descoberto ainda, árvore
-                    dfs_data.fathers.insert(aresta.destiny_key(), vertice_key);
-                    stack.push(aresta.destiny_key()); // Empilha o vértice
-                    dfs_data.classificate_aresta(&aresta, EdgeClassification::Arvore);
-                    descobriu_vertice = true;
-                    break;
-                }
-                if !dfs_data.already_explored(aresta.destiny_key()) {
-                    // Se ainda não finalizou, é retorno
-                    dfs_data.classificate_aresta(&aresta, EdgeClassification::Retorno);
-                } else {
-                    let vertice_descoberta =
-                        dfs_data.tempo_descoberta.get(&vertice_key).unwrap_or(&-1);
-                    let destino_descoberta = dfs_data
-                        .tempo_descoberta
-                        .get(&aresta.destiny_key())
-                        .unwrap_or(&-1);
-                    if vertice_descoberta < destino_descoberta {
-                        // Se já finalizou a busca, mas ele é mais novo que o vertice_key, é avanço
-                        dfs_data.classificate_aresta(&aresta, EdgeClassification::Avanco);
-                    } else {
-                        // Se já finalizou a busca, mas ele é mais velho que o vertice_key, é cruzamento
-                        dfs_data.classificate_aresta(&aresta, EdgeClassification::Cruzamento);
-                    }
+    /// A aresta leva a um vértice já finalizado de outro ramo ou de outra árvore
+    fn cross_edge(&mut self, edge: &Edge<K, W>) -> DfsControl {
+        DfsControl::Continue
+    }
+}
+
+/// Tempos de descoberta e de término compartilhados pelas árvores de uma mesma busca
+struct DfsClock<K> {
+    discovery: HashMap<K, i32>,
+    finish: HashMap<K, i32>,
+    clock: i32,
+}
+
+impl<K: VerticeKey> DfsClock<K> {
+    fn tick(&mut self) -> i32 {
+        self.clock += 1;
+        self.clock - 1
+    }
+}
+
+/// Estado de um vértice na pilha da busca em profundidade
+struct DfsFrame<K, W> {
+    vertice: K,
+    edges: Vec<Edge<K, W>>,
+    next: usize,
+}
+
+/// Busca em profundidade a partir de `root`, avisando `visitor` de cada evento
+///
+/// As arestas de cada vértice são exploradas na ordem em que foram inseridas no grafo.
+fn traverse<K: VerticeKey, W: Weight, V: DfsVisitor<K, W> + ?Sized>(
+    graph: &DiGraph<K, W>,
+    root: K,
+    times: &mut DfsClock<K>,
+    visitor: &mut V,
+) -> DfsControl {
+    if times.discovery.contains_key(&root) {
+        return DfsControl::Continue;
+    }
+    let mut stack: Vec<DfsFrame<K, W>> = Vec::new();
+    let mut discovered = Some(root);
+
+    loop {
+        if let Some(vertice) = discovered.take() {
+            let time = times.tick();
+            times.discovery.insert(vertice.clone(), time);
+            let edges = match visitor.discover_vertex(&vertice) {
+                DfsControl::Stop => return DfsControl::Stop,
+                DfsControl::Prune => Vec::new(),
+                DfsControl::Continue => {
+                    let mut arestas = graph.edges_of(vertice.clone()).unwrap_or_default();
+                    arestas.sort_by_key(|a| a.id());
+                    arestas
                 }
+            };
+            stack.push(DfsFrame {
+                vertice,
+                edges,
+                next: 0,
+            });
+        }
+
+        let Some(frame) = stack.last_mut() else {
+            return DfsControl::Continue;
+        };
+        let Some(aresta) = frame.edges.get(frame.next).cloned() else {
+            let time = times.tick();
+            times.finish.insert(frame.vertice.clone(), time);
+            if visitor.finish_vertex(&frame.vertice) == DfsControl::Stop {
+                return DfsControl::Stop;
+            }
+            stack.pop();
+            continue;
+        };
+        frame.next += 1;
+
+        let destino = aresta.destiny_key_ref();
+        let control = if !times.discovery.contains_key(destino) {
+            let control = visitor.tree_edge(&aresta);
+            if control == DfsControl::Continue {
+                discovered = Some(aresta.destiny_key());
             }
+            control
+        } else if !times.finish.contains_key(destino) {
+            visitor.back_edge(&aresta)
+        } else if times.discovery[&frame.vertice] < times.discovery[destino] {
+            visitor.forward_edge(&aresta)
+        } else {
+            visitor.cross_edge(&aresta)
+        };
+        if control == DfsControl::Stop {
+            return DfsControl::Stop;
+        }
+    }
+}
 
-            if !descobriu_vertice {
-                dfs_data.finish_exploring(vertice_key);
-                stack.pop();
+#[allow(unused)]
+impl<K: VerticeKey, W: Weight> DiGraph<K, W> {
+    /// Busca em profundidade guiada por um `DfsVisitor`
+    ///
+    /// Cada vértice de `roots` ainda não descoberto, em ordem, é raiz de uma nova árvore; para
+    /// percorrer o grafo inteiro basta passar `get_vertice_key_array()`. Exemplo de ordem
+    /// topológica (vértices em ordem decrescente de término):
+    /// ```
+    /// # use rust_graph::{DiGraph, graph_lib::search::busca::{DfsControl, DfsVisitor}};
+    /// struct Order(Vec<i32>);
+    ///
+    /// impl DfsVisitor for Order {
+    ///     fn finish_vertex(&mut self, vertice_key: &i32) -> DfsControl {
+    ///         self.0.push(*vertice_key);
+    ///         DfsControl::Continue
+    ///     }
+    /// }
+    ///
+    /// let graph: DiGraph = DiGraph::from_edge_list("4 4\n2 0\n0 1\n3 2\n3 1").unwrap();
+    /// let mut order = Order(Vec::new());
+    /// graph.dfs_visit(&[0, 1, 2, 3], &mut order);
+    /// order.0.reverse();
+    /// assert_eq!(order.0, vec![3, 2, 0, 1]);
+    /// ```
+    ///
+    /// ## Retorna
+    /// `DfsControl::Stop` se algum evento interrompeu a busca, `DfsControl::Continue` caso contrário
+    pub fn dfs_visit<V: DfsVisitor<K, W> + ?Sized>(&self, roots: &[K], visitor: &mut V) -> DfsControl {
+        let mut times = DfsClock {
+            discovery: HashMap::with_capacity(self.vertices_length()),
+            finish: HashMap::with_capacity(self.vertices_length()),
+            clock: 0,
+        };
+        for root in roots {
+            if traverse(self, root.clone(), &mut times, visitor) == DfsControl::Stop {
+                return DfsControl::Stop;
             }
         }
+        DfsControl::Continue
+    }
+}
+
+/// Registra no `DfsStruct` os pais, a classificação das arestas e as árvores da busca
+struct DfsRecorder<'a, K, W>(&'a mut DfsStruct<K, W>);
+
+impl<K: VerticeKey, W: Weight> DfsRecorder<'_, K, W> {
+    fn record(&mut self, aresta: &Edge<K, W>, class: EdgeClassification) -> DfsControl {
+        self.0.arestas_marked.insert(aresta.id(), true);
+        self.0.classificate_aresta(aresta, class);
+        DfsControl::Continue
+    }
+}
+
+impl<K: VerticeKey, W: Weight> DfsVisitor<K, W> for DfsRecorder<'_, K, W> {
+    fn tree_edge(&mut self, aresta: &Edge<K, W>) -> DfsControl {
+        self.0.fathers.insert(aresta.destiny_key(), aresta.origin_key());
+        self.record(aresta, EdgeClassification::Arvore)
+    }
+
+    fn back_edge(&mut self, aresta: &Edge<K, W>) -> DfsControl {
+        self.record(aresta, EdgeClassification::Retorno)
+    }
+
+    fn forward_edge(&mut self, aresta: &Edge<K, W>) -> DfsControl {
+        self.record(aresta, EdgeClassification::Avanco)
+    }
+
+    fn cross_edge(&mut self, aresta: &Edge<K, W>) -> DfsControl {
+        self.record(aresta, EdgeClassification::Cruzamento)
+    }
+}
+
+#[allow(non_snake_case)]
+pub trait DeepFirstSearch<K = i32, W = i32> {
+    fn DeepFirstSearch(&self, start_vertice: K, data: &mut DfsStruct<K, W>);
+}
+
+/// Implementação de busca em profundidade
+///
+/// As arestas de cada vértice são exploradas na ordem em que foram inseridas no grafo.
+/// Os tempos continuam os de `dfs_data`, então chamadas sucessivas formam uma única busca.
+impl<K: VerticeKey, W: Weight> DeepFirstSearch<K, W> for DiGraph<K, W> {
+    fn DeepFirstSearch(&self, search_key: K, dfs_data: &mut DfsStruct<K, W>) {
+        if dfs_data.already_visited(&search_key) {
+            return;
+        }
+        dfs_data.add_root(search_key.clone());
+
+        // Os tempos ficam com a busca enquanto ela roda e voltam para o `dfs_data` no final
+        let mut times = DfsClock {
+            discovery: std::mem::take(&mut dfs_data.tempo_descoberta),
+            finish: std::mem::take(&mut dfs_data.tempo_termino),
+            clock: dfs_data.clock,
+        };
+        traverse(self, search_key, &mut times, &mut DfsRecorder(dfs_data));
+        dfs_data.tempo_descoberta = times.discovery;
+        dfs_data.tempo_termino = times.finish;
+        dfs_data.clock = times.clock;
     }
 }
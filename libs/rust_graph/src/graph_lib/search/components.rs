@@ -0,0 +1,111 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    graph_lib::{edge::Edge, vertice::VerticeKey},
+    tools::weight::Weight,
+    UnGraph,
+};
+
+/// Componentes conexas de um grafo não direcionado
+///
+/// Cada componente é representada pelo vetor das chaves de seus vértices.
+pub fn connected_components<K: VerticeKey, W: Weight>(graph: &UnGraph<K, W>) -> Vec<Vec<K>> {
+    let mut visited: HashSet<K> = HashSet::with_capacity(graph.vertices_length());
+    let mut components = vec![];
+
+    for root in graph.get_vertice_key_array() {
+        if !visited.insert(root.clone()) {
+            continue;
+        }
+        let mut component = vec![];
+        let mut stack = vec![root];
+        while let Some(vertice_key) = stack.pop() {
+            for neighbour in graph.neighbours(vertice_key.clone()).into_iter().flatten() {
+                if visited.insert(neighbour.clone()) {
+                    stack.push(neighbour);
+                }
+            }
+            component.push(vertice_key);
+        }
+        components.push(component);
+    }
+    components
+}
+
+/// Estado de um vértice na pilha da busca de pontes
+struct BridgeFrame<K, W> {
+    vertice: K,
+    /// aresta de árvore usada para chegar ao vértice
+    parent_edge: Option<Edge<K, W>>,
+    edges: Vec<Edge<K, W>>,
+    next: usize,
+}
+
+/// Pontes de um grafo não direcionado (algoritmo de Tarjan)
+///
+/// Uma ponte é uma aresta cuja remoção aumenta o número de componentes conexas.
+///
+/// Arestas paralelas nunca são pontes, pois a busca distingue as arestas pelo `id`.
+pub fn bridges<K: VerticeKey, W: Weight>(graph: &UnGraph<K, W>) -> Vec<Edge<K, W>> {
+    let mut discovery: HashMap<K, usize> = HashMap::with_capacity(graph.vertices_length());
+    let mut low: HashMap<K, usize> = HashMap::with_capacity(graph.vertices_length());
+    let mut clock = 0;
+    let mut bridges = vec![];
+
+    for root in graph.get_vertice_key_array() {
+        if discovery.contains_key(&root) {
+            continue;
+        }
+        discovery.insert(root.clone(), clock);
+        low.insert(root.clone(), clock);
+        clock += 1;
+
+        let mut stack = vec![BridgeFrame {
+            edges: graph.edges_of(root.clone()).unwrap_or_default(),
+            vertice: root,
+            parent_edge: None,
+            next: 0,
+        }];
+
+        while let Some(frame) = stack.last_mut() {
+            if frame.next < frame.edges.len() {
+                let e = frame.edges[frame.next].clone();
+                frame.next += 1;
+                if frame.parent_edge.as_ref().is_some_and(|p| p.id() == e.id()) {
+                    continue;
+                }
+
+                let v = frame.vertice.clone();
+                let w = UnGraph::other_end(&e, &v).clone();
+                if let Some(&w_discovery) = discovery.get(&w) {
+                    // Aresta de retorno
+                    let low_v = low.get_mut(&v).unwrap();
+                    *low_v = (*low_v).min(w_discovery);
+                } else {
+                    discovery.insert(w.clone(), clock);
+                    low.insert(w.clone(), clock);
+                    clock += 1;
+                    stack.push(BridgeFrame {
+                        edges: graph.edges_of(w.clone()).unwrap_or_default(),
+                        vertice: w,
+                        parent_edge: Some(e),
+                        next: 0,
+                    });
+                }
+                continue;
+            }
+
+            // Terminou de explorar o vértice: propaga o low para o pai
+            let frame = stack.pop().unwrap();
+            if let Some(parent) = stack.last() {
+                let low_v = low[&frame.vertice];
+                let low_parent = low.get_mut(&parent.vertice).unwrap();
+                *low_parent = (*low_parent).min(low_v);
+                if low_v > discovery[&parent.vertice] {
+                    bridges.extend(frame.parent_edge);
+                }
+            }
+        }
+    }
+    bridges
+}
@@ -1,29 +1,44 @@
+use crate::{
+    graph_lib::vertice::VerticeKey,
+    tools::weight::Weight,
+    DiGraph,
+};
 
-
-use std::{cell::RefCell, rc::Rc};
-
-use crate::DiGraph;
-
-use super::busca::{DeepFirstSearch, DfsStruct};
+use super::busca::{DeepFirstSearch, DfsStruct, DfsTree};
 
 
 
 ///Kosaraju method to find conex components
 #[derive(Debug)]
-pub struct ConexComponents {
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(
+        serialize = "K: VerticeKey + serde::Serialize, W: Weight + serde::Serialize",
+        deserialize = "K: VerticeKey + serde::Deserialize<'de>, W: Weight + serde::Deserialize<'de>"
+    ))
+)]
+pub struct ConexComponents<K = i32, W = i32> {
     len: usize,
-    components : Vec<Rc<RefCell<DiGraph>>>,
+    components : Vec<DfsTree<K, W>>,
 }
+
+impl<K: VerticeKey, W: Weight> Default for ConexComponents<K, W> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[allow(unused)]
-impl ConexComponents{
-    pub fn new() -> ConexComponents{
+impl<K: VerticeKey, W: Weight> ConexComponents<K, W> {
+    pub fn new() -> ConexComponents<K, W> {
         ConexComponents{
             len:0,
             components : Vec::new(),
         }
     }
 
-    fn from_dfsData(dfs_data : &mut DfsStruct) -> ConexComponents{
+    fn from_dfs_data(dfs_data : &mut DfsStruct<K, W>) -> ConexComponents<K, W> {
         let mut len = 0;
         let mut components = ConexComponents::new();
 
@@ -36,36 +51,38 @@ impl ConexComponents{
         components
     }
 
-    pub fn clone_components(&self)->Vec<Rc<RefCell<DiGraph>>> {
+    pub fn clone_components(&self) -> Vec<DfsTree<K, W>> {
         self.components.clone()
     }
-    
+
 }
 
 
-pub trait Kosaraju {
-    fn conex_components(&self) -> ConexComponents;
+pub trait Kosaraju<K = i32, W = i32> {
+    fn conex_components(&self) -> ConexComponents<K, W>;
 }
 
 
 // Kosaraju
-impl Kosaraju for DiGraph {
-    fn conex_components(&self) -> ConexComponents {
+impl<K: VerticeKey, W: Weight> Kosaraju<K, W> for DiGraph<K, W> {
+    fn conex_components(&self) -> ConexComponents<K, W> {
         let t_graph = self.transpose();
-        let first_dfs_data = t_graph.dfs_search(1);
-        let mut vertices_queue: Vec<(i32, i32)> =
+        let Some(start) = t_graph.get_vertice_key_array().into_iter().next() else {
+            return ConexComponents::new();
+        };
+        let first_dfs_data = t_graph.dfs_search(start);
+        let mut vertices_queue: Vec<(K, i32)> =
             first_dfs_data.tempo_termino.into_iter().collect();
-        vertices_queue.sort_by(|a, b| b.1.cmp(&a.1)); // Ordenar decrescente por tempo de término
+        vertices_queue.sort_by_key(|tuple| std::cmp::Reverse(tuple.1)); // Ordenar decrescente por tempo de término
 
-        let vertices_queue: Vec<i32> = vertices_queue.into_iter().map(|tuple| tuple.0).collect(); // Filtrar para conter apenas os vertices
-                                                                                                  // Agora temos em ordem decrescente o tempo de término, basta realizar a busca e pegar os componentes fortemente conexos
+        let vertices_queue: Vec<K> = vertices_queue.into_iter().map(|tuple| tuple.0).collect(); // Filtrar para conter apenas os vertices
+                                                                                                // Agora temos em ordem decrescente o tempo de término, basta realizar a busca e pegar os componentes fortemente conexos
         let mut dfs_data = DfsStruct::new(self);
         let mut search_key = dfs_data.get_unexplored_vertice(&vertices_queue);
-        while search_key != -1 {
-            self.DeepFirstSearch(search_key, &mut dfs_data);
+        while let Some(key) = search_key {
+            self.DeepFirstSearch(key, &mut dfs_data);
             search_key = dfs_data.get_unexplored_vertice(&vertices_queue);
         }
-        ConexComponents::from_dfsData(&mut dfs_data)
+        ConexComponents::from_dfs_data(&mut dfs_data)
     }
 }
-
@@ -1,2 +1,4 @@
+pub mod bfs;
 pub mod busca;
-pub mod kosaraju;
\ No newline at end of file
+pub mod components;
+pub mod kosaraju;
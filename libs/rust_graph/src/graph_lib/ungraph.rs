@@ -0,0 +1,320 @@
+use super::{
+    edge::{Edge, EdgeId},
+    graph::DiGraph,
+    vertice::{Vertice, VerticeKey},
+};
+#[cfg(feature = "serde")]
+use super::graph::GraphRepr;
+use crate::tools::weight::Weight;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+/// # UnGraph
+/// Grafo não direcionado representado em lista de Adjacência
+///
+/// Cada aresta `{v, w}` é armazenada nos dois extremos com o mesmo `EdgeId`,
+/// de forma que a adjacência é sempre simétrica. A aresta guarda a orientação
+/// com que foi inserida (`origin_key`, `destiny_key`), mas ela não tem significado aqui.
+///
+/// `edges_len`: quantidade de arestas (cada aresta não direcionada conta uma vez)
+///
+/// `vertices`: HashMap para encontrar vértices usando sua key em O(1)
+///
+/// `edge_index`: extremos `(v, w)` de cada aresta, indexados pelo `EdgeId`
+/// (`None` para arestas removidas)
+pub struct UnGraph<K = i32, W = i32> {
+    edges_len: usize,
+    vertices: HashMap<K, Vertice<K, W>>,
+    edge_index: Vec<Option<(K, K)>>,
+}
+
+impl<K: VerticeKey, W: Weight> Default for UnGraph<K, W> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[allow(unused)]
+impl<K: VerticeKey, W: Weight> UnGraph<K, W> {
+    /// Cria um novo grafo não direcionado vazio.
+    pub fn new() -> UnGraph<K, W> {
+        UnGraph {
+            edges_len: 0,
+            vertices: HashMap::new(),
+            edge_index: Vec::new(),
+        }
+    }
+
+    /// Cria um novo grafo não direcionado com uma capacidade inicial para os vértices.
+    pub fn new_sized(vertice_num: u32) -> UnGraph<K, W> {
+        UnGraph {
+            edges_len: 0,
+            vertices: HashMap::with_capacity(vertice_num as usize),
+            edge_index: Vec::new(),
+        }
+    }
+
+    /// Cria um grafo não direcionado a partir de um vetor de arestas.
+    pub fn from_edges(edge_array: Vec<Edge<K, W>>) -> UnGraph<K, W> {
+        let mut graph = UnGraph::new();
+        for edge in edge_array {
+            graph.add_edge(edge);
+        }
+        graph
+    }
+
+    /// Cria um grafo não direcionado esquecendo a direção das arestas de um `DiGraph`.
+    ///
+    /// Os vértices isolados são mantidos e `v -> w` e `w -> v` viram duas arestas paralelas.
+    /// As arestas recebem novos `EdgeId`s do `UnGraph`.
+    pub fn from_digraph(graph: &DiGraph<K, W>) -> UnGraph<K, W> {
+        let mut ungraph = UnGraph::new_sized(graph.vertices_length() as u32);
+        for vertice in graph.iter_vertices() {
+            ungraph.add_vertice(vertice.key());
+            for edge in vertice.edges_vec_ref() {
+                ungraph.add_edge(edge.clone());
+            }
+        }
+        ungraph
+    }
+
+    /// Cria um `DiGraph` simétrico: cada aresta `{v, w}` vira `v -> w` e `w -> v`.
+    ///
+    /// Laços (`v == w`) geram apenas uma aresta.
+    pub fn to_digraph(&self) -> DiGraph<K, W> {
+        let mut digraph = DiGraph::new_sized(self.vertices.len() as u32);
+        for vertice in self.iter_vertices() {
+            digraph.add_vertice(vertice.key());
+        }
+        for edge in self.all_edges() {
+            if edge.origin_key_ref() != edge.destiny_key_ref() {
+                digraph.add_edge(edge.reversed());
+            }
+            digraph.add_edge(edge);
+        }
+        digraph
+    }
+
+    /// Retorna a quantidade de vértices no grafo.
+    pub fn vertices_length(&self) -> usize {
+        self.vertices.len()
+    }
+
+    /// Retorna a quantidade de arestas no grafo.
+    pub fn edges_length(&self) -> usize {
+        self.edges_len
+    }
+
+    /// Retorna um vetor com as chaves dos vértices.
+    pub fn get_vertice_key_array(&self) -> Vec<K> {
+        self.vertices.keys().cloned().collect()
+    }
+
+    /// Retorna a referência do vértice, se existir.
+    pub fn get_vertice_arc(&self, vertice_key: K) -> Option<&Vertice<K, W>> {
+        self.vertices.get(&vertice_key)
+    }
+
+    /// Verifica a existência de um vértice no grafo.
+    pub fn vertice_exists(&self, vert_key: K) -> bool {
+        self.vertices.contains_key(&vert_key)
+    }
+
+    /// Adiciona um vértice ao grafo.
+    pub fn add_vertice(&mut self, vertice_key: K) -> bool {
+        if self.vertice_exists(vertice_key.clone()) {
+            return false;
+        }
+        let vertice = Vertice::new(vertice_key.clone());
+        self.vertices.insert(vertice_key, vertice);
+        true
+    }
+
+    /// Adiciona uma aresta não direcionada ao grafo, criando os extremos se necessário.
+    ///
+    /// A aresta recebe o próximo `EdgeId` livre do grafo, que é retornado.
+    pub fn add_edge(&mut self, mut edge: Edge<K, W>) -> EdgeId {
+        let id = EdgeId::new(self.edge_index.len());
+        edge.set_id(id);
+        let (v, w) = edge.v_w();
+        self.edge_index.push(Some((v.clone(), w.clone())));
+        self.add_vertice(v.clone());
+        self.add_vertice(w.clone());
+
+        if v != w {
+            let vertice_w = self.vertices.get_mut(&w).unwrap();
+            vertice_w.add_edge_to(v.clone(), edge.clone());
+        }
+        let vertice_v = self.vertices.get_mut(&v).unwrap();
+        vertice_v.add_edge_to(w, edge);
+
+        self.edges_len += 1;
+        id
+    }
+
+    /// Remove uma aresta dos dois extremos, usando seu `id`.
+    ///
+    /// Retorna a aresta removida, ou `None` se ela não pertence ao grafo.
+    pub fn remove_edge(&mut self, e: &Edge<K, W>) -> Option<Edge<K, W>> {
+        let (v, w) = e.v_w();
+        let removed = self
+            .vertices
+            .get_mut(&v)?
+            .remove_edge_to(w.clone(), e.id())?;
+        if v != w {
+            if let Some(vertice_w) = self.vertices.get_mut(&w) {
+                vertice_w.remove_edge_to(v, e.id());
+            }
+        }
+        self.edge_index[e.id().index()] = None;
+        self.edges_len -= 1;
+        Some(removed)
+    }
+
+    /// Remove a aresta de id `id` dos dois extremos.
+    ///
+    /// Retorna a aresta removida, ou `None` se o id não pertence ao grafo.
+    pub fn remove_edge_by_id(&mut self, id: EdgeId) -> Option<Edge<K, W>> {
+        let edge = self.edge(id)?.clone();
+        self.remove_edge(&edge)
+    }
+
+    /// Retorna a aresta de id `id`, se existir.
+    pub fn edge(&self, id: EdgeId) -> Option<&Edge<K, W>> {
+        let (v, w) = self.edge_index.get(id.index())?.as_ref()?;
+        self.vertices
+            .get(v)?
+            .get_edges_to(w.clone())?
+            .iter()
+            .find(|e| e.id() == id)
+    }
+
+    /// Remove um vértice e todas as arestas incidentes a ele.
+    ///
+    /// Retorna as arestas removidas, ou `None` se o vértice não existe.
+    pub fn remove_vertice(&mut self, vertice_key: K) -> Option<Vec<Edge<K, W>>> {
+        let vertice = self.vertices.remove(&vertice_key)?;
+        let removed = vertice.edges_vec();
+        for edge in removed.iter() {
+            let neighbour = Self::other_end(edge, &vertice_key);
+            if let Some(vertice_n) = self.vertices.get_mut(neighbour) {
+                vertice_n.remove_edge_to(vertice_key.clone(), edge.id());
+            }
+            self.edge_index[edge.id().index()] = None;
+        }
+        self.edges_len -= removed.len();
+        Some(removed)
+    }
+
+    /// Verifica se existe pelo menos uma aresta entre dois vértices.
+    pub fn has_edge(&self, v: K, w: K) -> bool {
+        if let Some(vertice) = self.vertices.get(&v) {
+            return vertice.has_edge_to(w);
+        }
+        false
+    }
+
+    /// Retorna todas as arestas entre dois vértices, em qualquer orientação.
+    pub fn get_edges(&self, v: K, w: K) -> Option<Vec<Edge<K, W>>> {
+        self.vertices.get(&v)?.get_edges_to(w).cloned()
+    }
+
+    /// Retorna as chaves dos vizinhos de um vértice.
+    pub fn neighbours(&self, vertice_key: K) -> Option<Vec<K>> {
+        let vertice = self.get_vertice_arc(vertice_key)?;
+        Some(
+            vertice
+                .edges_hashmap()
+                .keys()
+                .map(|(_, neighbour)| neighbour.clone())
+                .collect(),
+        )
+    }
+
+    /// Retorna um vetor clonado das arestas incidentes a um vértice.
+    pub fn edges_of(&self, vertice_key: K) -> Option<Vec<Edge<K, W>>> {
+        let vertice = self.get_vertice_arc(vertice_key)?;
+        Some(vertice.edges_vec())
+    }
+
+    /// Retorna o grau de um vértice (laços contam uma vez).
+    pub fn degree(&self, vertice_key: K) -> Option<usize> {
+        let vertice = self.get_vertice_arc(vertice_key)?;
+        Some(vertice.edges_vec_ref().len())
+    }
+
+    /// Retorna todas as arestas do grafo, cada uma uma única vez.
+    pub fn all_edges(&self) -> Vec<Edge<K, W>> {
+        let mut edges = Vec::with_capacity(self.edges_len);
+        for vertice in self.vertices.values() {
+            for e in vertice.edges_vec_ref() {
+                // A cópia guardada na origem representa a aresta
+                if e.origin_key_ref() == vertice.key_ref() {
+                    edges.push(e.clone());
+                }
+            }
+        }
+        edges
+    }
+
+    /// Retorna o extremo de `edge` oposto a `vertice_key`.
+    pub fn other_end<'e>(edge: &'e Edge<K, W>, vertice_key: &K) -> &'e K {
+        if edge.origin_key_ref() == vertice_key {
+            edge.destiny_key_ref()
+        } else {
+            edge.origin_key_ref()
+        }
+    }
+
+    /// Retorna um iterador sobre os vértices do grafo.
+    pub fn iter_vertices(&self) -> impl Iterator<Item = &Vertice<K, W>> {
+        self.vertices.values()
+    }
+}
+
+impl<K: VerticeKey, W: Weight> From<&DiGraph<K, W>> for UnGraph<K, W> {
+    fn from(graph: &DiGraph<K, W>) -> Self {
+        UnGraph::from_digraph(graph)
+    }
+}
+
+impl<K: VerticeKey, W: Weight> From<&UnGraph<K, W>> for DiGraph<K, W> {
+    fn from(graph: &UnGraph<K, W>) -> Self {
+        graph.to_digraph()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<K: VerticeKey + serde::Serialize, W: Weight + serde::Serialize> serde::Serialize for UnGraph<K, W> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut edges = self.all_edges();
+        edges.sort_by_key(|e| e.id());
+        let vertices = self.get_vertice_key_array();
+        serde::Serialize::serialize(&GraphRepr { vertices, edges }, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, K, W> serde::Deserialize<'de> for UnGraph<K, W>
+where
+    K: VerticeKey + serde::Deserialize<'de>,
+    W: Weight + serde::Deserialize<'de>,
+{
+    /// Ver [`GraphRepr`]: cada aresta aparece uma única vez
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr: GraphRepr<K, W> = serde::Deserialize::deserialize(deserializer)?;
+        let mut graph = UnGraph::new();
+        for v in repr.vertices {
+            graph.add_vertice(v);
+        }
+        for edge in repr.edges {
+            let id = edge.id().index();
+            if id == EdgeId::UNASSIGNED.index() || id < graph.edge_index.len() {
+                return Err(serde::de::Error::custom(format!("edge id {} is out of order", id)));
+            }
+            graph.edge_index.resize(id, None);
+            graph.add_edge(edge);
+        }
+        Ok(graph)
+    }
+}
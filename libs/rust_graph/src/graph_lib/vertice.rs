@@ -1,18 +1,36 @@
-use super::edge::Edge;
-use std::collections::HashMap;
+use super::edge::{Edge, EdgeId};
+use crate::tools::weight::Weight;
+use std::{collections::HashMap, fmt::Debug, hash::Hash};
+
+/// Requisitos para a chave de um vértice
+///
+/// Qualquer tipo `Hash + Eq + Clone + Debug` serve: inteiros, `String`, tuplas, UUIDs...
+///
+/// `Debug` é usado para identificar o vértice em um `GraphError`.
+pub trait VerticeKey: Hash + Eq + Clone + Debug {}
+
+impl<T: Hash + Eq + Clone + Debug> VerticeKey for T {}
 
 /// # Vertice
 /// Estrutura destinada a representar vértices em um grafo.
 ///
 /// Contém campos como `key` e `edges`.
-#[derive(Debug, PartialEq, Eq,Clone)]
-pub struct Vertice {
-    key: i32,
-    edges: HashMap<(i32, i32), Vec<Edge>>, // Arestas armazenadas como HashMap com vetores para permitir arestas paralelas
-    back_edges: HashMap<(i32, i32), Vec<Edge>>,
+#[derive(Debug, Clone)]
+pub struct Vertice<K = i32, W = i32> {
+    key: K,
+    edges: HashMap<(K, K), Vec<Edge<K, W>>>, // Arestas armazenadas como HashMap com vetores para permitir arestas paralelas
+    back_edges: HashMap<(K, K), Vec<Edge<K, W>>>,
+}
+
+impl<K: VerticeKey, W: PartialEq> PartialEq for Vertice<K, W> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key && self.edges == other.edges && self.back_edges == other.back_edges
+    }
 }
 
-impl Vertice {
+impl<K: VerticeKey, W: Eq> Eq for Vertice<K, W> {}
+
+impl<K: VerticeKey, W: Weight> Vertice<K, W> {
     /// Cria um novo vértice com uma chave específica.
     ///
     /// # Argumentos
@@ -22,9 +40,10 @@ impl Vertice {
     /// # Exemplo
     ///
     /// ```
-    /// let vertice = Vertice::new(1);
+    /// # use rust_graph::graph_lib::vertice::Vertice;
+    /// let vertice: Vertice = Vertice::new(1);
     /// ```
-    pub fn new(vertice_key: i32) -> Vertice {
+    pub fn new(vertice_key: K) -> Vertice<K, W> {
         Vertice {
             key: vertice_key,
             edges: HashMap::new(),
@@ -37,11 +56,17 @@ impl Vertice {
     /// # Exemplo
     ///
     /// ```
-    /// let vertice = Vertice::new(1);
+    /// # use rust_graph::graph_lib::vertice::Vertice;
+    /// let vertice: Vertice = Vertice::new(1);
     /// assert_eq!(vertice.key(), 1);
     /// ```
-    pub fn key(&self) -> i32 {
-        self.key
+    pub fn key(&self) -> K {
+        self.key.clone()
+    }
+
+    /// Retorna uma referência para a chave do vértice.
+    pub fn key_ref(&self) -> &K {
+        &self.key
     }
 
     /// Obtém uma referência para as arestas específicas que levam a um vértice destino.
@@ -53,13 +78,18 @@ impl Vertice {
     /// # Exemplo
     ///
     /// ```
-    /// let mut vertice = Vertice::new(1);
-    /// // Suponha que Edge tenha uma função `new` adequada
+    /// # use rust_graph::graph_lib::{edge::Edge, vertice::Vertice};
+    /// let mut vertice: Vertice = Vertice::new(1);
     /// vertice.add_edge(Edge::new(1, 2));
     /// assert!(vertice.get_edges_to(2).is_some());
     /// ```
-    pub fn get_edges_to(&self, destiny_key: i32) -> Option<&Vec<Edge>> {
-        self.edges.get(&(self.key, destiny_key))
+    pub fn get_edges_to(&self, destiny_key: K) -> Option<&Vec<Edge<K, W>>> {
+        self.edges.get(&(self.key.clone(), destiny_key))
+    }
+
+    /// Obtém uma referência mutável para as arestas que levam a um vértice destino.
+    pub fn get_edges_to_mut(&mut self, destiny_key: K) -> Option<&mut Vec<Edge<K, W>>> {
+        self.edges.get_mut(&(self.key.clone(), destiny_key))
     }
 
     /// Retorna uma referência imutável ao `HashMap` de arestas.
@@ -67,10 +97,11 @@ impl Vertice {
     /// # Exemplo
     ///
     /// ```
-    /// let vertice = Vertice::new(1);
+    /// # use rust_graph::graph_lib::vertice::Vertice;
+    /// let vertice: Vertice = Vertice::new(1);
     /// let edges_map = vertice.edges_hashmap();
     /// ```
-    pub fn edges_hashmap(&self) -> &HashMap<(i32, i32), Vec<Edge>> {
+    pub fn edges_hashmap(&self) -> &HashMap<(K, K), Vec<Edge<K, W>>> {
         &self.edges
     }
 
@@ -79,22 +110,24 @@ impl Vertice {
     /// # Exemplo
     ///
     /// ```
-    /// let mut vertice = Vertice::new(1);
+    /// # use rust_graph::graph_lib::vertice::Vertice;
+    /// let mut vertice: Vertice = Vertice::new(1);
     /// let edges_map_mut = vertice.edges_hashmap_mut();
     /// ```
-    pub fn edges_hashmap_mut(&mut self) -> &mut HashMap<(i32, i32), Vec<Edge>> {
+    pub fn edges_hashmap_mut(&mut self) -> &mut HashMap<(K, K), Vec<Edge<K, W>>> {
         &mut self.edges
     }
 
-    /// Retorna todas as arestas como um vetor (`Vec<Edge>`).
+    /// Retorna todas as arestas como um vetor (`Vec<Edge<K, W>>`).
     /// apenas as arestas sucessoras
     /// # Exemplo
     ///
     /// ```
-    /// let vertice = Vertice::new(1);
+    /// # use rust_graph::graph_lib::vertice::Vertice;
+    /// let vertice: Vertice = Vertice::new(1);
     /// let all_edges = vertice.edges_vec();
     /// ```
-    pub fn edges_vec(&self) -> Vec<Edge> {
+    pub fn edges_vec(&self) -> Vec<Edge<K, W>> {
         self.edges.values().flat_map(|vec| vec.clone()).collect()
     }
 
@@ -103,10 +136,11 @@ impl Vertice {
     /// # Exemplo
     ///
     /// ```
-    /// let vertice = Vertice::new(1);
+    /// # use rust_graph::graph_lib::vertice::Vertice;
+    /// let vertice: Vertice = Vertice::new(1);
     /// let all_edges_ref = vertice.edges_vec_ref();
     /// ```
-    pub fn edges_vec_ref(&self) -> Vec<&Edge> {
+    pub fn edges_vec_ref(&self) -> Vec<&Edge<K, W>> {
         self.edges.values().flat_map(|vec| vec.iter()).collect()
     }
 
@@ -115,10 +149,11 @@ impl Vertice {
     /// # Exemplo
     ///
     /// ```
-    /// let mut vertice = Vertice::new(1);
+    /// # use rust_graph::graph_lib::vertice::Vertice;
+    /// let mut vertice: Vertice = Vertice::new(1);
     /// let all_edges_mut = vertice.edges_vec_mut();
     /// ```
-    pub fn edges_vec_mut(&mut self) -> Vec<&mut Edge> {
+    pub fn edges_vec_mut(&mut self) -> Vec<&mut Edge<K, W>> {
         self.edges
             .values_mut()
             .flat_map(|vec| vec.iter_mut())
@@ -134,21 +169,27 @@ impl Vertice {
     /// # Exemplo
     ///
     /// ```
-    /// let mut vertice = Vertice::new(1);
-    /// let edge = Edge::new(1, 2); // Supondo que Edge tenha essa função
+    /// # use rust_graph::graph_lib::{edge::Edge, vertice::Vertice};
+    /// let mut vertice: Vertice = Vertice::new(1);
+    /// let edge = Edge::new(1, 2);
     /// vertice.add_edge(edge);
     /// ```
-    pub fn add_edge(&mut self, edge: Edge) {
-        let key = (self.key, edge.destiny_key());
-        self.edges.entry(key).or_insert_with(Vec::new).push(edge);
+    pub fn add_edge(&mut self, edge: Edge<K, W>) {
+        let key = (self.key.clone(), edge.destiny_key());
+        self.edges.entry(key).or_default().push(edge);
     }
 
-    pub fn add_back_edge(&mut self, edge: Edge) {
-        let key = (self.key, edge.origin_key());
-        self.back_edges
-            .entry(key)
-            .or_insert_with(Vec::new)
-            .push(edge);
+    /// Adiciona uma aresta indexada pelo vizinho `neighbour_key`, independente da orientação da aresta.
+    ///
+    /// Usado pelo `UnGraph`, que guarda a mesma aresta nos dois extremos.
+    pub fn add_edge_to(&mut self, neighbour_key: K, edge: Edge<K, W>) {
+        let key = (self.key.clone(), neighbour_key);
+        self.edges.entry(key).or_default().push(edge);
+    }
+
+    pub fn add_back_edge(&mut self, edge: Edge<K, W>) {
+        let key = (self.key.clone(), edge.origin_key());
+        self.back_edges.entry(key).or_default().push(edge);
     }
 
     /// Verifica se há pelo menos uma aresta entre este vértice e o destino dado.
@@ -160,35 +201,68 @@ impl Vertice {
     /// # Exemplo
     ///
     /// ```
-    /// let mut vertice = Vertice::new(1);
+    /// # use rust_graph::graph_lib::{edge::Edge, vertice::Vertice};
+    /// let mut vertice: Vertice = Vertice::new(1);
     /// vertice.add_edge(Edge::new(1, 2));
     /// assert!(vertice.has_edge_to(2));
     /// assert!(!vertice.has_edge_to(3));
     /// ```
-    pub fn has_edge_to(&self, destiny_key: i32) -> bool {
-        self.edges.contains_key(&(self.key, destiny_key))
+    pub fn has_edge_to(&self, destiny_key: K) -> bool {
+        self.edges.contains_key(&(self.key.clone(), destiny_key))
     }
 
-    pub fn remove_edge(&mut self, e: Edge) {
-        let (v, w) = (e.origin_key(), e.destiny_key());
+    /// Remove uma aresta que sai deste vértice, identificada pelo `id`.
+    ///
+    /// Arestas paralelas com o mesmo peso não são afetadas.
+    ///
+    /// Retorna a aresta removida, se existir.
+    pub fn remove_edge(&mut self, e: &Edge<K, W>) -> Option<Edge<K, W>> {
+        self.remove_edge_to(e.destiny_key(), e.id())
+    }
+
+    /// Remove a aresta de id `edge_id` armazenada para o vizinho `neighbour_key`.
+    ///
+    /// Retorna a aresta removida, se existir.
+    pub fn remove_edge_to(&mut self, neighbour_key: K, edge_id: EdgeId) -> Option<Edge<K, W>> {
+        let key = (self.key.clone(), neighbour_key);
+        let edges = self.edges.get_mut(&key)?;
+        let index = edges.iter().position(|e| e.id() == edge_id)?;
+        let removed = edges.remove(index);
+        if edges.is_empty() {
+            self.edges.remove(&key);
+        }
+        Some(removed)
+    }
 
-        if let Some((_, edges)) = self.edges.remove_entry(&(v, w)) {
-            let filtered_edges: Vec<_> = edges
-                .into_iter()
-                .filter(|edge| edge.weight() != e.weight())
-                .collect();
+    /// Retorna a cópia da aresta de id `edge_id` que chega neste vértice vinda de `origin_key`.
+    pub fn get_back_edge_mut(&mut self, origin_key: K, edge_id: EdgeId) -> Option<&mut Edge<K, W>> {
+        self.back_edges
+            .get_mut(&(self.key.clone(), origin_key))?
+            .iter_mut()
+            .find(|e| e.id() == edge_id)
+    }
 
-            if !filtered_edges.is_empty() {
-                self.edges.insert((v, w), filtered_edges);
-            }
+    /// Remove a aresta de id `edge_id` que chega neste vértice vinda de `origin_key`.
+    ///
+    /// Retorna a aresta removida, se existir.
+    pub fn remove_back_edge_from(&mut self, origin_key: K, edge_id: EdgeId) -> Option<Edge<K, W>> {
+        let key = (self.key.clone(), origin_key);
+        let edges = self.back_edges.get_mut(&key)?;
+        let index = edges.iter().position(|e| e.id() == edge_id)?;
+        let removed = edges.remove(index);
+        if edges.is_empty() {
+            self.back_edges.remove(&key);
         }
+        Some(removed)
     }
+
     /// Retorna todas as arestas que saem deste vértice.
     /// 
     /// tuple.0 contém as arestas que saem do vértice
     /// 
     /// tuple.1 contém as arestas que chegam no vértice
-    pub fn get_all_edges_tuple(&self) -> (Vec<Edge>, Vec<Edge>) {
+    #[allow(clippy::type_complexity)]
+    pub fn get_all_edges_tuple(&self) -> (Vec<Edge<K, W>>, Vec<Edge<K, W>>) {
         let mut edges = Vec::with_capacity(self.edges.len());
         let mut back_edges = Vec::with_capacity(self.back_edges.len());
         for (_, vec) in self.edges.iter() {
@@ -204,22 +278,22 @@ impl Vertice {
         (edges, back_edges)
     }
 
-    pub fn back_edges_hashmap(&self) -> Vec<Edge> {
+    pub fn back_edges_hashmap(&self) -> Vec<Edge<K, W>> {
         self.back_edges
             .values()
             .flat_map(|vec| vec.clone())
             .collect()
     }
 
-    pub fn get_all_edges(&self, destiny_key: i32) -> Vec<Edge> {
+    pub fn get_all_edges(&self, destiny_key: K) -> Vec<Edge<K, W>> {
         let mut edges = Vec::new();
         let mut back_edges = Vec::new();
-        if let Some(vec) = self.edges.get(&(self.key, destiny_key)) {
+        if let Some(vec) = self.edges.get(&(self.key.clone(), destiny_key.clone())) {
             for e in vec {
                 edges.push(e.clone());
             }
         }
-        if let Some(vec) = self.back_edges.get(&(self.key, destiny_key)) {
+        if let Some(vec) = self.back_edges.get(&(self.key.clone(), destiny_key)) {
             for e in vec {
                 back_edges.push(e.clone());
             }
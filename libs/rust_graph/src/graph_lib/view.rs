@@ -1,10 +1,15 @@
-use crate::DiGraph;
+use crate::{
+    graph_lib::{attributes::VerticeMap, generators::geometric::Point},
+    tools::weight::Weight,
+    DiGraph, GraphError,
+};
 use std::collections::HashMap;
 use std::fs;
 use std::process::Command;
 use std::slice::Iter;
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Color {
     Rgb(u8, u8, u8),
     Red,
@@ -13,6 +18,7 @@ pub enum Color {
     Cyan,
     Magenta,
     Yellow,
+    #[default]
     White,
     Grey,
     Black,
@@ -48,6 +54,55 @@ impl Color {
         }
     }
 
+    /// Componentes RGB da cor, usando os valores das cores nomeadas de CSS/SVG
+    pub fn to_rgb(&self) -> (u8, u8, u8) {
+        match *self {
+            Color::Rgb(r, g, b) => (r, g, b),
+            Color::Red => (0xFF, 0x00, 0x00),
+            Color::Green => (0x00, 0x80, 0x00),
+            Color::Blue => (0x00, 0x00, 0xFF),
+            Color::Cyan => (0x00, 0xFF, 0xFF),
+            Color::Magenta => (0xFF, 0x00, 0xFF),
+            Color::Yellow => (0xFF, 0xFF, 0x00),
+            Color::White => (0xFF, 0xFF, 0xFF),
+            Color::Grey => (0x80, 0x80, 0x80),
+            Color::Black => (0x00, 0x00, 0x00),
+            Color::Orange => (0xFF, 0xA5, 0x00),
+            Color::Purple => (0x80, 0x00, 0x80),
+            Color::Pink => (0xFF, 0xC0, 0xCB),
+            Color::Brown => (0xA5, 0x2A, 0x2A),
+            Color::Lime => (0x00, 0xFF, 0x00),
+            Color::Indigo => (0x4B, 0x00, 0x82),
+            Color::Violet => (0xEE, 0x82, 0xEE),
+        }
+    }
+
+    /// Cor no formato `#RRGGBB`, usado por GraphML/GML (Gephi, yEd)
+    pub fn to_hex(&self) -> String {
+        let (r, g, b) = self.to_rgb();
+        format!("#{:02X}{:02X}{:02X}", r, g, b)
+    }
+
+    /// Lê uma cor `#RRGGBB` ou o nome de uma cor (`red`, `green`, ...)
+    ///
+    /// Uma cor hexadecimal igual a uma cor nomeada vira a cor nomeada.
+    pub fn parse(value: &str) -> Option<Color> {
+        let value = value.trim();
+        let named = Color::iterator().chain([Color::White, Color::Black].iter());
+        if let Some(hex) = value.strip_prefix('#') {
+            if hex.len() != 6 {
+                return None;
+            }
+            let channel = |i: usize| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok();
+            let rgb = (channel(0)?, channel(2)?, channel(4)?);
+            let color = named.copied().find(|c| c.to_rgb() == rgb);
+            return Some(color.unwrap_or(Color::Rgb(rgb.0, rgb.1, rgb.2)));
+        }
+        named
+            .copied()
+            .find(|c| c.to_dot_color().eq_ignore_ascii_case(value))
+    }
+
     pub fn iterator() -> Iter<'static, Color> {
         static COLORS: [Color; 14] = [
             Color::Red,
@@ -69,24 +124,21 @@ impl Color {
     }
 }
 
-impl Default for Color {
-    fn default() -> Self {
-        Color::White
-    }
-}
-
 struct Vertice {
     key: i32,
     label: String,
     color: Color,
+    position: Option<Point>,
 }
 
+#[allow(unused)]
 impl Vertice {
     pub fn new(key: i32, color: Color) -> Vertice {
         Vertice {
             key,
             label: key.to_string(), // Por padrão, a label é a key como String
             color,
+            position: None,
         }
     }
 
@@ -114,6 +166,7 @@ struct Edge {
     color: Color,
 }
 
+#[allow(unused)]
 impl Edge {
     pub fn new_colored(origin_vertice: i32, destiny_vertice: i32, color: Color) -> Edge {
         Edge {
@@ -154,6 +207,12 @@ pub struct GraphPainter {
     edges: HashMap<(i32, i32), Edge>,
 }
 
+impl Default for GraphPainter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl GraphPainter {
     pub fn new() -> GraphPainter {
         GraphPainter {
@@ -182,6 +241,20 @@ impl GraphPainter {
         }
     }
 
+    /// Fixa a posição de um vértice no desenho.
+    pub fn update_vertice_position(&mut self, key: i32, position: Point) {
+        if let Some(vertice) = self.vertices.get_mut(&key) {
+            vertice.position = Some(position);
+        }
+    }
+
+    /// Fixa a posição de cada vértice que tem coordenadas em `positions`.
+    pub fn update_vertice_positions(&mut self, positions: &VerticeMap<i32, Point>) {
+        for (key, position) in positions.iter() {
+            self.update_vertice_position(*key, *position);
+        }
+    }
+
     /// Adiciona uma aresta ao grafo.
     pub fn add_edge(
         &mut self,
@@ -190,10 +263,10 @@ impl GraphPainter {
         label: Option<String>,
         color: Option<Color>,
     ) {
-        if self.vertices.get(&origin).is_none() {
+        if !self.vertices.contains_key(&origin) {
             self.add_vertice(origin, None);
         }
-        if self.vertices.get(&destiny).is_none() {
+        if !self.vertices.contains_key(&destiny) {
             self.add_vertice(destiny, None);
         }
 
@@ -218,6 +291,34 @@ impl GraphPainter {
         }
     }
 
+    /// Retorna o label de um vértice, se ele existir.
+    pub fn vertice_label(&self, key: i32) -> Option<&str> {
+        self.vertices.get(&key).map(|v| v.get_label())
+    }
+
+    /// Retorna a cor de um vértice, se ele existir.
+    pub fn vertice_color(&self, key: i32) -> Option<Color> {
+        self.vertices.get(&key).map(|v| v.color)
+    }
+
+    /// Retorna a posição de um vértice, se ele existir e tiver posição.
+    pub fn vertice_position(&self, key: i32) -> Option<Point> {
+        self.vertices.get(&key).and_then(|v| v.position)
+    }
+
+    /// Retorna o label de uma aresta, se ela existir e tiver label.
+    pub fn edge_label(&self, origin: i32, destiny: i32) -> Option<&str> {
+        self.edges
+            .get(&(origin, destiny))
+            .and_then(|e| e.get_label())
+            .map(|l| l.as_str())
+    }
+
+    /// Retorna a cor de uma aresta, se ela existir.
+    pub fn edge_color(&self, origin: i32, destiny: i32) -> Option<Color> {
+        self.edges.get(&(origin, destiny)).map(|e| e.color)
+    }
+
     /// Remove todas as arestas com uma cor específica.
     pub fn remove_edges_by_color(&mut self, color: Color) {
         self.edges.retain(|_, edge| edge.color != color);
@@ -244,20 +345,28 @@ impl GraphPainter {
     }
 
     /// Gera a representação DOT do grafo.
+    ///
+    /// Se algum vértice tiver posição o layout é `neato` e as posições são fixas (`pos="x,y!"`).
     pub fn to_dot(&self, title: &str) -> String {
+        let positioned = self.vertices.values().any(|v| v.position.is_some());
         let mut dot = String::from("digraph G {\n");
-        dot.push_str("layout=dot;\n");
+        dot.push_str(if positioned { "layout=neato;\n" } else { "layout=dot;\n" });
         dot.push_str("node [shape=circle];\n");
         dot.push_str("edge [dir=forward];\n");
         dot.push_str(format!("label=\"{}\";\n", title).as_str()); // Adiciona o título
         dot.push_str("labelloc=\"t\";\n"); // Posição do título no topo
         dot.push_str("fontsize=20;\n"); // Define o tamanho da fonte do título
         for vertice in self.vertices.values() {
+            let pos = vertice
+                .position
+                .map(|p| format!(", pos=\"{},{}!\"", p.x, p.y))
+                .unwrap_or_default();
             dot.push_str(&format!(
-                "{} [label=\"{}\", style=filled, fillcolor=\"{}\"];\n",
+                "{} [label=\"{}\", style=filled, fillcolor=\"{}\"{}];\n",
                 vertice.key,
                 vertice.label,
-                vertice.color.to_dot_color()
+                vertice.color.to_dot_color(),
+                pos
             ));
         }
 
@@ -272,18 +381,22 @@ impl GraphPainter {
             ));
         }
 
-        dot.push_str("}");
+        dot.push('}');
         dot
     }
 
     /// Gera uma imagem PNG a partir da representação DOT do grafo.
-    pub fn to_png(&self, file_path: &str, title: &str) {
+    ///
+    /// # Errors
+    /// `GraphError::Io` se o arquivo DOT não puder ser escrito ou o `dot` (Graphviz)
+    /// não puder ser executado, e `GraphError::ExternalTool` se o `dot` falhar.
+    pub fn to_png(&self, file_path: &str, title: &str) -> Result<(), GraphError> {
         let dot = self.to_dot(title);
         let dot_file = format!("{}.dot", file_path);
         let png_file = format!("{}.png", file_path);
 
         // Salva o arquivo DOT
-        fs::write(&dot_file, dot).expect("Erro ao escrever arquivo DOT");
+        fs::write(&dot_file, dot)?;
 
         // Usa o Graphviz para converter DOT em PNG
         let output = Command::new("dot")
@@ -291,21 +404,23 @@ impl GraphPainter {
             .arg(&dot_file)
             .arg("-o")
             .arg(&png_file)
-            .output()
-            .expect("Erro ao gerar imagem PNG");
+            .output();
 
+        // Deleta o arquivo DOT após gerar a imagem PNG, mesmo em caso de erro
+        let removed = fs::remove_file(&dot_file);
+        let output = output?;
         if !output.status.success() {
-            eprintln!(
-                "Erro ao gerar imagem PNG: {}",
-                String::from_utf8_lossy(&output.stderr)
-            );
+            return Err(GraphError::ExternalTool {
+                tool: "dot".to_string(),
+                message: String::from_utf8_lossy(&output.stderr).into_owned(),
+            });
         }
-        // Deleta o arquivo DOT após gerar a imagem PNG
-        fs::remove_file(&dot_file).expect("Erro ao deletar arquivo DOT");
+        removed?;
+        Ok(())
     }
 
     /// Cria um `GraphPainter` a partir de um `DiGraph`.
-    pub fn from_digraph(g: &DiGraph) -> Self {
+    pub fn from_digraph<W: Weight>(g: &DiGraph<i32, W>) -> Self {
         let mut graph = Self::new();
         for v in g.iter_vertices() {
             graph.add_vertice(v.key(), None);
@@ -315,6 +430,25 @@ impl GraphPainter {
         }
         graph
     }
+
+    /// Cria um `GraphPainter` a partir de um `DiGraph`, com cada vértice fixo na sua posição
+    /// em `positions` (layout `neato`, ver `to_dot`).
+    ///
+    /// Para um grafo geométrico: `GraphPainter::from_geometric(&g.graph, &g.positions)`
+    /// (com `g.graph.to_digraph()` se o grafo não for direcionado).
+    /// ```
+    /// # use rust_graph::{DiGraph, graph_lib::{generators::geometric::GeometricOptions, view::GraphPainter}};
+    /// let geometric = DiGraph::from_random_geometric(20, &GeometricOptions::nearest(3), 1);
+    /// let painter = GraphPainter::from_geometric(&geometric.graph, &geometric.positions);
+    /// assert_eq!(painter.vertice_position(0), geometric.position(0));
+    /// assert!(painter.to_dot("rotas").contains("layout=neato;"));
+    /// ```
+    pub fn from_geometric<W: Weight>(g: &DiGraph<i32, W>, positions: &VerticeMap<i32, Point>) -> Self {
+        let mut painter = Self::from_digraph(g);
+        painter.update_vertice_positions(positions);
+        painter
+    }
+
     /// Creates a `GraphPainter` from a `DiGraph` with a title and saves it as a PNG file.
     ///
     /// # Arguments
@@ -324,8 +458,12 @@ impl GraphPainter {
     /// * `file_path` - The path to save the PNG file.
     ///
     /// * `title` - The title of the graph.
-    pub fn draw(graph: &DiGraph, file_path: &str, title: &str) {
+    ///
+    /// # Errors
+    ///
+    /// Same as [`GraphPainter::to_png`].
+    pub fn draw<W: Weight>(graph: &DiGraph<i32, W>, file_path: &str, title: &str) -> Result<(), GraphError> {
         let painter = GraphPainter::from_digraph(graph);
-        painter.to_png(file_path, title);
+        painter.to_png(file_path, title)
     }
 }
@@ -0,0 +1,7 @@
+pub mod graph_lib;
+pub mod tools;
+
+pub use graph_lib::error::GraphError;
+pub use graph_lib::graph::DiGraph;
+pub use graph_lib::matrix_graph::MatrixGraph;
+pub use graph_lib::ungraph::UnGraph;
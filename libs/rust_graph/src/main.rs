@@ -1,14 +1,3 @@
-mod graph_lib;
-mod tools;
-
-use std::{char::TryFromCharError, time::Duration};
-
-use graph_lib::{
-    edge::Edge, flux::ford_fulkerson::max_flux, graph::DiGraph, minPath::floyd_warshall,
-    view::GraphPainter,
-};
-use tools::inifinity::Infinity;
-
 /*
 
 (1 → 2): 3
@@ -22,8 +11,4 @@ use tools::inifinity::Infinity;
 (5 → 4): 6
 
 */
-pub fn main() {
-    let abc = 20;
-
-
-}
+pub fn main() {}
@@ -35,7 +35,12 @@ where T:  Clone,
     }
 
     pub fn empty(&self) -> bool {
-        self.heap.len() == 0
+        self.heap.is_empty()
+    }
+
+    /// Returns true if the heap has no elements
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
     }
 
     /// Get the parent index and its value
@@ -49,6 +54,7 @@ where T:  Clone,
     }
 
     /// Get the children indices and values
+    #[allow(clippy::type_complexity)]
     fn get_children(&self, idx: usize) -> (Option<(usize, &T)>, Option<(usize, &T)>) {
         let left_idx = 2 * idx + 1;
         let right_idx = 2 * idx + 2;
@@ -117,6 +123,6 @@ where T:  Clone,
 
     /// ## Peek at the root element (the smallest element) without removing it
     pub fn peek(&self) -> Option<&T> {
-        self.heap.get(0)
+        self.heap.first()
     }
 }
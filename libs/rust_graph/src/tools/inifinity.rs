@@ -1,14 +1,17 @@
-use std::ops::{Add, Sub, Mul, Div};
+use std::ops::{Add, Div, Mul, Sub};
+
+use super::weight::Weight;
 
 #[derive(Debug, Clone, Copy)]
-pub enum Infinity {
-    Infinite, // pode ser infinito
-    Number(i32), // ou um número
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Infinity<W = i32> {
+    Infinite,  // pode ser infinito
+    Number(W), // ou um número
 }
 
 #[allow(unused)]
-impl Infinity {
-    pub fn new(val: i32) -> Self {
+impl<W: Weight> Infinity<W> {
+    pub fn new(val: W) -> Self {
         Infinity::Number(val)
     }
 
@@ -20,7 +23,7 @@ impl Infinity {
         matches!(self, Infinity::Infinite)
     }
 
-    pub fn unwrap(&self) -> i32 {
+    pub fn unwrap(&self) -> W {
         match self {
             Infinity::Number(val) => *val,
             Infinity::Infinite => panic!("Cannot unwrap infinite value"),
@@ -36,15 +39,15 @@ impl Infinity {
 
     pub fn saturating_sub(self, other: Self) -> Self {
         match (self, other) {
-            (Infinity::Infinite, Infinity::Infinite) => Infinity::Number(0), // Custom behavior for ∞ - ∞
+            (Infinity::Infinite, Infinity::Infinite) => Infinity::Number(W::zero()), // Custom behavior for ∞ - ∞
             (Infinity::Infinite, _) => Infinity::Infinite,
-            (_, Infinity::Infinite) => Infinity::Number(0),
+            (_, Infinity::Infinite) => Infinity::Number(W::zero()),
             (Infinity::Number(a), Infinity::Number(b)) => Infinity::Number(a.saturating_sub(b)),
         }
     }
 }
 
-impl PartialEq for Infinity {
+impl<W: PartialEq> PartialEq for Infinity<W> {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
             (Infinity::Infinite, Infinity::Infinite) => true,
@@ -54,9 +57,9 @@ impl PartialEq for Infinity {
     }
 }
 
-impl Eq for Infinity {}
+impl<W: Eq> Eq for Infinity<W> {}
 
-impl Ord for Infinity {
+impl<W: Ord> Ord for Infinity<W> {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         match (self, other) {
             (Infinity::Infinite, Infinity::Infinite) => std::cmp::Ordering::Equal,
@@ -67,13 +70,18 @@ impl Ord for Infinity {
     }
 }
 
-impl PartialOrd for Infinity {
+impl<W: PartialOrd> PartialOrd for Infinity<W> {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
+        match (self, other) {
+            (Infinity::Infinite, Infinity::Infinite) => Some(std::cmp::Ordering::Equal),
+            (Infinity::Infinite, Infinity::Number(_)) => Some(std::cmp::Ordering::Greater),
+            (Infinity::Number(_), Infinity::Infinite) => Some(std::cmp::Ordering::Less),
+            (Infinity::Number(a), Infinity::Number(b)) => a.partial_cmp(b),
+        }
     }
 }
 
-impl Add for Infinity {
+impl<W: Weight> Add for Infinity<W> {
     type Output = Self;
 
     fn add(self, other: Self) -> Self {
@@ -84,26 +92,28 @@ impl Add for Infinity {
     }
 }
 
-impl Sub for Infinity {
+impl<W: Weight> Sub for Infinity<W> {
     type Output = Self;
 
     fn sub(self, other: Self) -> Self {
         match (self, other) {
             (Infinity::Infinite, Infinity::Infinite) => Infinity::Infinite,
             (Infinity::Infinite, _) => Infinity::Infinite,
-            (_, Infinity::Infinite) => Infinity::Number(0),
+            (_, Infinity::Infinite) => Infinity::Number(W::zero()),
             (Infinity::Number(a), Infinity::Number(b)) => Infinity::Number(a - b),
         }
     }
 }
 
-impl Mul for Infinity {
+impl<W: Weight + Mul<Output = W>> Mul for Infinity<W> {
     type Output = Self;
 
     fn mul(self, other: Self) -> Self {
         match (self, other) {
-            (Infinity::Infinite, Infinity::Number(0)) | (Infinity::Number(0), Infinity::Infinite) => {
-                Infinity::Number(0)
+            (Infinity::Infinite, Infinity::Number(z)) | (Infinity::Number(z), Infinity::Infinite)
+                if z == W::zero() =>
+            {
+                Infinity::Number(W::zero())
             }
             (Infinity::Infinite, _) | (_, Infinity::Infinite) => Infinity::Infinite,
             (Infinity::Number(a), Infinity::Number(b)) => Infinity::Number(a * b),
@@ -111,21 +121,21 @@ impl Mul for Infinity {
     }
 }
 
-impl Div for Infinity {
+impl<W: Weight + Div<Output = W>> Div for Infinity<W> {
     type Output = Self;
 
     fn div(self, other: Self) -> Self {
         match (self, other) {
             (Infinity::Infinite, Infinity::Infinite) => panic!("Indeterminate form: ∞ / ∞"),
-            (_, Infinity::Number(0)) => panic!("Division by zero"),
+            (_, Infinity::Number(z)) if z == W::zero() => panic!("Division by zero"),
             (Infinity::Infinite, _) => Infinity::Infinite,
             (Infinity::Number(a), Infinity::Number(b)) => Infinity::Number(a / b),
-            (Infinity::Number(_), Infinity::Infinite) => Self::Number(0),
+            (Infinity::Number(_), Infinity::Infinite) => Self::Number(W::zero()),
         }
     }
 }
 
-impl std::fmt::Display for Infinity {
+impl<W: std::fmt::Display> std::fmt::Display for Infinity<W> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Infinity::Infinite => write!(f, "∞"),
@@ -0,0 +1,69 @@
+use std::{
+    fmt::{Debug, Display},
+    ops::{Add, Sub},
+};
+
+/// Numeric type usable as an edge weight
+///
+/// Implemented for every primitive integer and float, so a graph can pick
+/// `i64` or `u64` to avoid overflow, or `f64` for real-valued costs.
+pub trait Weight:
+    Copy + PartialOrd + Add<Output = Self> + Sub<Output = Self> + Debug + Display
+{
+    /// Additive identity
+    fn zero() -> Self;
+
+    /// Default weight of an unweighted edge
+    fn one() -> Self;
+
+    /// Sum that clamps at the numeric bounds instead of overflowing
+    fn saturating_add(self, other: Self) -> Self;
+
+    /// Difference that clamps at the numeric bounds instead of overflowing
+    fn saturating_sub(self, other: Self) -> Self;
+}
+
+macro_rules! impl_integer_weight {
+    ($($t:ty),*) => {
+        $(
+            impl Weight for $t {
+                fn zero() -> Self {
+                    0
+                }
+                fn one() -> Self {
+                    1
+                }
+                fn saturating_add(self, other: Self) -> Self {
+                    <$t>::saturating_add(self, other)
+                }
+                fn saturating_sub(self, other: Self) -> Self {
+                    <$t>::saturating_sub(self, other)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_float_weight {
+    ($($t:ty),*) => {
+        $(
+            impl Weight for $t {
+                fn zero() -> Self {
+                    0.0
+                }
+                fn one() -> Self {
+                    1.0
+                }
+                fn saturating_add(self, other: Self) -> Self {
+                    self + other
+                }
+                fn saturating_sub(self, other: Self) -> Self {
+                    self - other
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_weight!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_float_weight!(f32, f64);